@@ -0,0 +1,187 @@
+/*
+ * @file base64.rs
+ * @brief Base64 decoder
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: base64.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Base64 Decoder.
+//!
+//! BRIEF:
+//! Decodes standard base64 text into a caller buffer without allocation.
+//! Accepts padded and unpadded input and reports malformed data.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Errors reported by the base64 decoder.
+///
+/// # Variants
+/// * `InvalidChar` - Byte outside the base64 alphabet
+/// * `InvalidLength` - Input length cannot encode whole bytes
+/// * `InvalidPadding` - Padding is misplaced or too long
+/// * `BufferTooSmall` - Output buffer cannot hold the decoded bytes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum Base64Error {
+    InvalidChar(u8),
+    InvalidLength,
+    InvalidPadding,
+    BufferTooSmall,
+}
+
+/// Maps a base64 alphabet character to its 6-bit value.
+///
+/// # Arguments
+/// * `ch` - Character from the standard alphabet
+///
+/// # Returns
+/// * `Result<u32, Base64Error>` - Sextet value or InvalidChar
+fn sextet(ch: u8) -> Result<u32, Base64Error> {
+    let value = match ch {
+        b'A'..=b'Z' => ch - b'A',
+        b'a'..=b'z' => ch - b'a' + 26,
+        b'0'..=b'9' => ch - b'0' + 52,
+        b'+' => 62,
+        b'/' => 63,
+        _ => return Err(Base64Error::InvalidChar(ch)),
+    };
+    Ok(value as u32)
+}
+
+/// Decodes standard base64 text.
+///
+/// # Details
+/// Up to two trailing `=` are accepted when the input is a multiple of
+/// four characters; unpadded input may end with a 2- or 3-character group.
+///
+/// # Arguments
+/// * `input` - Base64 text
+/// * `out` - Destination for decoded bytes
+///
+/// # Returns
+/// * `Result<usize, Base64Error>` - Number of decoded bytes or the failure
+#[allow(dead_code)]
+pub fn decode(input: &[u8], out: &mut [u8]) -> Result<usize, Base64Error> {
+    let pad = input.iter().rev().take_while(|&&b| b == b'=').count();
+    if pad > 2 || (pad > 0 && !input.len().is_multiple_of(4)) {
+        return Err(Base64Error::InvalidPadding);
+    }
+    let data = &input[..input.len() - pad];
+    if data.len() % 4 == 1 {
+        return Err(Base64Error::InvalidLength);
+    }
+    if data.len() * 3 / 4 > out.len() {
+        return Err(Base64Error::BufferTooSmall);
+    }
+    let mut acc = 0u32;
+    let mut bits = 0;
+    let mut len = 0;
+    for &ch in data {
+        acc = (acc << 6) | sextet(ch)?;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out[len] = (acc >> bits) as u8;
+            len += 1;
+        }
+    }
+    Ok(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Decodes into a scratch buffer and returns the bytes.
+    fn decode_vec(input: &[u8]) -> Result<Vec<u8>, Base64Error> {
+        let mut out = [0u8; 64];
+        decode(input, &mut out).map(|n| out[..n].to_vec())
+    }
+
+    // ==================== Known Vector Tests ====================
+
+    #[test]
+    fn test_decode_empty() {
+        assert_eq!(decode_vec(b""), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_decode_full_group() {
+        assert_eq!(decode_vec(b"Zm9v"), Ok(b"foo".to_vec()));
+    }
+
+    #[test]
+    fn test_decode_padded() {
+        assert_eq!(decode_vec(b"Zg=="), Ok(b"f".to_vec()));
+        assert_eq!(decode_vec(b"Zm8="), Ok(b"fo".to_vec()));
+        assert_eq!(decode_vec(b"SGVsbG8="), Ok(b"Hello".to_vec()));
+    }
+
+    #[test]
+    fn test_decode_unpadded() {
+        assert_eq!(decode_vec(b"Zg"), Ok(b"f".to_vec()));
+        assert_eq!(decode_vec(b"Zm8"), Ok(b"fo".to_vec()));
+        assert_eq!(decode_vec(b"SGVsbG8"), Ok(b"Hello".to_vec()));
+    }
+
+    #[test]
+    fn test_decode_binary() {
+        assert_eq!(decode_vec(b"AP8+/w=="), Ok(vec![0x00, 0xFF, 0x3E, 0xFF]));
+    }
+
+    // ==================== Error Tests ====================
+
+    #[test]
+    fn test_invalid_char() {
+        assert_eq!(decode_vec(b"Zm9*"), Err(Base64Error::InvalidChar(b'*')));
+    }
+
+    #[test]
+    fn test_padding_inside_data() {
+        assert_eq!(decode_vec(b"Zg==Zg=="), Err(Base64Error::InvalidChar(b'=')));
+    }
+
+    #[test]
+    fn test_invalid_length() {
+        assert_eq!(decode_vec(b"Zm9vY"), Err(Base64Error::InvalidLength));
+    }
+
+    #[test]
+    fn test_invalid_padding() {
+        assert_eq!(decode_vec(b"Zg="), Err(Base64Error::InvalidPadding));
+        assert_eq!(decode_vec(b"Z==="), Err(Base64Error::InvalidPadding));
+    }
+
+    #[test]
+    fn test_buffer_too_small() {
+        let mut out = [0u8; 2];
+        assert_eq!(decode(b"Zm9v", &mut out), Err(Base64Error::BufferTooSmall));
+    }
+}
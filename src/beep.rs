@@ -0,0 +1,104 @@
+/*
+ * @file beep.rs
+ * @brief Buzzer beep scheduling
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: beep.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Buzzer Beep Scheduling.
+//!
+//! BRIEF:
+//! Decides whether the bell buzzer is sounding after a BEL.
+//! The PWM tone itself is driven from main.rs.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Decides whether the buzzer should be sounding.
+///
+/// # Details
+/// A beep lasts `beep_ms` from the BEL that started it. Unlike the
+/// activity LED, a start time ahead of `now_ms` is not yet sounding.
+///
+/// # Arguments
+/// * `start_ms` - Time the BEL was received or emitted
+/// * `now_ms` - Current time
+/// * `beep_ms` - Beep duration, 0 to disable the buzzer
+///
+/// # Returns
+/// * `bool` - True if the tone should be on
+#[allow(dead_code)]
+pub fn beep_active(start_ms: u64, now_ms: u64, beep_ms: u64) -> bool {
+    now_ms
+        .checked_sub(start_ms)
+        .is_some_and(|elapsed| elapsed < beep_ms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Beep Window Tests ====================
+
+    #[test]
+    fn test_beep_on_at_start() {
+        assert!(beep_active(1000, 1000, 100));
+    }
+
+    #[test]
+    fn test_beep_on_within_window() {
+        assert!(beep_active(1000, 1099, 100));
+    }
+
+    #[test]
+    fn test_beep_off_at_window_end() {
+        assert!(!beep_active(1000, 1100, 100));
+    }
+
+    #[test]
+    fn test_beep_off_long_after() {
+        assert!(!beep_active(1000, 9000, 100));
+    }
+
+    #[test]
+    fn test_beep_off_before_start() {
+        assert!(!beep_active(1000, 999, 100));
+    }
+
+    #[test]
+    fn test_beep_zero_duration_disabled() {
+        assert!(!beep_active(1000, 1000, 0));
+    }
+
+    #[test]
+    fn test_beep_restart_extends_window() {
+        assert!(!beep_active(1000, 1150, 100));
+        assert!(beep_active(1120, 1150, 100));
+    }
+}
@@ -0,0 +1,82 @@
+/*
+ * @file boot.rs
+ * @brief Boot delay progress
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: boot.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Boot Delay Progress.
+//!
+//! BRIEF:
+//! Computes how many progress dots a slow boot should have shown.
+//! Timestamps come from the main loop; the count is pure.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Returns the number of progress dots due after `elapsed_ms`.
+///
+/// # Details
+/// One dot per completed interval. A zero interval shows no dots.
+///
+/// # Arguments
+/// * `elapsed_ms` - Time since the boot delay started
+/// * `interval_ms` - Time between dots
+///
+/// # Returns
+/// * `u64` - Dots that should have been sent so far
+#[allow(dead_code)]
+pub fn dots_for_elapsed(elapsed_ms: u64, interval_ms: u64) -> u64 {
+    elapsed_ms.checked_div(interval_ms).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Dot Count Tests ====================
+
+    #[test]
+    fn test_no_dots_before_first_interval() {
+        assert_eq!(dots_for_elapsed(0, 500), 0);
+        assert_eq!(dots_for_elapsed(499, 500), 0);
+    }
+
+    #[test]
+    fn test_dot_per_completed_interval() {
+        assert_eq!(dots_for_elapsed(500, 500), 1);
+        assert_eq!(dots_for_elapsed(1999, 500), 3);
+        assert_eq!(dots_for_elapsed(2000, 500), 4);
+    }
+
+    #[test]
+    fn test_zero_interval_no_dots() {
+        assert_eq!(dots_for_elapsed(1000, 0), 0);
+    }
+}
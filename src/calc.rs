@@ -0,0 +1,240 @@
+/*
+ * @file calc.rs
+ * @brief Integer calculator
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: calc.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Integer Calculator.
+//!
+//! BRIEF:
+//! Tokenizes and evaluates simple integer expressions left to right.
+//! All arithmetic is overflow-checked for the calc command.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Errors reported by the calculator.
+///
+/// # Variants
+/// * `Empty` - Expression has no tokens
+/// * `Malformed` - Unexpected character or token order
+/// * `Overflow` - A number or result does not fit a u64, or goes negative
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum CalcError {
+    Empty,
+    Malformed,
+    Overflow,
+}
+
+/// Lexical token of an expression.
+///
+/// # Variants
+/// * `Number` - Non-negative integer literal
+/// * `Op` - Operator: `+`, `-`, or `*`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum Token {
+    Number(u64),
+    Op(u8),
+}
+
+/// Iterator over the tokens of an expression.
+///
+/// # Details
+/// ASCII whitespace between tokens is skipped.
+///
+/// # Fields
+/// * `input` - Expression text
+/// * `pos` - Index of the next unread byte
+#[derive(Clone, Copy, Debug)]
+#[allow(dead_code)]
+pub struct Tokenizer<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Tokenizer<'a> {
+    /// Creates a tokenizer at the start of an expression.
+    ///
+    /// # Arguments
+    /// * `input` - Expression text
+    ///
+    /// # Returns
+    /// * `Self` - New Tokenizer
+    #[allow(dead_code)]
+    pub fn new(input: &'a [u8]) -> Self {
+        Self { input, pos: 0 }
+    }
+}
+
+impl Iterator for Tokenizer<'_> {
+    type Item = Result<Token, CalcError>;
+
+    /// Reads the next token.
+    ///
+    /// # Returns
+    /// * `Option<Result<Token, CalcError>>` - Token or error, None at the end
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.input.get(self.pos)?.is_ascii_whitespace() {
+            self.pos += 1;
+        }
+        let ch = self.input[self.pos];
+        self.pos += 1;
+        match ch {
+            b'+' | b'-' | b'*' => Some(Ok(Token::Op(ch))),
+            b'0'..=b'9' => {
+                let mut value = u64::from(ch - b'0');
+                while let Some(&d @ b'0'..=b'9') = self.input.get(self.pos) {
+                    self.pos += 1;
+                    value = match value
+                        .checked_mul(10)
+                        .and_then(|v| v.checked_add(u64::from(d - b'0')))
+                    {
+                        Some(v) => v,
+                        None => return Some(Err(CalcError::Overflow)),
+                    };
+                }
+                Some(Ok(Token::Number(value)))
+            }
+            _ => Some(Err(CalcError::Malformed)),
+        }
+    }
+}
+
+/// Evaluates an expression strictly left to right.
+///
+/// # Details
+/// Supports `+`, `-`, and `*` on non-negative integers with no
+/// precedence, so `2+3*4` is 20. A negative intermediate result is
+/// reported as Overflow.
+///
+/// # Arguments
+/// * `expr` - Expression text
+///
+/// # Returns
+/// * `Result<u64, CalcError>` - Result, or the reason evaluation failed
+#[allow(dead_code)]
+pub fn evaluate(expr: &[u8]) -> Result<u64, CalcError> {
+    let mut tokens = Tokenizer::new(expr);
+    let mut acc = match tokens.next() {
+        None => return Err(CalcError::Empty),
+        Some(Ok(Token::Number(n))) => n,
+        Some(Ok(Token::Op(_))) => return Err(CalcError::Malformed),
+        Some(Err(e)) => return Err(e),
+    };
+    while let Some(op) = tokens.next() {
+        let (op, rhs) = match (op?, tokens.next().transpose()?) {
+            (Token::Op(op), Some(Token::Number(rhs))) => (op, rhs),
+            _ => return Err(CalcError::Malformed),
+        };
+        acc = match op {
+            b'+' => acc.checked_add(rhs),
+            b'-' => acc.checked_sub(rhs),
+            _ => acc.checked_mul(rhs),
+        }
+        .ok_or(CalcError::Overflow)?;
+    }
+    Ok(acc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Tokenizer Tests ====================
+
+    #[test]
+    fn test_tokenize_with_spaces() {
+        let tokens: Vec<_> = Tokenizer::new(b" 12 + 3").collect();
+        assert_eq!(
+            tokens,
+            [
+                Ok(Token::Number(12)),
+                Ok(Token::Op(b'+')),
+                Ok(Token::Number(3))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_bad_char() {
+        assert_eq!(Tokenizer::new(b"/").next(), Some(Err(CalcError::Malformed)));
+    }
+
+    // ==================== Evaluate Tests ====================
+
+    #[test]
+    fn test_left_to_right() {
+        assert_eq!(evaluate(b"2+3*4"), Ok(20));
+    }
+
+    #[test]
+    fn test_subtraction() {
+        assert_eq!(evaluate(b"10 - 4 - 1"), Ok(5));
+    }
+
+    #[test]
+    fn test_single_number() {
+        assert_eq!(evaluate(b"42"), Ok(42));
+    }
+
+    #[test]
+    fn test_multiply_overflow() {
+        assert_eq!(
+            evaluate(b"18446744073709551615*2"),
+            Err(CalcError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_literal_overflow() {
+        assert_eq!(evaluate(b"18446744073709551616"), Err(CalcError::Overflow));
+    }
+
+    #[test]
+    fn test_negative_result_overflow() {
+        assert_eq!(evaluate(b"1-2"), Err(CalcError::Overflow));
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(evaluate(b"  "), Err(CalcError::Empty));
+    }
+
+    #[test]
+    fn test_malformed() {
+        assert_eq!(evaluate(b"+1"), Err(CalcError::Malformed));
+        assert_eq!(evaluate(b"1+"), Err(CalcError::Malformed));
+        assert_eq!(evaluate(b"1 2"), Err(CalcError::Malformed));
+        assert_eq!(evaluate(b"1+*2"), Err(CalcError::Malformed));
+        assert_eq!(evaluate(b"2/1"), Err(CalcError::Malformed));
+    }
+}
@@ -0,0 +1,772 @@
+/*
+ * @file command.rs
+ * @brief Command line parser
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: command.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Command Line Parser.
+//!
+//! BRIEF:
+//! Maps committed command-mode lines to known commands.
+//! Execution lives in the controller, which owns the state commands act on.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+use crate::error::UartError;
+use crate::fmtbuf::FmtBuf;
+use core::fmt::Write;
+
+/// Length of the longest command name.
+const MAX_NAME_LEN: usize = 9;
+
+/// Commands recognized in command mode.
+///
+/// # Variants
+/// * `Dump` - Print the pre-command line buffer as hex
+/// * `Reset` - Confirm and request a software reset
+/// * `Base64` - Decode the base64 argument and report its byte count
+/// * `Status` - Report the UART flag register bits
+/// * `Menu` - Open the settings menu
+/// * `Quality` - Report the RX overrun rate
+/// * `Fault` - Arm a one-shot malformed response for host testing
+/// * `Case` - Set the echo case mode from the argument
+/// * `Sweep` - Print every printable ASCII character, optionally repeated
+/// * `Rate` - Report the current receive throughput
+/// * `Name` - Set the device name from the argument, or show it
+/// * `Calc` - Evaluate an integer expression left to right
+/// * `Find` - Search the typed transcript for a hex byte pattern
+/// * `Mem` - Report the approximate free RAM
+/// * `Export` - Stream the typed transcript as continuous hex
+/// * `Profile` - Switch to settings profile a or b
+/// * `IndentUp` - Indent echoed committed lines one more space
+/// * `IndentDown` - Indent echoed committed lines one less space
+/// * `Errors` - List the most recent errors with their timestamps
+/// * `Again` - Re-run the previous command line (`again` or `!!`)
+/// * `Bootsel` - Confirm and request a reboot into the UF2 bootloader
+/// * `Delay` - Set the per-character echo delay in milliseconds, or show it
+/// * `Stats` - Report the receive, echo, and overrun counters
+/// * `Pattern` - Stream the given number of PRBS-7 test pattern bytes
+/// * `Logo` - Print the ASCII-art logo
+/// * `Clear` - Zero the statistic named by the argument
+/// * `FlashInfo` - Report the application image size and CRC-32
+/// * `Baud` - Confirm and switch UART0 to the given baud rate
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum Command {
+    Dump,
+    Reset,
+    Base64,
+    Status,
+    Menu,
+    Quality,
+    Fault,
+    Case,
+    Sweep,
+    Rate,
+    Name,
+    Calc,
+    Find,
+    Mem,
+    Export,
+    Profile,
+    IndentUp,
+    IndentDown,
+    Errors,
+    Again,
+    Bootsel,
+    Delay,
+    Stats,
+    Pattern,
+    Logo,
+    Clear,
+    FlashInfo,
+    Baud,
+}
+
+impl Command {
+    /// Returns whether the command changes device state.
+    ///
+    /// # Details
+    /// Privileged commands are refused in a read-only monitor session.
+    ///
+    /// # Returns
+    /// * `bool` - True if the command needs full access
+    #[allow(dead_code)]
+    pub fn is_privileged(self) -> bool {
+        matches!(
+            self,
+            Command::Reset
+                | Command::Fault
+                | Command::Case
+                | Command::Menu
+                | Command::Name
+                | Command::Profile
+                | Command::IndentUp
+                | Command::IndentDown
+                | Command::Bootsel
+                | Command::Delay
+                | Command::Clear
+                | Command::Baud
+        )
+    }
+
+    /// Returns whether the command disrupts the session.
+    ///
+    /// # Details
+    /// Destructive commands wait for a y/n answer when the controller's
+    /// interlock is enabled.
+    ///
+    /// # Returns
+    /// * `bool` - True if the command resets, reboots, or changes the baud rate
+    #[allow(dead_code)]
+    pub fn is_destructive(self) -> bool {
+        matches!(self, Command::Reset | Command::Bootsel | Command::Baud)
+    }
+}
+
+/// Outcome of running a command line.
+///
+/// # Variants
+/// * `Ok` - The command ran successfully
+/// * `Error` - The command was recognized but failed
+/// * `NotFound` - The line did not name a known command
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum CmdResult {
+    Ok,
+    Error(UartError),
+    NotFound,
+}
+
+impl From<Result<(), UartError>> for CmdResult {
+    /// Classifies a command handler's return value.
+    ///
+    /// # Arguments
+    /// * `result` - Handler result
+    ///
+    /// # Returns
+    /// * `Self` - Ok or Error
+    fn from(result: Result<(), UartError>) -> Self {
+        match result {
+            Ok(()) => CmdResult::Ok,
+            Err(err) => CmdResult::Error(err),
+        }
+    }
+}
+
+impl CmdResult {
+    /// Returns the shell-style exit code.
+    ///
+    /// # Returns
+    /// * `u8` - 0 for Ok, 1 for Error, 127 for NotFound
+    #[allow(dead_code)]
+    pub fn code(self) -> u8 {
+        match self {
+            CmdResult::Ok => 0,
+            CmdResult::Error(_) => 1,
+            CmdResult::NotFound => 127,
+        }
+    }
+
+    /// Converts the outcome back to the controller's error type.
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, the failure, or UnknownCommand
+    #[allow(dead_code)]
+    pub fn into_result(self) -> Result<(), UartError> {
+        match self {
+            CmdResult::Ok => Ok(()),
+            CmdResult::Error(err) => Err(err),
+            CmdResult::NotFound => Err(UartError::UnknownCommand),
+        }
+    }
+
+    /// Formats the status marker line.
+    ///
+    /// # Arguments
+    /// * `out` - Destination buffer
+    ///
+    /// # Returns
+    /// * `usize` - Bytes written, e.g. `[OK]` or `[ERR 127]` and CRLF
+    #[allow(dead_code)]
+    pub fn marker(self, out: &mut [u8]) -> usize {
+        let mut text = FmtBuf::new(out);
+        let result = match self {
+            CmdResult::Ok => write!(text, "[OK]\r\n"),
+            _ => write!(text, "[ERR {}]\r\n", self.code()),
+        };
+        match result {
+            Ok(()) => text.len(),
+            Err(_) => 0,
+        }
+    }
+}
+
+/// Strips leading and trailing ASCII whitespace from a line.
+///
+/// # Arguments
+/// * `line` - Raw line bytes
+///
+/// # Returns
+/// * `&[u8]` - Line without surrounding whitespace
+#[allow(dead_code)]
+pub fn trim(line: &[u8]) -> &[u8] {
+    let start = line
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(line.len());
+    let end = line
+        .iter()
+        .rposition(|b| !b.is_ascii_whitespace())
+        .map_or(start, |i| i + 1);
+    &line[start..end]
+}
+
+/// Splits a line into its command name and argument text.
+///
+/// # Details
+/// The name is the first whitespace-delimited token; the arguments are
+/// the trimmed remainder of the line.
+///
+/// # Arguments
+/// * `line` - Committed line bytes
+///
+/// # Returns
+/// * `(&[u8], &[u8])` - Command name and arguments
+#[allow(dead_code)]
+pub fn split(line: &[u8]) -> (&[u8], &[u8]) {
+    let line = trim(line);
+    match line.iter().position(|b| b.is_ascii_whitespace()) {
+        Some(i) => (&line[..i], trim(&line[i..])),
+        None => (line, &[]),
+    }
+}
+
+/// Checks whether a line requests a software reset.
+///
+/// # Details
+/// Matches the word RESET alone, ignoring surrounding whitespace.
+/// Letter case follows the same rule as every other command name:
+/// exact unless `fold_case` is set. Prefixes and arguments are rejected.
+///
+/// # Arguments
+/// * `line` - Committed line bytes
+/// * `fold_case` - True to match the name case-insensitively
+///
+/// # Returns
+/// * `bool` - True if the line is a reset command
+#[allow(dead_code)]
+pub fn is_reset_command(line: &[u8], fold_case: bool) -> bool {
+    matches_name(trim(line), b"RESET", fold_case)
+}
+
+/// Checks whether a line requests a reboot into the bootloader.
+///
+/// # Details
+/// Matches the word bootsel alone, ignoring surrounding whitespace.
+/// Letter case follows the same rule as every other command name:
+/// exact unless `fold_case` is set. Prefixes and arguments are rejected.
+///
+/// # Arguments
+/// * `line` - Committed line bytes
+/// * `fold_case` - True to match the name case-insensitively
+///
+/// # Returns
+/// * `bool` - True if the line is a bootsel command
+#[allow(dead_code)]
+pub fn is_bootsel_command(line: &[u8], fold_case: bool) -> bool {
+    matches_name(trim(line), b"bootsel", fold_case)
+}
+
+/// Compares a token against a command name.
+///
+/// # Arguments
+/// * `token` - Token typed by the user
+/// * `name` - Command name as documented
+/// * `fold_case` - True to ignore letter case
+///
+/// # Returns
+/// * `bool` - True if the token names the command
+fn matches_name(token: &[u8], name: &[u8], fold_case: bool) -> bool {
+    if fold_case {
+        token.eq_ignore_ascii_case(name)
+    } else {
+        token == name
+    }
+}
+
+/// Parses a committed line into a command.
+///
+/// # Details
+/// The first token selects the command; arguments are not validated here.
+///
+/// # Arguments
+/// * `line` - Committed line bytes
+///
+/// # Returns
+/// * `Option<Command>` - Recognized command, or None
+#[allow(dead_code)]
+pub fn parse(line: &[u8]) -> Option<Command> {
+    parse_folded(line, false)
+}
+
+/// Parses a committed line, optionally ignoring the name's letter case.
+///
+/// # Details
+/// With `fold_case`, only a lowercased copy of the first token is
+/// compared, so the caller's line and its arguments keep their case.
+/// The same rule applies to RESET and bootsel.
+///
+/// # Arguments
+/// * `line` - Committed line bytes
+/// * `fold_case` - True to match command names case-insensitively
+///
+/// # Returns
+/// * `Option<Command>` - Recognized command, or None
+#[allow(dead_code)]
+pub fn parse_folded(line: &[u8], fold_case: bool) -> Option<Command> {
+    if is_reset_command(line, fold_case) {
+        return Some(Command::Reset);
+    }
+    if is_bootsel_command(line, fold_case) {
+        return Some(Command::Bootsel);
+    }
+    let name = split(line).0;
+    if !fold_case {
+        return lookup(name);
+    }
+    if name.len() > MAX_NAME_LEN {
+        return None;
+    }
+    let mut folded = [0u8; MAX_NAME_LEN];
+    folded[..name.len()].copy_from_slice(name);
+    folded.make_ascii_lowercase();
+    lookup(&folded[..name.len()])
+}
+
+/// Looks up a command by its exact name.
+///
+/// # Arguments
+/// * `name` - First token of the line
+///
+/// # Returns
+/// * `Option<Command>` - Command with that name, or None
+fn lookup(name: &[u8]) -> Option<Command> {
+    match name {
+        b"dump" => Some(Command::Dump),
+        b"b64" => Some(Command::Base64),
+        b"status" => Some(Command::Status),
+        b"menu" => Some(Command::Menu),
+        b"quality" => Some(Command::Quality),
+        b"fault" => Some(Command::Fault),
+        b"case" => Some(Command::Case),
+        b"sweep" => Some(Command::Sweep),
+        b"rate" => Some(Command::Rate),
+        b"name" => Some(Command::Name),
+        b"calc" => Some(Command::Calc),
+        b"find" => Some(Command::Find),
+        b"mem" => Some(Command::Mem),
+        b"export" => Some(Command::Export),
+        b"profile" => Some(Command::Profile),
+        b"indent+" => Some(Command::IndentUp),
+        b"indent-" => Some(Command::IndentDown),
+        b"errors" => Some(Command::Errors),
+        b"again" | b"!!" => Some(Command::Again),
+        b"delay" => Some(Command::Delay),
+        b"stats" => Some(Command::Stats),
+        b"pattern" => Some(Command::Pattern),
+        b"logo" => Some(Command::Logo),
+        b"clear" => Some(Command::Clear),
+        b"flashinfo" => Some(Command::FlashInfo),
+        b"baud" => Some(Command::Baud),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Trim Tests ====================
+
+    #[test]
+    fn test_trim_both_ends() {
+        assert_eq!(trim(b"  dump \t"), b"dump");
+    }
+
+    #[test]
+    fn test_trim_all_whitespace() {
+        assert_eq!(trim(b"   "), b"");
+    }
+
+    // ==================== Split Tests ====================
+
+    #[test]
+    fn test_split_name_only() {
+        assert_eq!(split(b" dump "), (&b"dump"[..], &b""[..]));
+    }
+
+    #[test]
+    fn test_split_name_and_args() {
+        assert_eq!(split(b"b64   Zm9v  "), (&b"b64"[..], &b"Zm9v"[..]));
+    }
+
+    // ==================== Reset Recognition Tests ====================
+
+    #[test]
+    fn test_reset_exact_match() {
+        assert!(is_reset_command(b"RESET", false));
+        assert!(is_reset_command(b"RESET", true));
+    }
+
+    #[test]
+    fn test_reset_case_variations_need_folding() {
+        assert!(!is_reset_command(b"reset", false));
+        assert!(!is_reset_command(b"Reset", false));
+        assert!(is_reset_command(b"reset", true));
+        assert!(is_reset_command(b"Reset", true));
+        assert!(is_reset_command(b"rEsEt", true));
+    }
+
+    #[test]
+    fn test_reset_surrounding_whitespace() {
+        assert!(is_reset_command(b"  RESET\t", false));
+    }
+
+    #[test]
+    fn test_reset_near_misses() {
+        for fold_case in [false, true] {
+            assert!(!is_reset_command(b"RESE", fold_case));
+            assert!(!is_reset_command(b"RESETS", fold_case));
+            assert!(!is_reset_command(b"RE SET", fold_case));
+            assert!(!is_reset_command(b"", fold_case));
+        }
+    }
+
+    // ==================== Bootsel Recognition Tests ====================
+
+    #[test]
+    fn test_bootsel_exact_match() {
+        assert!(is_bootsel_command(b"bootsel", false));
+        assert!(is_bootsel_command(b"bootsel", true));
+    }
+
+    #[test]
+    fn test_bootsel_case_variations_need_folding() {
+        assert!(!is_bootsel_command(b"BOOTSEL", false));
+        assert!(is_bootsel_command(b"BOOTSEL", true));
+        assert!(is_bootsel_command(b"BootSel", true));
+    }
+
+    #[test]
+    fn test_bootsel_surrounding_whitespace() {
+        assert!(is_bootsel_command(b" bootsel\r\n", false));
+    }
+
+    #[test]
+    fn test_bootsel_near_misses() {
+        for fold_case in [false, true] {
+            assert!(!is_bootsel_command(b"boots", fold_case));
+            assert!(!is_bootsel_command(b"bootsel now", fold_case));
+            assert!(!is_bootsel_command(b"boot sel", fold_case));
+            assert!(!is_bootsel_command(b"", fold_case));
+        }
+    }
+
+    // ==================== CmdResult Tests ====================
+
+    fn marker(result: CmdResult) -> Vec<u8> {
+        let mut out = [0u8; 16];
+        let n = result.marker(&mut out);
+        out[..n].to_vec()
+    }
+
+    #[test]
+    fn test_cmd_result_from_handler() {
+        assert_eq!(CmdResult::from(Ok(())), CmdResult::Ok);
+        assert_eq!(
+            CmdResult::from(Err(UartError::InvalidArgument)),
+            CmdResult::Error(UartError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn test_cmd_result_codes() {
+        assert_eq!(CmdResult::Ok.code(), 0);
+        assert_eq!(CmdResult::Error(UartError::InvalidArgument).code(), 1);
+        assert_eq!(CmdResult::NotFound.code(), 127);
+    }
+
+    #[test]
+    fn test_cmd_result_into_result() {
+        assert_eq!(CmdResult::Ok.into_result(), Ok(()));
+        assert_eq!(
+            CmdResult::NotFound.into_result(),
+            Err(UartError::UnknownCommand)
+        );
+    }
+
+    #[test]
+    fn test_cmd_result_markers() {
+        assert_eq!(marker(CmdResult::Ok), b"[OK]\r\n");
+        assert_eq!(
+            marker(CmdResult::Error(UartError::PermissionDenied)),
+            b"[ERR 1]\r\n"
+        );
+        assert_eq!(marker(CmdResult::NotFound), b"[ERR 127]\r\n");
+    }
+
+    #[test]
+    fn test_cmd_result_marker_too_small() {
+        let mut out = [0u8; 4];
+        assert_eq!(CmdResult::NotFound.marker(&mut out), 0);
+    }
+
+    // ==================== Privilege Tests ====================
+
+    #[test]
+    fn test_state_changing_commands_privileged() {
+        assert!(Command::Reset.is_privileged());
+        assert!(Command::Fault.is_privileged());
+        assert!(Command::Case.is_privileged());
+        assert!(Command::Menu.is_privileged());
+        assert!(Command::Name.is_privileged());
+        assert!(Command::Profile.is_privileged());
+        assert!(Command::IndentUp.is_privileged());
+        assert!(Command::IndentDown.is_privileged());
+        assert!(Command::Bootsel.is_privileged());
+        assert!(Command::Delay.is_privileged());
+    }
+
+    #[test]
+    fn test_read_only_commands_unprivileged() {
+        assert!(!Command::Status.is_privileged());
+        assert!(!Command::Quality.is_privileged());
+        assert!(!Command::Rate.is_privileged());
+        assert!(!Command::Dump.is_privileged());
+        assert!(!Command::Mem.is_privileged());
+        assert!(!Command::Export.is_privileged());
+    }
+
+    // ==================== Parse Tests ====================
+
+    #[test]
+    fn test_parse_dump() {
+        assert_eq!(parse(b"dump"), Some(Command::Dump));
+    }
+
+    #[test]
+    fn test_parse_status() {
+        assert_eq!(parse(b"status"), Some(Command::Status));
+    }
+
+    #[test]
+    fn test_parse_menu() {
+        assert_eq!(parse(b"menu"), Some(Command::Menu));
+    }
+
+    #[test]
+    fn test_parse_quality() {
+        assert_eq!(parse(b"quality"), Some(Command::Quality));
+    }
+
+    #[test]
+    fn test_parse_fault() {
+        assert_eq!(parse(b"fault"), Some(Command::Fault));
+    }
+
+    #[test]
+    fn test_parse_case() {
+        assert_eq!(parse(b"case upper"), Some(Command::Case));
+    }
+
+    #[test]
+    fn test_parse_sweep() {
+        assert_eq!(parse(b"sweep 2"), Some(Command::Sweep));
+    }
+
+    #[test]
+    fn test_parse_rate() {
+        assert_eq!(parse(b"rate"), Some(Command::Rate));
+    }
+
+    #[test]
+    fn test_parse_mem() {
+        assert_eq!(parse(b"mem"), Some(Command::Mem));
+    }
+
+    #[test]
+    fn test_parse_profile() {
+        assert_eq!(parse(b"profile b"), Some(Command::Profile));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse(b"errors"), Some(Command::Errors));
+    }
+
+    #[test]
+    fn test_parse_logo() {
+        assert_eq!(parse(b"logo"), Some(Command::Logo));
+        assert!(!Command::Logo.is_privileged());
+    }
+
+    #[test]
+    fn test_parse_flashinfo() {
+        assert_eq!(parse(b"flashinfo"), Some(Command::FlashInfo));
+        assert_eq!(parse_folded(b"FlashInfo", true), Some(Command::FlashInfo));
+        assert!(!Command::FlashInfo.is_privileged());
+    }
+
+    #[test]
+    fn test_parse_clear() {
+        assert_eq!(parse(b"clear errors"), Some(Command::Clear));
+        assert!(Command::Clear.is_privileged());
+    }
+
+    #[test]
+    fn test_parse_delay() {
+        assert_eq!(parse(b"delay 250"), Some(Command::Delay));
+    }
+
+    #[test]
+    fn test_parse_stats() {
+        assert_eq!(parse(b"stats"), Some(Command::Stats));
+        assert!(!Command::Stats.is_privileged());
+    }
+
+    #[test]
+    fn test_parse_is_case_sensitive() {
+        assert_eq!(parse(b"STATS"), None);
+        assert_eq!(parse_folded(b"STATS", false), None);
+    }
+
+    #[test]
+    fn test_parse_folded_ignores_name_case() {
+        assert_eq!(parse_folded(b"STATS", true), Some(Command::Stats));
+        assert_eq!(parse_folded(b"stats", true), Some(Command::Stats));
+        assert_eq!(parse_folded(b"Indent+", true), Some(Command::IndentUp));
+        assert_eq!(parse_folded(b"Name Bob", true), Some(Command::Name));
+    }
+
+    #[test]
+    fn test_parse_folded_rejects_long_token() {
+        assert_eq!(parse_folded(b"STATISTICS", true), None);
+    }
+
+    #[test]
+    fn test_parse_pattern() {
+        assert_eq!(parse(b"pattern 64"), Some(Command::Pattern));
+    }
+
+    #[test]
+    fn test_parse_again() {
+        assert_eq!(parse(b"again"), Some(Command::Again));
+        assert_eq!(parse(b" !! "), Some(Command::Again));
+        assert_eq!(parse(b"!"), None);
+    }
+
+    #[test]
+    fn test_parse_indent() {
+        assert_eq!(parse(b"indent+"), Some(Command::IndentUp));
+        assert_eq!(parse(b"indent-"), Some(Command::IndentDown));
+        assert_eq!(parse(b"indent"), None);
+    }
+
+    #[test]
+    fn test_parse_export() {
+        assert_eq!(parse(b"export"), Some(Command::Export));
+    }
+
+    #[test]
+    fn test_parse_name() {
+        assert_eq!(parse(b"name lab1"), Some(Command::Name));
+    }
+
+    #[test]
+    fn test_parse_calc() {
+        assert_eq!(parse(b"calc 2+3"), Some(Command::Calc));
+    }
+
+    #[test]
+    fn test_parse_find() {
+        assert_eq!(parse(b"find 41 42"), Some(Command::Find));
+    }
+
+    #[test]
+    fn test_parse_dump_with_spaces() {
+        assert_eq!(parse(b" dump "), Some(Command::Dump));
+    }
+
+    #[test]
+    fn test_parse_reset() {
+        assert_eq!(parse(b"RESET"), Some(Command::Reset));
+    }
+
+    #[test]
+    fn test_parse_folds_reset_and_bootsel_like_other_names() {
+        assert_eq!(parse(b"reset"), None);
+        assert_eq!(parse(b"BOOTSEL"), None);
+        assert_eq!(parse_folded(b"reset", true), Some(Command::Reset));
+        assert_eq!(parse_folded(b"BOOTSEL", true), Some(Command::Bootsel));
+    }
+
+    #[test]
+    fn test_destructive_commands() {
+        assert!(Command::Reset.is_destructive());
+        assert!(Command::Bootsel.is_destructive());
+        assert!(Command::Baud.is_destructive());
+        assert!(!Command::Case.is_destructive());
+        assert!(!Command::Status.is_destructive());
+    }
+
+    #[test]
+    fn test_parse_baud() {
+        assert_eq!(parse(b"baud 9600"), Some(Command::Baud));
+        assert!(Command::Baud.is_privileged());
+    }
+
+    #[test]
+    fn test_parse_bootsel() {
+        assert_eq!(parse(b"bootsel"), Some(Command::Bootsel));
+    }
+
+    #[test]
+    fn test_parse_b64_with_argument() {
+        assert_eq!(parse(b"b64 Zm9v"), Some(Command::Base64));
+    }
+
+    #[test]
+    fn test_parse_unknown() {
+        assert_eq!(parse(b"dumpx"), None);
+        assert_eq!(parse(b""), None);
+    }
+}
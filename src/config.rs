@@ -38,7 +38,7 @@
 //!
 //! AUTHOR: Kevin Thomas
 //! CREATION DATE: December 4, 2025
-//! UPDATE DATE: December 5, 2025
+//! UPDATE DATE: October 16, 2026
 
 /// Default UART baud rate.
 ///
@@ -71,15 +71,817 @@ pub const BACKSPACE: u8 = 0x08;
 #[allow(dead_code)]
 pub const DELETE: u8 = 0x7F;
 
+/// Escape character code.
+///
+/// # Details
+/// Introduces ANSI/VT100 control sequences such as `ESC[6n`.
+///
+/// # Value
+/// 0x1B
+#[allow(dead_code)]
+pub const ESCAPE: u8 = 0x1B;
+
+/// Bell character code.
+///
+/// # Details
+/// Echoed to reject input, e.g. when the line buffer is full.
+///
+/// # Value
+/// 0x07
+#[allow(dead_code)]
+pub const BELL: u8 = 0x07;
+
 /// Backspace erase sequence: backspace, space, backspace.
 ///
 /// # Details
-/// When echoed to a terminal this sequence erases the previous character.
+/// When echoed to a terminal this sequence erases the previous character.
+///
+/// # Value
+/// [0x08, b' ', 0x08]
+#[allow(dead_code)]
+pub const BACKSPACE_SEQ: [u8; 3] = [0x08, b' ', 0x08];
+
+/// Visible carriage return sequence: literal `\r` then CR.
+///
+/// # Details
+/// Echoed for CR when line endings are shown for debugging.
+///
+/// # Value
+/// [b'\\', b'r', b'\r']
+#[allow(dead_code)]
+pub const VISIBLE_CR_SEQ: [u8; 3] = [b'\\', b'r', b'\r'];
+
+/// Visible line feed sequence: literal `\n` then LF.
+///
+/// # Details
+/// Echoed for LF when line endings are shown for debugging.
+///
+/// # Value
+/// [b'\\', b'n', b'\n']
+#[allow(dead_code)]
+pub const VISIBLE_LF_SEQ: [u8; 3] = [b'\\', b'n', b'\n'];
+
+/// Vertical tab character code.
+///
+/// # Details
+/// Echoed as VT_NEWLINE_SEQ when form control handling is enabled.
+///
+/// # Value
+/// 0x0B
+#[allow(dead_code)]
+pub const VERTICAL_TAB: u8 = 0x0B;
+
+/// Form feed character code.
+///
+/// # Details
+/// Clears the screen when form control handling is enabled.
+///
+/// # Value
+/// 0x0C
+#[allow(dead_code)]
+pub const FORM_FEED: u8 = 0x0C;
+
+/// Ctrl-O character code.
+///
+/// # Details
+/// Toggles echo on and off in echo mode when the toggle key is enabled.
+///
+/// # Value
+/// 0x0F
+#[allow(dead_code)]
+pub const CTRL_O: u8 = 0x0F;
+
+/// Newline sequence echoed for a vertical tab.
+///
+/// # Details
+/// Used when form control handling is enabled.
+///
+/// # Value
+/// [b'\r', b'\n']
+#[allow(dead_code)]
+pub const VT_NEWLINE_SEQ: [u8; 2] = [b'\r', b'\n'];
+
+/// VT100 clear screen and home cursor sequence.
+///
+/// # Details
+/// Echoed for a form feed when form control handling is enabled.
+///
+/// # Value
+/// ESC [ 2 J ESC [ H
+#[allow(dead_code)]
+pub const CLEAR_SCREEN_SEQ: [u8; 7] = [0x1B, b'[', b'2', b'J', 0x1B, b'[', b'H'];
+
+/// VT100 visible bell sequence.
+///
+/// # Details
+/// Switches the screen to reverse video and straight back, which
+/// terminals show as a brief flash.
+///
+/// # Value
+/// ESC [ ? 5 h ESC [ ? 5 l
+#[allow(dead_code)]
+pub const VISIBLE_BELL_SEQ: [u8; 10] = [0x1B, b'[', b'?', b'5', b'h', 0x1B, b'[', b'?', b'5', b'l'];
+
+/// Capacity of the controller's per-character response buffer.
+///
+/// # Details
+/// Bounds the bytes a single received character can produce,
+/// including generated replies such as command output.
+///
+/// # Value
+/// 512 bytes
+#[allow(dead_code)]
+pub const ECHO_BUF_SIZE: usize = 512;
+
+/// Capacity of the line buffer.
+///
+/// # Details
+/// Characters typed beyond this before Enter are rejected with a bell.
+///
+/// # Value
+/// 128 bytes
+#[allow(dead_code)]
+pub const LINE_BUF_SIZE: usize = 128;
+
+/// Default maximum command line length in command mode.
+///
+/// # Details
+/// Shorter than LINE_BUF_SIZE so a runaway paste into the prompt is
+/// cut off early. Characters beyond it are rejected with a bell.
+///
+/// # Value
+/// 64 bytes
+#[allow(dead_code)]
+pub const MAX_CMD_LEN: usize = 64;
+
+/// Capacity of the input queue used while command output is pending.
+///
+/// # Details
+/// Characters typed during command output are held here and replayed
+/// afterwards; further characters are dropped.
+///
+/// # Value
+/// 32 bytes
+#[allow(dead_code)]
+pub const INPUT_BUF_SIZE: usize = 32;
+
+/// Default terminal width used for automatic line wrapping.
+///
+/// # Details
+/// With auto-wrap enabled, a printable character that would land past
+/// this column is preceded by CR LF.
+///
+/// # Value
+/// 80 columns
+#[allow(dead_code)]
+pub const RIGHT_MARGIN: u16 = 80;
+
+/// Prompt shown when entering command mode.
+///
+/// # Details
+/// Emitted on a fresh line after ESC is pressed in echo mode.
+///
+/// # Value
+/// "> "
+#[allow(dead_code)]
+pub const PROMPT: &[u8] = b"> ";
+
+/// Hardware watchdog timeout.
+///
+/// # Details
+/// The chip resets if the main loop stops feeding the watchdog for
+/// this long.
+///
+/// # Value
+/// 2000 milliseconds
+#[allow(dead_code)]
+pub const WATCHDOG_TIMEOUT_MS: u64 = 2000;
+
+/// Interval between watchdog feeds.
+///
+/// # Details
+/// Must stay well below WATCHDOG_TIMEOUT_MS. IDLE_TICK_MS wakes the
+/// loop often enough to feed during quiet periods.
+///
+/// # Value
+/// 500 milliseconds
+#[allow(dead_code)]
+pub const WATCHDOG_FEED_MS: u64 = 500;
+
+/// Settling delay before the echo loop starts.
+///
+/// # Details
+/// For hardware that needs time after power-up. A progress dot is
+/// sent every BOOT_DOT_INTERVAL_MS while waiting. Zero disables it.
+///
+/// # Value
+/// 0 milliseconds (no delay)
+#[allow(dead_code)]
+pub const BOOT_DELAY_MS: u64 = 0;
+
+/// Interval between boot delay progress dots.
+///
+/// # Value
+/// 500 milliseconds
+#[allow(dead_code)]
+pub const BOOT_DOT_INTERVAL_MS: u64 = 500;
+
+/// Byte sent to keep an idle link alive.
+///
+/// # Value
+/// 0x00 (NUL)
+#[allow(dead_code)]
+pub const KEEPALIVE_BYTE: u8 = 0x00;
+
+/// Idle time between keepalive bytes.
+///
+/// # Details
+/// Checked on each IDLE_TICK_MS tick, so keepalives go out with that
+/// granularity. Zero disables them.
+///
+/// # Value
+/// 0 milliseconds (disabled)
+#[allow(dead_code)]
+pub const KEEPALIVE_INTERVAL_MS: u64 = 0;
+
+/// Idle time before the banner is re-sent.
+///
+/// # Details
+/// Checked on each IDLE_TICK_MS tick. The banner is re-sent once per
+/// idle period, so a returning user sees the device identity; the next
+/// received key starts a new period. Zero disables the refresh.
+///
+/// # Value
+/// 0 milliseconds (disabled)
+#[allow(dead_code)]
+pub const BANNER_REFRESH_MS: u64 = 0;
+
+/// Idle time before the screensaver animation starts.
+///
+/// # Details
+/// Checked on each IDLE_TICK_MS tick. The first received key stops the
+/// animation and erases its line. Zero disables the screensaver.
+///
+/// # Value
+/// 0 milliseconds (disabled)
+#[allow(dead_code)]
+pub const SCREENSAVER_IDLE_MS: u64 = 0;
+
+/// Time between screensaver animation frames.
+///
+/// # Value
+/// 100 milliseconds
+#[allow(dead_code)]
+pub const SCREENSAVER_FRAME_MS: u64 = 100;
+
+/// Number of columns the screensaver character bounces across.
+///
+/// # Value
+/// 40 columns
+#[allow(dead_code)]
+pub const SCREENSAVER_WIDTH: usize = 40;
+
+/// Character drawn by the screensaver animation.
+///
+/// # Value
+/// b'*'
+#[allow(dead_code)]
+pub const SCREENSAVER_CHAR: u8 = b'*';
+
+/// Interval between main loop iteration rate reports.
+///
+/// # Details
+/// For gauging CPU headroom. Checked on idle ticks, and the report is
+/// sent to the console. Zero disables it.
+///
+/// # Value
+/// 0 milliseconds (disabled)
+#[allow(dead_code)]
+pub const LOOP_REPORT_MS: u64 = 0;
+
+/// Capacity of the panic report buffer.
+///
+/// # Details
+/// Fits `PANIC at file:line` for typical source paths; longer paths
+/// are reported as plain `PANIC`.
+///
+/// # Value
+/// 96 bytes
+#[allow(dead_code)]
+pub const PANIC_MSG_SIZE: usize = 96;
+
+/// Polls of a full TX FIFO before a panic report byte is dropped.
+///
+/// # Details
+/// Bounds the wait so a wedged UART cannot stop the halt.
+///
+/// # Value
+/// 100000 polls
+#[allow(dead_code)]
+pub const PANIC_TX_SPIN_LIMIT: u32 = 100_000;
+
+/// Width of one byte rate meter bucket.
+///
+/// # Value
+/// 100 milliseconds
+#[allow(dead_code)]
+pub const RATE_BUCKET_MS: u64 = 100;
+
+/// Number of buckets in the byte rate window.
+///
+/// # Details
+/// The rate command averages over RATE_BUCKETS * RATE_BUCKET_MS.
+///
+/// # Value
+/// 10 buckets (1 second)
+#[allow(dead_code)]
+pub const RATE_BUCKETS: usize = 10;
+
+/// Most repetitions accepted by the sweep command.
+///
+/// # Details
+/// Each sweep line is at most 97 bytes. The whole response, including
+/// the CRLF before it, must fit ECHO_BUF_SIZE and so the TX queue.
+///
+/// # Value
+/// 4
+#[allow(dead_code)]
+pub const SWEEP_MAX_REPEAT: usize = 4;
+
+/// The longest sweep response must fit one controller response, which
+/// the TX queue always has room for.
+const _: () = assert!(2 + SWEEP_MAX_REPEAT * 97 <= ECHO_BUF_SIZE && ECHO_BUF_SIZE <= TX_QUEUE_SIZE);
+
+/// How long typed input stays unechoed after a command runs.
+///
+/// # Details
+/// Keeps long command output readable while the user types ahead.
+/// Zero disables muting.
+///
+/// # Value
+/// 0 milliseconds (disabled)
+#[allow(dead_code)]
+pub const MUTE_WINDOW_MS: u64 = 0;
+
+/// Positive acknowledgement sent after a valid CRC frame.
+///
+/// # Value
+/// 0x06
+#[allow(dead_code)]
+pub const ACK: u8 = 0x06;
+
+/// Negative acknowledgement sent for a corrupt CRC frame.
+///
+/// # Value
+/// 0x15
+#[allow(dead_code)]
+pub const NAK: u8 = 0x15;
+
+/// Largest payload accepted in one CRC frame.
+///
+/// # Details
+/// Frames carry a 16-bit length; this bounds the decode buffer.
+///
+/// # Value
+/// 64 bytes
+#[allow(dead_code)]
+pub const FRAME_MAX_PAYLOAD: usize = 64;
+
+/// Default digit width of echoed line numbers.
+///
+/// # Details
+/// Numbers are right-aligned and padded with spaces to this width.
+///
+/// # Value
+/// 3 digits
+#[allow(dead_code)]
+pub const LINE_NUMBER_WIDTH: usize = 3;
+
+/// Largest indent, in spaces, applied to echoed committed lines.
+///
+/// # Value
+/// 16 spaces
+#[allow(dead_code)]
+pub const MAX_INDENT: u8 = 16;
+
+/// Startup banner sent when the device boots.
+///
+/// # Details
+/// Followed by " - name" when a device name is set, and CRLF.
+///
+/// # Value
+/// "RP2350 UART echo"
+#[allow(dead_code)]
+pub const BANNER: &[u8] = b"RP2350 UART echo";
+
+/// ASCII-art logo shown by the logo command.
+///
+/// # Details
+/// Lines are separated by a bare `\n`, which the logo command replaces
+/// with the line ending of the current newline mode.
+///
+/// # Value
+/// Four lines spelling "RP2350"
+#[allow(dead_code)]
+pub const LOGO: &[u8] = b" ___ ___ ___ ____ ___ __\n\
+| _ \\ _ \\_  )__ /| __/  \\\n\
+|   /  _// / |_ \\|__ \\ () |\n\
+|_|_\\_| /___|___/|___/\\__/\n";
+
+/// Longest device name accepted by the name command.
+///
+/// # Value
+/// 16 bytes
+#[allow(dead_code)]
+pub const DEVICE_NAME_SIZE: usize = 16;
+
+/// Buffer size for the assembled command prompt.
+///
+/// # Details
+/// Fits the longest name, a u64 echo count, and any mode label.
+///
+/// # Value
+/// 64 bytes
+#[allow(dead_code)]
+pub const PROMPT_BUF_SIZE: usize = 64;
+
+/// Default welcome shown after a successful login.
+///
+/// # Details
+/// Followed by ", session #N" and CRLF.
+///
+/// # Value
+/// "Welcome"
+#[allow(dead_code)]
+pub const GREETING: &[u8] = b"Welcome";
+
+/// Depth of the RP2350 UART transmit FIFO.
+///
+/// # Details
+/// The PL011 TX FIFO holds 32 bytes.
+///
+/// # Value
+/// 32 bytes
+#[allow(dead_code)]
+pub const TX_FIFO_DEPTH: usize = 32;
+
+/// Minimum free TX FIFO slots required to keep writing echo output.
+///
+/// # Details
+/// Below this the main loop defers output into the TX queue.
+/// The PL011 reports only empty/full, so 1 defers while the FIFO is full.
+///
+/// # Value
+/// 1 slot
+#[allow(dead_code)]
+pub const TX_THROTTLE_THRESHOLD: usize = 1;
+
+/// Wait for each UART0 write to finish transmitting before counting it.
+///
+/// # Details
+/// For strict request/response hosts. Output only counts as flushed
+/// once the UART reports it idle, at the cost of blocking the loop
+/// for the transmit time.
+///
+/// # Value
+/// false
+#[allow(dead_code)]
+pub const FLUSH_ACK: bool = false;
+
+/// Capacity of the deferred transmit queue.
+///
+/// # Details
+/// Holds echo bytes while output is throttled. A response that does
+/// not fit the free space is dropped whole, so the queue holds two
+/// full ECHO_BUF_SIZE responses: one still draining and the next.
+///
+/// # Value
+/// 1024 bytes
+#[allow(dead_code)]
+pub const TX_QUEUE_SIZE: usize = 1024;
+
+/// Every controller response must fit the transmit queue, even with a
+/// previous full response still queued.
+const _: () = assert!(TX_QUEUE_SIZE >= 2 * ECHO_BUF_SIZE);
+
+/// A full-line dump must reach the UART, not just the controller
+/// buffer, so it is checked against the TX queue as well.
+const _: () = assert!(
+    LINE_BUF_SIZE * 3 + PROMPT.len() <= ECHO_BUF_SIZE
+        && LINE_BUF_SIZE * 3 + PROMPT.len() <= TX_QUEUE_SIZE - ECHO_BUF_SIZE
+);
+
+/// Capacity of the DMA receive ring buffer.
+///
+/// # Details
+/// DMA keeps filling the ring while the loop is busy, so bytes are
+/// only lost if the loop lags by more than this many.
+///
+/// # Value
+/// 256 bytes
+#[allow(dead_code)]
+pub const RX_RING_SIZE: usize = 256;
+
+/// Most bytes taken from the receive ring per loop iteration.
+///
+/// # Value
+/// 32 bytes
+#[allow(dead_code)]
+pub const RX_BATCH_SIZE: usize = 32;
+
+/// Longest time the main loop waits for a received byte.
+///
+/// # Details
+/// Bounds the read so deferred work such as draining the TX queue
+/// runs even when the line is idle.
+///
+/// # Value
+/// 10 milliseconds
+#[allow(dead_code)]
+pub const IDLE_TICK_MS: u64 = 10;
+
+/// Input script replayed for demos until someone types.
+///
+/// # Details
+/// Played through the controller as if typed. Leave empty to disable.
+///
+/// # Value
+/// Empty (no demo playback)
+#[allow(dead_code)]
+pub const DEMO_SCRIPT: &[u8] = b"";
+
+/// Delay between demo script characters.
+///
+/// # Details
+/// Paces DEMO_SCRIPT playback to a readable typing speed.
+///
+/// # Value
+/// 150 milliseconds
+#[allow(dead_code)]
+pub const DEMO_INTERVAL_MS: u64 = 150;
+
+/// Number of typed bytes kept for the find command.
+///
+/// # Value
+/// 256 bytes
+#[allow(dead_code)]
+pub const TRANSCRIPT_SIZE: usize = 256;
+
+/// Transcript bytes hex-encoded per export chunk.
+///
+/// # Details
+/// Each chunk produces twice this many hex digits of output.
+///
+/// # Value
+/// 32 bytes
+#[allow(dead_code)]
+pub const EXPORT_CHUNK_SIZE: usize = 32;
+
+/// Test pattern bytes produced per chunk.
+///
+/// # Value
+/// 32 bytes
+#[allow(dead_code)]
+pub const PATTERN_CHUNK_SIZE: usize = 32;
+
+/// Longest test pattern the pattern command accepts.
+///
+/// # Value
+/// 1000000 bytes
+#[allow(dead_code)]
+pub const PATTERN_MAX_BYTES: u32 = 1_000_000;
+
+/// Bytes expanded per chunk when decoding run-length input.
+///
+/// # Value
+/// 16 bytes
+#[allow(dead_code)]
+pub const RLE_CHUNK_SIZE: usize = 16;
+
+/// Number of recent errors kept for the errors command.
+///
+/// # Value
+/// 8 errors
+#[allow(dead_code)]
+pub const ERROR_LOG_SIZE: usize = 8;
+
+/// Largest payload carried by a core1 offload job or result.
+///
+/// # Value
+/// 64 bytes
+#[allow(dead_code)]
+pub const OFFLOAD_PAYLOAD_SIZE: usize = 64;
+
+/// Messages each offload channel holds before senders must wait.
+///
+/// # Value
+/// 4 messages
+#[allow(dead_code)]
+pub const OFFLOAD_QUEUE_DEPTH: usize = 4;
+
+/// Stack reserved for core1 when the `core1-offload` feature is on.
+///
+/// # Value
+/// 4096 bytes
+#[allow(dead_code)]
+pub const CORE1_STACK_SIZE: usize = 4096;
+
+/// Number of recently transmitted bytes remembered by the loopback guard.
+///
+/// # Details
+/// Received bytes matching this window are not re-echoed when TX is
+/// wired to RX for self-test.
+///
+/// # Value
+/// 16
+#[allow(dead_code)]
+pub const RECENT_TX_SIZE: usize = 16;
+
+/// Address where XIP flash is memory-mapped.
+///
+/// # Details
+/// Start of the application image checksummed by the flashinfo
+/// command. Must match the FLASH origin in memory.x.
+///
+/// # Value
+/// 0x10000000
+#[allow(dead_code)]
+pub const FLASH_BASE: usize = 0x1000_0000;
+
+/// GPIO number of the onboard activity LED.
+///
+/// # Details
+/// Blinks on each echoed character. Must match the pin handed to the
+/// LED output in main.rs.
+///
+/// # Value
+/// 25
+#[allow(dead_code)]
+pub const LED_PIN: u8 = 25;
+
+/// GPIO number of the "print screen" button.
+///
+/// # Details
+/// Active low with the internal pull-up. Each press emits the stats
+/// report as if the command had been typed. Must match the pin handed
+/// to the button input in main.rs.
+///
+/// # Value
+/// 15
+#[allow(dead_code)]
+pub const BUTTON_PIN: u8 = 15;
+
+/// Time a button press must be separated from the previous one.
+///
+/// # Details
+/// Contact bounce within this window is ignored.
+///
+/// # Value
+/// 50 milliseconds
+#[allow(dead_code)]
+pub const BUTTON_DEBOUNCE_MS: u64 = 50;
+
+/// GPIO number of the bell buzzer.
+///
+/// # Details
+/// Driven by PWM slice 0 channel A. Must match the pin handed to the
+/// buzzer PWM in main.rs.
+///
+/// # Value
+/// 16
+#[allow(dead_code)]
+pub const BUZZER_PIN: u8 = 16;
+
+/// Length of the buzzer beep for each BEL.
+///
+/// # Details
+/// A BEL (0x07) received or emitted starts a beep of this length; a
+/// later BEL restarts it. Zero disables the buzzer and leaves its PWM
+/// slice and pin unclaimed.
+///
+/// # Value
+/// 0 milliseconds (disabled)
+#[allow(dead_code)]
+pub const BEEP_MS: u64 = 0;
+
+/// Tone frequency of the buzzer beep.
+///
+/// # Details
+/// Sets the PWM period from the system clock; played at 50% duty.
+///
+/// # Value
+/// 4000 Hz
+#[allow(dead_code)]
+pub const BEEP_FREQ_HZ: u32 = 4000;
+
+/// GPIO number of the boot mode select jumper.
+///
+/// # Details
+/// Sampled once at boot with the internal pull-up. Open reads high and
+/// boots into echo mode; a jumper to ground boots command-only. Must
+/// match the pin handed to the mode select input in main.rs.
+///
+/// # Value
+/// 14
+#[allow(dead_code)]
+pub const MODE_SELECT_PIN: u8 = 14;
+
+/// Minimum time the activity LED stays lit after an echo.
+///
+/// # Details
+/// Keeps single keystrokes visible.
+///
+/// # Value
+/// 50 milliseconds
+#[allow(dead_code)]
+pub const LED_ON_TIME_MS: u64 = 50;
+
+/// Window in which an identical repeated byte is ignored as a bounce.
+///
+/// # Details
+/// For noisy relay-driven links that duplicate bytes within
+/// microseconds. 0 disables debouncing.
 ///
 /// # Value
-/// [0x08, b' ', 0x08]
+/// 0 microseconds
 #[allow(dead_code)]
-pub const BACKSPACE_SEQ: [u8; 3] = [0x08, b' ', 0x08];
+pub const DEBOUNCE_US: u64 = 0;
+
+/// Minimum spacing between accepted copies of the same byte.
+///
+/// # Details
+/// Suppresses terminal auto-repeat so a held key yields at most one
+/// character per interval. 0 disables the filter.
+///
+/// # Value
+/// 0 milliseconds
+#[allow(dead_code)]
+pub const REPEAT_FILTER_MS: u64 = 0;
+
+/// Mirror all UART0 output to UART1 as a read-only tap.
+///
+/// # Details
+/// UART1 transmits on GPIO4 at UART_BAUD_RATE. When false, UART1,
+/// GPIO4, and its DMA channel are not claimed.
+///
+/// # Value
+/// false
+#[allow(dead_code)]
+pub const TEE_UART1: bool = false;
+
+/// Delay each echo by a pseudo-random amount to simulate typing.
+///
+/// # Details
+/// A demo effect. Delays come from a generator seeded with GHOST_SEED.
+///
+/// # Value
+/// false
+#[allow(dead_code)]
+pub const GHOST_TYPING: bool = false;
+
+/// Seed of the ghost typing delay generator.
+///
+/// # Value
+/// 0x2350_0001
+#[allow(dead_code)]
+pub const GHOST_SEED: u32 = 0x2350_0001;
+
+/// Shortest ghost typing delay.
+///
+/// # Value
+/// 30 milliseconds
+#[allow(dead_code)]
+pub const GHOST_MIN_DELAY_MS: u32 = 30;
+
+/// Longest ghost typing delay.
+///
+/// # Value
+/// 150 milliseconds
+#[allow(dead_code)]
+pub const GHOST_MAX_DELAY_MS: u32 = 150;
+
+/// The watchdog is fed before each ghost typing delay, so the longest
+/// delay plus one feed interval must fit inside the watchdog timeout.
+const _: () = assert!(GHOST_MAX_DELAY_MS as u64 + WATCHDOG_FEED_MS < WATCHDOG_TIMEOUT_MS);
+
+/// Longest echo delay accepted by the delay command.
+///
+/// # Value
+/// 1000 milliseconds
+#[allow(dead_code)]
+pub const MAX_ECHO_DELAY_MS: u32 = 1000;
+
+/// The watchdog is fed before each echo delay, so the longest delay
+/// plus one feed interval must fit inside the watchdog timeout.
+const _: () = assert!(MAX_ECHO_DELAY_MS as u64 + WATCHDOG_FEED_MS < WATCHDOG_TIMEOUT_MS);
+
+/// Baud rates accepted by the baud command.
+///
+/// # Details
+/// Standard rates a terminal can be switched to; anything else is
+/// rejected before the interlock prompt.
+#[allow(dead_code)]
+pub const BAUD_RATES: [u32; 8] = [9600, 19200, 38400, 57600, 115200, 230400, 460800, 921600];
 
 #[cfg(test)]
 mod tests {
@@ -102,6 +904,343 @@ mod tests {
         assert_eq!(DELETE, 0x7F);
     }
 
+    #[test]
+    fn test_escape_value() {
+        assert_eq!(ESCAPE, 0x1B);
+    }
+
+    #[test]
+    fn test_bell_value() {
+        assert_eq!(BELL, 0x07);
+    }
+
+    #[test]
+    fn test_echo_buf_size_fits_sequences() {
+        assert!(ECHO_BUF_SIZE >= BACKSPACE_SEQ.len());
+        assert!(ECHO_BUF_SIZE >= b"\x1b[65535;65535R".len());
+    }
+
+    #[test]
+    fn test_echo_buf_size_fits_line_dump() {
+        assert!(ECHO_BUF_SIZE >= LINE_BUF_SIZE * 3 + PROMPT.len());
+    }
+
+    #[test]
+    fn test_tx_queue_fits_line_dump() {
+        let dump = LINE_BUF_SIZE * 3 + PROMPT.len();
+        assert!(TX_QUEUE_SIZE - ECHO_BUF_SIZE >= dump);
+    }
+
+    #[test]
+    fn test_line_buf_size_value() {
+        assert_eq!(LINE_BUF_SIZE, 128);
+    }
+
+    #[test]
+    fn test_input_buf_size_value() {
+        assert_eq!(INPUT_BUF_SIZE, 32);
+    }
+
+    #[test]
+    fn test_right_margin_value() {
+        assert_eq!(RIGHT_MARGIN, 80);
+    }
+
+    #[test]
+    fn test_prompt_value() {
+        assert_eq!(PROMPT, b"> ");
+    }
+
+    #[test]
+    fn test_ack_nak_values() {
+        assert_eq!(ACK, 0x06);
+        assert_eq!(NAK, 0x15);
+    }
+
+    #[test]
+    fn test_frame_max_payload_value() {
+        assert_eq!(FRAME_MAX_PAYLOAD, 64);
+    }
+
+    #[test]
+    fn test_line_number_width_value() {
+        assert_eq!(LINE_NUMBER_WIDTH, 3);
+    }
+
+    #[test]
+    fn test_max_indent_value() {
+        assert_eq!(MAX_INDENT, 16);
+    }
+
+    #[test]
+    fn test_banner_value() {
+        assert_eq!(BANNER, b"RP2350 UART echo");
+    }
+
+    #[test]
+    fn test_logo_lines() {
+        assert_eq!(LOGO.iter().filter(|&&b| b == b'\n').count(), 4);
+        assert!(LOGO.ends_with(b"\n"));
+        assert!(!LOGO.contains(&b'\r'));
+        assert!(LOGO
+            .iter()
+            .all(|&b| b == b'\n' || (0x20..=0x7E).contains(&b)));
+    }
+
+    #[test]
+    fn test_device_name_size_value() {
+        assert_eq!(DEVICE_NAME_SIZE, 16);
+    }
+
+    #[test]
+    fn test_prompt_buf_size_value() {
+        assert_eq!(PROMPT_BUF_SIZE, 64);
+    }
+
+    #[test]
+    fn test_greeting_value() {
+        assert_eq!(GREETING, b"Welcome");
+    }
+
+    #[test]
+    fn test_tx_fifo_depth() {
+        assert_eq!(TX_FIFO_DEPTH, 32);
+    }
+
+    #[test]
+    fn test_tx_throttle_threshold_value() {
+        assert_eq!(TX_THROTTLE_THRESHOLD, 1);
+    }
+
+    #[test]
+    fn test_tx_queue_size_value() {
+        assert_eq!(TX_QUEUE_SIZE, 1024);
+    }
+
+    #[test]
+    fn test_rx_ring_values() {
+        assert_eq!(RX_RING_SIZE, 256);
+        assert_eq!(RX_BATCH_SIZE, 32);
+    }
+
+    #[test]
+    fn test_idle_tick_value() {
+        assert_eq!(IDLE_TICK_MS, 10);
+    }
+
+    #[test]
+    fn test_demo_script_disabled_by_default() {
+        assert!(DEMO_SCRIPT.is_empty());
+    }
+
+    #[test]
+    fn test_demo_interval_value() {
+        assert_eq!(DEMO_INTERVAL_MS, 150);
+    }
+
+    #[test]
+    fn test_recent_tx_size_value() {
+        assert_eq!(RECENT_TX_SIZE, 16);
+    }
+
+    #[test]
+    fn test_flash_base_value() {
+        assert_eq!(FLASH_BASE, 0x1000_0000);
+    }
+
+    #[test]
+    fn test_led_pin_value() {
+        assert_eq!(LED_PIN, 25);
+    }
+
+    #[test]
+    fn test_button_values() {
+        assert_eq!(BUTTON_PIN, 15);
+        assert_eq!(BUTTON_DEBOUNCE_MS, 50);
+    }
+
+    #[test]
+    fn test_buzzer_values() {
+        assert_eq!(BUZZER_PIN, 16);
+        assert_eq!(BEEP_MS, 0);
+        assert_eq!(BEEP_FREQ_HZ, 4000);
+    }
+
+    #[test]
+    fn test_mode_select_pin_value() {
+        assert_eq!(MODE_SELECT_PIN, 14);
+    }
+
+    #[test]
+    fn test_rle_chunk_size_value() {
+        assert_eq!(RLE_CHUNK_SIZE, 16);
+    }
+
+    #[test]
+    fn test_error_log_size_value() {
+        assert_eq!(ERROR_LOG_SIZE, 8);
+    }
+
+    #[test]
+    fn test_offload_payload_size_value() {
+        assert_eq!(OFFLOAD_PAYLOAD_SIZE, 64);
+    }
+
+    #[test]
+    fn test_offload_queue_depth_value() {
+        assert_eq!(OFFLOAD_QUEUE_DEPTH, 4);
+    }
+
+    #[test]
+    fn test_core1_stack_size_value() {
+        assert_eq!(CORE1_STACK_SIZE, 4096);
+    }
+
+    #[test]
+    fn test_max_cmd_len_value() {
+        assert_eq!(MAX_CMD_LEN, 64);
+    }
+
+    #[test]
+    fn test_max_cmd_len_below_line_size() {
+        let (cmd, line) = (MAX_CMD_LEN, LINE_BUF_SIZE);
+        assert!(cmd < line);
+    }
+
+    #[test]
+    fn test_transcript_size_value() {
+        assert_eq!(TRANSCRIPT_SIZE, 256);
+    }
+
+    #[test]
+    fn test_export_chunk_size_value() {
+        assert_eq!(EXPORT_CHUNK_SIZE, 32);
+    }
+
+    #[test]
+    fn test_pattern_values() {
+        assert_eq!(PATTERN_CHUNK_SIZE, 32);
+        assert_eq!(PATTERN_MAX_BYTES, 1_000_000);
+    }
+
+    #[test]
+    fn test_led_on_time_value() {
+        assert_eq!(LED_ON_TIME_MS, 50);
+    }
+
+    #[test]
+    fn test_debounce_disabled_by_default() {
+        assert_eq!(DEBOUNCE_US, 0);
+    }
+
+    #[test]
+    fn test_repeat_filter_disabled_by_default() {
+        assert_eq!(REPEAT_FILTER_MS, 0);
+    }
+
+    #[test]
+    fn test_flush_ack_disabled_by_default() {
+        let enabled = FLUSH_ACK;
+        assert!(!enabled);
+    }
+
+    #[test]
+    fn test_tee_disabled_by_default() {
+        let enabled = TEE_UART1;
+        assert!(!enabled);
+    }
+
+    #[test]
+    fn test_ghost_typing_disabled_by_default() {
+        let enabled = GHOST_TYPING;
+        assert!(!enabled);
+    }
+
+    #[test]
+    fn test_ghost_seed_value() {
+        assert_eq!(GHOST_SEED, 0x2350_0001);
+    }
+
+    #[test]
+    fn test_ghost_delay_values() {
+        assert_eq!(GHOST_MIN_DELAY_MS, 30);
+        assert_eq!(GHOST_MAX_DELAY_MS, 150);
+    }
+
+    #[test]
+    fn test_max_echo_delay_value() {
+        assert_eq!(MAX_ECHO_DELAY_MS, 1000);
+    }
+
+    #[test]
+    fn test_baud_rates_include_default() {
+        assert!(BAUD_RATES.contains(&UART_BAUD_RATE));
+    }
+
+    #[test]
+    fn test_watchdog_values() {
+        assert_eq!(WATCHDOG_TIMEOUT_MS, 2000);
+        assert_eq!(WATCHDOG_FEED_MS, 500);
+    }
+
+    #[test]
+    fn test_watchdog_feed_inside_timeout() {
+        let (feed, timeout) = (WATCHDOG_FEED_MS, WATCHDOG_TIMEOUT_MS);
+        assert!(feed + IDLE_TICK_MS < timeout);
+    }
+
+    #[test]
+    fn test_keepalive_values() {
+        assert_eq!(KEEPALIVE_BYTE, 0x00);
+        assert_eq!(KEEPALIVE_INTERVAL_MS, 0);
+    }
+
+    #[test]
+    fn test_banner_refresh_ms_value() {
+        assert_eq!(BANNER_REFRESH_MS, 0);
+    }
+
+    #[test]
+    fn test_screensaver_values() {
+        assert_eq!(SCREENSAVER_IDLE_MS, 0);
+        assert_eq!(SCREENSAVER_FRAME_MS, 100);
+        assert_eq!(SCREENSAVER_WIDTH, 40);
+        assert_eq!(SCREENSAVER_CHAR, b'*');
+    }
+
+    #[test]
+    fn test_loop_report_disabled_by_default() {
+        assert_eq!(LOOP_REPORT_MS, 0);
+    }
+
+    #[test]
+    fn test_boot_delay_disabled_by_default() {
+        assert_eq!(BOOT_DELAY_MS, 0);
+        assert_eq!(BOOT_DOT_INTERVAL_MS, 500);
+    }
+
+    #[test]
+    fn test_panic_values() {
+        assert_eq!(PANIC_MSG_SIZE, 96);
+        assert_eq!(PANIC_TX_SPIN_LIMIT, 100_000);
+    }
+
+    #[test]
+    fn test_rate_window_values() {
+        assert_eq!(RATE_BUCKET_MS, 100);
+        assert_eq!(RATE_BUCKETS, 10);
+    }
+
+    #[test]
+    fn test_sweep_max_repeat_value() {
+        assert_eq!(SWEEP_MAX_REPEAT, 4);
+    }
+
+    #[test]
+    fn test_mute_disabled_by_default() {
+        assert_eq!(MUTE_WINDOW_MS, 0);
+    }
+
     #[test]
     fn test_backspace_seq_length() {
         assert_eq!(BACKSPACE_SEQ.len(), 3);
@@ -141,4 +1280,50 @@ mod tests {
     fn test_backspace_seq_full() {
         assert_eq!(BACKSPACE_SEQ, [0x08, b' ', 0x08]);
     }
+
+    #[test]
+    fn test_visible_cr_seq_full() {
+        assert_eq!(&VISIBLE_CR_SEQ, b"\\r\r");
+    }
+
+    #[test]
+    fn test_visible_lf_seq_full() {
+        assert_eq!(&VISIBLE_LF_SEQ, b"\\n\n");
+    }
+
+    #[test]
+    fn test_visible_seqs_end_with_real_ending() {
+        assert_eq!(VISIBLE_CR_SEQ[2], b'\r');
+        assert_eq!(VISIBLE_LF_SEQ[2], b'\n');
+    }
+
+    #[test]
+    fn test_vertical_tab_value() {
+        assert_eq!(VERTICAL_TAB, 0x0B);
+    }
+
+    #[test]
+    fn test_form_feed_value() {
+        assert_eq!(FORM_FEED, 0x0C);
+    }
+
+    #[test]
+    fn test_ctrl_o_value() {
+        assert_eq!(CTRL_O, 0x0F);
+    }
+
+    #[test]
+    fn test_vt_newline_seq_full() {
+        assert_eq!(&VT_NEWLINE_SEQ, b"\r\n");
+    }
+
+    #[test]
+    fn test_clear_screen_seq_full() {
+        assert_eq!(&CLEAR_SCREEN_SEQ, b"\x1b[2J\x1b[H");
+    }
+
+    #[test]
+    fn test_visible_bell_seq_full() {
+        assert_eq!(&VISIBLE_BELL_SEQ, b"\x1b[?5h\x1b[?5l");
+    }
 }
@@ -0,0 +1,164 @@
+/*
+ * @file crc.rs
+ * @brief CRC-16 checksum
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: crc.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART CRC Checksums.
+//!
+//! BRIEF:
+//! Computes CRC-16/CCITT-FALSE and CRC-32 checksums over byte slices.
+//! Used to verify framed payloads and report the flash image.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// CRC-16/CCITT generator polynomial.
+///
+/// # Value
+/// 0x1021
+#[allow(dead_code)]
+pub const CRC16_POLY: u16 = 0x1021;
+
+/// CRC-16/CCITT-FALSE initial register value.
+///
+/// # Value
+/// 0xFFFF
+#[allow(dead_code)]
+pub const CRC16_INIT: u16 = 0xFFFF;
+
+/// Computes the CRC-16/CCITT-FALSE checksum of a byte slice.
+///
+/// # Details
+/// MSB-first, no reflection, no final XOR. Computed bitwise to avoid
+/// a 512-byte lookup table.
+///
+/// # Arguments
+/// * `data` - Bytes to checksum
+///
+/// # Returns
+/// * `u16` - Checksum, e.g. 0x29B1 for b"123456789"
+#[allow(dead_code)]
+pub fn crc16(data: &[u8]) -> u16 {
+    let mut crc = CRC16_INIT;
+    for &b in data {
+        crc ^= u16::from(b) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ CRC16_POLY
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+/// CRC-32 (IEEE 802.3) reflected generator polynomial.
+///
+/// # Value
+/// 0xEDB88320
+#[allow(dead_code)]
+pub const CRC32_POLY: u32 = 0xEDB8_8320;
+
+/// Computes the CRC-32 checksum of a byte slice.
+///
+/// # Details
+/// The zlib/IEEE variant: reflected, initial value and final XOR of
+/// 0xFFFFFFFF, so a host can compare against `zlib.crc32`. Computed
+/// bitwise to avoid a 1 KiB lookup table.
+///
+/// # Arguments
+/// * `data` - Bytes to checksum
+///
+/// # Returns
+/// * `u32` - Checksum, e.g. 0xCBF43926 for b"123456789"
+#[allow(dead_code)]
+pub fn crc32(data: &[u8]) -> u32 {
+    let mut crc = u32::MAX;
+    for &b in data {
+        crc ^= u32::from(b);
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ CRC32_POLY
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== CRC-16 Tests ====================
+
+    #[test]
+    fn test_crc16_check_value() {
+        assert_eq!(crc16(b"123456789"), 0x29B1);
+    }
+
+    #[test]
+    fn test_crc16_empty_is_init() {
+        assert_eq!(crc16(b""), CRC16_INIT);
+    }
+
+    #[test]
+    fn test_crc16_detects_bit_flip() {
+        assert_ne!(crc16(b"hello"), crc16(b"hellm"));
+    }
+
+    // ==================== CRC-32 Tests ====================
+
+    #[test]
+    fn test_crc32_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_crc32_empty_is_zero() {
+        assert_eq!(crc32(b""), 0);
+    }
+
+    #[test]
+    fn test_crc32_known_text() {
+        assert_eq!(
+            crc32(b"The quick brown fox jumps over the lazy dog"),
+            0x414F_A339
+        );
+    }
+
+    #[test]
+    fn test_crc32_detects_bit_flip() {
+        assert_ne!(crc32(b"hello"), crc32(b"hellm"));
+    }
+}
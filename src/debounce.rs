@@ -0,0 +1,93 @@
+/*
+ * @file debounce.rs
+ * @brief Input debouncing
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: debounce.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Input Debouncing.
+//!
+//! BRIEF:
+//! Decides whether a received byte is a spurious repeat on a noisy link.
+//! Only identical bytes inside DEBOUNCE_US are dropped, so fast typing
+//! of different keys is never filtered.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Decides whether a received byte is a bounce of the previous one.
+///
+/// # Details
+/// A byte identical to the previous one that arrives less than
+/// `window_us` later is a bounce. A zero window disables debouncing.
+///
+/// # Arguments
+/// * `last_us` - Arrival time of the previous byte
+/// * `now_us` - Arrival time of this byte
+/// * `window_us` - Debounce window
+/// * `same` - Whether this byte equals the previous byte
+///
+/// # Returns
+/// * `bool` - True if the byte should be ignored
+#[allow(dead_code)]
+pub fn is_bounce(last_us: u64, now_us: u64, window_us: u64, same: bool) -> bool {
+    same && now_us.saturating_sub(last_us) < window_us
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Bounce Tests ====================
+
+    #[test]
+    fn test_same_byte_within_window_is_bounce() {
+        assert!(is_bounce(1000, 1010, 50, true));
+    }
+
+    #[test]
+    fn test_same_byte_at_window_edge_not_bounce() {
+        assert!(!is_bounce(1000, 1050, 50, true));
+    }
+
+    #[test]
+    fn test_same_byte_after_window_not_bounce() {
+        assert!(!is_bounce(1000, 2000, 50, true));
+    }
+
+    #[test]
+    fn test_different_byte_never_bounce() {
+        assert!(!is_bounce(1000, 1001, 50, false));
+    }
+
+    #[test]
+    fn test_zero_window_disables() {
+        assert!(!is_bounce(1000, 1000, 0, true));
+    }
+}
@@ -0,0 +1,203 @@
+/*
+ * @file errlog.rs
+ * @brief Recent error history
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: errlog.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Error Log.
+//!
+//! BRIEF:
+//! Keeps the most recent UART errors with the time each occurred.
+//! Older entries are evicted once the ring is full.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+use crate::error::UartError;
+
+/// Ring of the most recent `N` errors and their timestamps.
+///
+/// # Details
+/// Once full, each new error overwrites the oldest. Index 0 of get()
+/// is always the oldest retained entry.
+///
+/// # Fields
+/// * `entries` - Ring storage of error and milliseconds since boot
+/// * `start` - Index of the oldest entry in `entries`
+/// * `len` - Number of valid entries
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct ErrorLog<const N: usize> {
+    entries: [(UartError, u64); N],
+    start: usize,
+    len: usize,
+}
+
+impl<const N: usize> Default for ErrorLog<N> {
+    /// Returns default ErrorLog instance.
+    ///
+    /// # Details
+    /// Delegates to new() for initialization.
+    ///
+    /// # Returns
+    /// * `Self` - New empty ErrorLog
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> ErrorLog<N> {
+    /// Creates an empty error log.
+    ///
+    /// # Returns
+    /// * `Self` - New ErrorLog with no entries
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self {
+            entries: [(UartError::BufferTooSmall, 0); N],
+            start: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of retained errors.
+    ///
+    /// # Returns
+    /// * `usize` - Entries in the log
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the log is empty.
+    ///
+    /// # Returns
+    /// * `bool` - True if no error has been recorded
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Records an error, evicting the oldest when full.
+    ///
+    /// # Arguments
+    /// * `err` - The error that occurred
+    /// * `ms` - Milliseconds since boot when it occurred
+    #[allow(dead_code)]
+    pub fn record(&mut self, err: UartError, ms: u64) {
+        if N == 0 {
+            return;
+        }
+        if self.len == N {
+            self.entries[self.start] = (err, ms);
+            self.start = (self.start + 1) % N;
+        } else {
+            self.entries[(self.start + self.len) % N] = (err, ms);
+            self.len += 1;
+        }
+    }
+
+    /// Removes every entry.
+    #[allow(dead_code)]
+    pub fn clear(&mut self) {
+        self.start = 0;
+        self.len = 0;
+    }
+
+    /// Returns the entry at a logical index.
+    ///
+    /// # Arguments
+    /// * `index` - Index from the oldest entry
+    ///
+    /// # Returns
+    /// * `Option<(UartError, u64)>` - Error and timestamp, or None past the end
+    #[allow(dead_code)]
+    pub fn get(&self, index: usize) -> Option<(UartError, u64)> {
+        (index < self.len).then(|| self.entries[(self.start + index) % N])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Record Tests ====================
+
+    #[test]
+    fn test_new_is_empty() {
+        let log: ErrorLog<4> = ErrorLog::new();
+        assert!(log.is_empty());
+        assert_eq!(log.get(0), None);
+    }
+
+    #[test]
+    fn test_records_in_order() {
+        let mut log: ErrorLog<4> = ErrorLog::new();
+        log.record(UartError::LineOverflow, 10);
+        log.record(UartError::UnknownCommand, 20);
+        log.record(UartError::RxOverrun, 30);
+        assert_eq!(log.len(), 3);
+        assert_eq!(log.get(0), Some((UartError::LineOverflow, 10)));
+        assert_eq!(log.get(1), Some((UartError::UnknownCommand, 20)));
+        assert_eq!(log.get(2), Some((UartError::RxOverrun, 30)));
+        assert_eq!(log.get(3), None);
+    }
+
+    #[test]
+    fn test_wraparound_evicts_oldest() {
+        let mut log: ErrorLog<2> = ErrorLog::new();
+        log.record(UartError::LineOverflow, 1);
+        log.record(UartError::UnknownCommand, 2);
+        log.record(UartError::CorruptFrame, 3);
+        assert_eq!(log.len(), 2);
+        assert_eq!(log.get(0), Some((UartError::UnknownCommand, 2)));
+        assert_eq!(log.get(1), Some((UartError::CorruptFrame, 3)));
+    }
+
+    #[test]
+    fn test_clear_empties_log() {
+        let mut log: ErrorLog<2> = ErrorLog::new();
+        log.record(UartError::LineOverflow, 1);
+        log.record(UartError::UnknownCommand, 2);
+        log.record(UartError::CorruptFrame, 3);
+        log.clear();
+        assert!(log.is_empty());
+        assert_eq!(log.get(0), None);
+        log.record(UartError::RxOverrun, 4);
+        assert_eq!(log.get(0), Some((UartError::RxOverrun, 4)));
+    }
+
+    #[test]
+    fn test_zero_capacity_records_nothing() {
+        let mut log: ErrorLog<0> = ErrorLog::new();
+        log.record(UartError::LineOverflow, 1);
+        assert!(log.is_empty());
+    }
+}
@@ -0,0 +1,155 @@
+/*
+ * @file error.rs
+ * @brief UART driver error type
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: error.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Driver Error Type.
+//!
+//! BRIEF:
+//! Defines the unified error enum returned by fallible controller methods.
+//! Allocation-free so it can be used from no_std code.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Errors reported by fallible UART controller operations.
+///
+/// # Details
+/// Returned by the `try_*` methods so callers can react to conditions
+/// that the infallible API silently drops.
+///
+/// # Variants
+/// * `UnsupportedByte` - Received byte has no echo mapping
+/// * `BufferTooSmall` - Caller output buffer cannot hold the echo
+/// * `TxQueueFull` - Transmit queue has no room for the response
+/// * `LineOverflow` - Line buffer is full and the character was rejected
+/// * `UnknownCommand` - Command-mode line did not match any command
+/// * `InvalidArgument` - Command arguments could not be parsed
+/// * `InputOverflow` - Input queue is full while output is pending
+/// * `BudgetExhausted` - Session byte or command budget has been used up
+/// * `CorruptFrame` - Received frame failed its CRC check
+/// * `PermissionDenied` - Command needs more privilege than the session has
+/// * `RxOverrun` - UART driver reported a receive overrun
+/// * `ParityError` - Received byte failed the software parity check
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum UartError {
+    UnsupportedByte(u8),
+    BufferTooSmall,
+    TxQueueFull,
+    LineOverflow,
+    UnknownCommand,
+    InvalidArgument,
+    InputOverflow,
+    BudgetExhausted,
+    CorruptFrame,
+    PermissionDenied,
+    RxOverrun,
+    ParityError,
+}
+
+impl UartError {
+    /// Returns a short human-readable name for the error.
+    ///
+    /// # Returns
+    /// * `&'static str` - Lowercase name, e.g. `line overflow`
+    #[allow(dead_code)]
+    pub fn name(self) -> &'static str {
+        match self {
+            UartError::UnsupportedByte(_) => "unsupported byte",
+            UartError::BufferTooSmall => "buffer too small",
+            UartError::TxQueueFull => "tx queue full",
+            UartError::LineOverflow => "line overflow",
+            UartError::UnknownCommand => "unknown command",
+            UartError::InvalidArgument => "invalid argument",
+            UartError::InputOverflow => "input overflow",
+            UartError::BudgetExhausted => "budget exhausted",
+            UartError::CorruptFrame => "corrupt frame",
+            UartError::PermissionDenied => "permission denied",
+            UartError::RxOverrun => "rx overrun",
+            UartError::ParityError => "parity error",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== UartError Tests ====================
+
+    #[test]
+    fn test_unsupported_byte_carries_value() {
+        assert_eq!(
+            UartError::UnsupportedByte(0x01),
+            UartError::UnsupportedByte(0x01)
+        );
+        assert_ne!(
+            UartError::UnsupportedByte(0x01),
+            UartError::UnsupportedByte(0x02)
+        );
+    }
+
+    #[test]
+    fn test_variants_not_equal() {
+        assert_ne!(UartError::UnsupportedByte(0x00), UartError::BufferTooSmall);
+        assert_ne!(UartError::BufferTooSmall, UartError::TxQueueFull);
+        assert_ne!(UartError::LineOverflow, UartError::UnknownCommand);
+        assert_ne!(UartError::UnknownCommand, UartError::InvalidArgument);
+        assert_ne!(UartError::InvalidArgument, UartError::InputOverflow);
+        assert_ne!(UartError::InputOverflow, UartError::BudgetExhausted);
+        assert_ne!(UartError::BudgetExhausted, UartError::CorruptFrame);
+        assert_ne!(UartError::CorruptFrame, UartError::PermissionDenied);
+        assert_ne!(UartError::PermissionDenied, UartError::RxOverrun);
+        assert_ne!(UartError::RxOverrun, UartError::ParityError);
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(UartError::UnsupportedByte(0x01).name(), "unsupported byte");
+        assert_eq!(UartError::LineOverflow.name(), "line overflow");
+        assert_eq!(UartError::RxOverrun.name(), "rx overrun");
+        assert_eq!(UartError::ParityError.name(), "parity error");
+    }
+
+    #[test]
+    fn test_copy() {
+        let err = UartError::BufferTooSmall;
+        let copied = err;
+        assert_eq!(err, copied);
+    }
+
+    #[test]
+    fn test_debug_format() {
+        let debug_str = format!("{:?}", UartError::BufferTooSmall);
+        assert!(debug_str.contains("BufferTooSmall"));
+    }
+}
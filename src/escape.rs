@@ -0,0 +1,372 @@
+/*
+ * @file escape.rs
+ * @brief ANSI escape sequence parser
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: escape.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART ANSI Escape Sequence Parser.
+//!
+//! BRIEF:
+//! Recognizes ESC/CSI sequences arriving one byte at a time.
+//! Reports complete sequences so the controller can answer or swallow them.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+use crate::config::ESCAPE;
+
+/// Result of feeding one byte to the escape parser.
+///
+/// # Variants
+/// * `NotEscape` - Byte is not part of a sequence, process normally
+/// * `Pending` - Byte was consumed by an incomplete sequence
+/// * `LoneEscape` - Preceding ESC was a keypress; process this byte normally
+/// * `CursorPositionRequest` - Completed `ESC[6n` device status report query
+/// * `SoftReset` - Second `ESC R` directly after a first; the R is consumed
+/// * `Unrecognized` - Completed a sequence this parser does not handle
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum EscapeEvent {
+    NotEscape,
+    Pending,
+    LoneEscape,
+    CursorPositionRequest,
+    SoftReset,
+    Unrecognized,
+}
+
+/// Parser position within an escape sequence.
+///
+/// # Variants
+/// * `Idle` - Outside any sequence
+/// * `Escape` - ESC received, awaiting the next byte
+/// * `Csi` - `ESC[` received, collecting parameters
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum EscapeState {
+    Idle,
+    Escape,
+    Csi,
+}
+
+/// Byte-at-a-time ANSI escape sequence parser.
+///
+/// # Details
+/// Tracks ESC and CSI (`ESC[`) sequences and the first numeric parameter.
+/// An ESC not followed by `[` is reported as a lone ESC keypress, either
+/// when the next byte arrives or when the line goes idle. `ESC R ESC R`
+/// with no other byte or idle tick between reports a soft reset.
+///
+/// # Fields
+/// * `state` - Current position within a sequence
+/// * `param` - First numeric CSI parameter
+/// * `extra_params` - True if more than one parameter was received
+/// * `reset_armed` - True directly after an `ESC R`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct EscapeParser {
+    state: EscapeState,
+    param: u16,
+    extra_params: bool,
+    reset_armed: bool,
+}
+
+impl Default for EscapeParser {
+    /// Returns default EscapeParser instance.
+    ///
+    /// # Details
+    /// Delegates to new() for initialization.
+    ///
+    /// # Returns
+    /// * `Self` - New idle EscapeParser
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EscapeParser {
+    /// Creates an idle escape parser.
+    ///
+    /// # Returns
+    /// * `Self` - New EscapeParser outside any sequence
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self {
+            state: EscapeState::Idle,
+            param: 0,
+            extra_params: false,
+            reset_armed: false,
+        }
+    }
+
+    /// Returns whether a sequence is currently in progress.
+    ///
+    /// # Returns
+    /// * `bool` - True if bytes are being consumed by a sequence
+    #[allow(dead_code)]
+    pub fn in_sequence(&self) -> bool {
+        self.state != EscapeState::Idle
+    }
+
+    /// Resolves a pending sequence when no further bytes arrive.
+    ///
+    /// # Details
+    /// Called on an idle tick. A waiting ESC becomes a keypress and
+    /// a partial CSI sequence is discarded. A pause also disarms the
+    /// soft reset sequence.
+    ///
+    /// # Returns
+    /// * `bool` - True if a lone ESC keypress was pending
+    #[allow(dead_code)]
+    pub fn expire(&mut self) -> bool {
+        let lone = self.state == EscapeState::Escape;
+        self.state = EscapeState::Idle;
+        self.reset_armed = false;
+        lone
+    }
+
+    /// Feeds one received byte to the parser.
+    ///
+    /// # Details
+    /// CSI parameter and intermediate bytes (0x20-0x3F) are consumed;
+    /// a final byte (0x40-0x7E) completes the sequence.
+    ///
+    /// # Arguments
+    /// * `ch` - The byte received
+    ///
+    /// # Returns
+    /// * `EscapeEvent` - How the controller should treat the byte
+    #[allow(dead_code)]
+    pub fn feed(&mut self, ch: u8) -> EscapeEvent {
+        if ch == ESCAPE {
+            self.state = EscapeState::Escape;
+            return EscapeEvent::Pending;
+        }
+        match self.state {
+            EscapeState::Idle => {
+                self.reset_armed = false;
+                EscapeEvent::NotEscape
+            }
+            EscapeState::Escape => self.after_escape(ch),
+            EscapeState::Csi => self.in_csi(ch),
+        }
+    }
+
+    /// Handles the byte following a lone ESC.
+    ///
+    /// # Arguments
+    /// * `ch` - The byte received
+    ///
+    /// # Returns
+    /// * `EscapeEvent` - Pending for `[`, SoftReset for a repeated
+    ///   `ESC R`, otherwise LoneEscape
+    fn after_escape(&mut self, ch: u8) -> EscapeEvent {
+        let armed = core::mem::take(&mut self.reset_armed);
+        if ch == b'R' && armed {
+            self.state = EscapeState::Idle;
+            return EscapeEvent::SoftReset;
+        }
+        self.reset_armed = ch == b'R';
+        if ch == b'[' {
+            self.state = EscapeState::Csi;
+            self.param = 0;
+            self.extra_params = false;
+            return EscapeEvent::Pending;
+        }
+        self.state = EscapeState::Idle;
+        EscapeEvent::LoneEscape
+    }
+
+    /// Handles a byte inside a CSI sequence.
+    ///
+    /// # Arguments
+    /// * `ch` - The byte received
+    ///
+    /// # Returns
+    /// * `EscapeEvent` - Pending until the final byte arrives
+    fn in_csi(&mut self, ch: u8) -> EscapeEvent {
+        match ch {
+            b'0'..=b'9' if !self.extra_params => {
+                self.param = self
+                    .param
+                    .saturating_mul(10)
+                    .saturating_add((ch - b'0') as u16);
+                EscapeEvent::Pending
+            }
+            b';' => {
+                self.extra_params = true;
+                EscapeEvent::Pending
+            }
+            0x20..=0x3F => EscapeEvent::Pending,
+            0x40..=0x7E => {
+                self.state = EscapeState::Idle;
+                if ch == b'n' && self.param == 6 && !self.extra_params {
+                    EscapeEvent::CursorPositionRequest
+                } else {
+                    EscapeEvent::Unrecognized
+                }
+            }
+            _ => {
+                self.state = EscapeState::Idle;
+                EscapeEvent::Unrecognized
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Feeds a byte sequence and returns the event for the last byte.
+    fn feed_all(parser: &mut EscapeParser, bytes: &[u8]) -> EscapeEvent {
+        let mut event = EscapeEvent::NotEscape;
+        for &b in bytes {
+            event = parser.feed(b);
+        }
+        event
+    }
+
+    // ==================== EscapeParser Tests ====================
+
+    #[test]
+    fn test_plain_byte_not_escape() {
+        let mut parser = EscapeParser::new();
+        assert_eq!(parser.feed(b'A'), EscapeEvent::NotEscape);
+        assert!(!parser.in_sequence());
+    }
+
+    #[test]
+    fn test_escape_is_pending() {
+        let mut parser = EscapeParser::new();
+        assert_eq!(parser.feed(0x1B), EscapeEvent::Pending);
+        assert!(parser.in_sequence());
+    }
+
+    #[test]
+    fn test_cursor_position_request() {
+        let mut parser = EscapeParser::new();
+        assert_eq!(
+            feed_all(&mut parser, b"\x1b[6n"),
+            EscapeEvent::CursorPositionRequest
+        );
+        assert!(!parser.in_sequence());
+    }
+
+    #[test]
+    fn test_other_dsr_unrecognized() {
+        let mut parser = EscapeParser::new();
+        assert_eq!(feed_all(&mut parser, b"\x1b[5n"), EscapeEvent::Unrecognized);
+    }
+
+    #[test]
+    fn test_multi_param_unrecognized() {
+        let mut parser = EscapeParser::new();
+        assert_eq!(
+            feed_all(&mut parser, b"\x1b[6;1n"),
+            EscapeEvent::Unrecognized
+        );
+    }
+
+    #[test]
+    fn test_arrow_key_unrecognized() {
+        let mut parser = EscapeParser::new();
+        assert_eq!(feed_all(&mut parser, b"\x1b[A"), EscapeEvent::Unrecognized);
+    }
+
+    #[test]
+    fn test_lone_escape_then_byte() {
+        let mut parser = EscapeParser::new();
+        assert_eq!(feed_all(&mut parser, b"\x1bc"), EscapeEvent::LoneEscape);
+        assert_eq!(parser.feed(b'x'), EscapeEvent::NotEscape);
+    }
+
+    #[test]
+    fn test_expire_pending_escape() {
+        let mut parser = EscapeParser::new();
+        parser.feed(0x1B);
+        assert!(parser.expire());
+        assert!(!parser.in_sequence());
+    }
+
+    #[test]
+    fn test_expire_partial_csi_discarded() {
+        let mut parser = EscapeParser::new();
+        feed_all(&mut parser, b"\x1b[6");
+        assert!(!parser.expire());
+        assert_eq!(parser.feed(b'n'), EscapeEvent::NotEscape);
+    }
+
+    #[test]
+    fn test_expire_when_idle() {
+        let mut parser = EscapeParser::new();
+        assert!(!parser.expire());
+    }
+
+    // ==================== Soft Reset Tests ====================
+
+    #[test]
+    fn test_double_escape_r_is_soft_reset() {
+        let mut parser = EscapeParser::new();
+        assert_eq!(feed_all(&mut parser, b"\x1bR"), EscapeEvent::LoneEscape);
+        assert_eq!(feed_all(&mut parser, b"\x1bR"), EscapeEvent::SoftReset);
+        assert!(!parser.in_sequence());
+    }
+
+    #[test]
+    fn test_single_escape_r_is_lone_escape() {
+        let mut parser = EscapeParser::new();
+        assert_eq!(feed_all(&mut parser, b"\x1bR"), EscapeEvent::LoneEscape);
+        assert_eq!(parser.feed(b'R'), EscapeEvent::NotEscape);
+    }
+
+    #[test]
+    fn test_byte_between_disarms_soft_reset() {
+        let mut parser = EscapeParser::new();
+        assert_eq!(
+            feed_all(&mut parser, b"\x1bRx\x1bR"),
+            EscapeEvent::LoneEscape
+        );
+    }
+
+    #[test]
+    fn test_idle_between_disarms_soft_reset() {
+        let mut parser = EscapeParser::new();
+        feed_all(&mut parser, b"\x1bR");
+        parser.expire();
+        assert_eq!(feed_all(&mut parser, b"\x1bR"), EscapeEvent::LoneEscape);
+    }
+
+    #[test]
+    fn test_soft_reset_not_repeated() {
+        let mut parser = EscapeParser::new();
+        feed_all(&mut parser, b"\x1bR\x1bR");
+        assert_eq!(feed_all(&mut parser, b"\x1bR"), EscapeEvent::LoneEscape);
+    }
+}
@@ -0,0 +1,205 @@
+/*
+ * @file events.rs
+ * @brief Event callbacks
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: events.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Controller Event Callbacks.
+//!
+//! BRIEF:
+//! Optional function-pointer hooks fired by the controller on events.
+//! Lets the application add policy without polling return values.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Controller events that can have a callback.
+///
+/// # Variants
+/// * `LineCommitted` - An echo-mode line was committed; data is the line
+/// * `Overflow` - The line buffer rejected a character; data is the full line
+/// * `ModeChanged` - The input mode changed; data is the new mode's name
+/// * `CommandRecognized` - A command line parsed; data is the trimmed line
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum Event {
+    LineCommitted,
+    Overflow,
+    ModeChanged,
+    CommandRecognized,
+}
+
+/// Callback invoked with the event's data.
+#[allow(dead_code)]
+pub type Callback = fn(&[u8]);
+
+/// Registered event callbacks.
+///
+/// # Details
+/// Plain function pointers so the table stays `Copy` and allocation-free.
+///
+/// # Fields
+/// * `on_line` - Called when a line is committed
+/// * `on_overflow` - Called when the line buffer overflows
+/// * `on_mode` - Called when the input mode changes
+/// * `on_command` - Called when a command is recognized
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(dead_code)]
+pub struct Callbacks {
+    on_line: Option<Callback>,
+    on_overflow: Option<Callback>,
+    on_mode: Option<Callback>,
+    on_command: Option<Callback>,
+}
+
+impl Callbacks {
+    /// Creates a table with no callbacks registered.
+    ///
+    /// # Returns
+    /// * `Self` - Empty callback table
+    #[allow(dead_code)]
+    pub const fn new() -> Self {
+        Self {
+            on_line: None,
+            on_overflow: None,
+            on_mode: None,
+            on_command: None,
+        }
+    }
+
+    /// Registers or clears the callback for an event.
+    ///
+    /// # Arguments
+    /// * `event` - Event to hook
+    /// * `callback` - Function to call, or None to clear
+    #[allow(dead_code)]
+    pub fn set(&mut self, event: Event, callback: Option<Callback>) {
+        *self.slot(event) = callback;
+    }
+
+    /// Invokes the callback for an event, if one is registered.
+    ///
+    /// # Arguments
+    /// * `event` - Event that occurred
+    /// * `data` - Event data passed to the callback
+    #[allow(dead_code)]
+    pub fn fire(&mut self, event: Event, data: &[u8]) {
+        if let Some(callback) = *self.slot(event) {
+            callback(data);
+        }
+    }
+
+    /// Returns the storage slot for an event.
+    ///
+    /// # Arguments
+    /// * `event` - Event to look up
+    ///
+    /// # Returns
+    /// * `&mut Option<Callback>` - Callback slot
+    fn slot(&mut self, event: Event) -> &mut Option<Callback> {
+        match event {
+            Event::LineCommitted => &mut self.on_line,
+            Event::Overflow => &mut self.on_overflow,
+            Event::ModeChanged => &mut self.on_mode,
+            Event::CommandRecognized => &mut self.on_command,
+        }
+    }
+}
+
+impl PartialEq for Callbacks {
+    /// Compares tables by which events have a callback.
+    ///
+    /// # Details
+    /// Function pointer addresses are not reliable across codegen units,
+    /// so only registration is compared.
+    ///
+    /// # Arguments
+    /// * `other` - Table to compare with
+    ///
+    /// # Returns
+    /// * `bool` - True if the same events are hooked
+    fn eq(&self, other: &Self) -> bool {
+        self.on_line.is_some() == other.on_line.is_some()
+            && self.on_overflow.is_some() == other.on_overflow.is_some()
+            && self.on_mode.is_some() == other.on_mode.is_some()
+            && self.on_command.is_some() == other.on_command.is_some()
+    }
+}
+
+impl Eq for Callbacks {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    static SEEN: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+
+    fn record(data: &[u8]) {
+        SEEN.lock().unwrap().extend_from_slice(data);
+    }
+
+    fn ignore(_data: &[u8]) {}
+
+    // ==================== Callbacks Tests ====================
+
+    #[test]
+    fn test_fire_without_callback_is_noop() {
+        let mut callbacks = Callbacks::new();
+        callbacks.fire(Event::LineCommitted, b"x");
+    }
+
+    #[test]
+    fn test_fire_registered_callback() {
+        let mut callbacks = Callbacks::new();
+        callbacks.set(Event::Overflow, Some(record));
+        callbacks.fire(Event::LineCommitted, b"no");
+        callbacks.fire(Event::Overflow, b"yes");
+        assert_eq!(SEEN.lock().unwrap().as_slice(), b"yes");
+    }
+
+    #[test]
+    fn test_set_none_clears() {
+        let mut callbacks = Callbacks::new();
+        callbacks.set(Event::ModeChanged, Some(ignore));
+        callbacks.set(Event::ModeChanged, None);
+        assert_eq!(callbacks, Callbacks::new());
+    }
+
+    #[test]
+    fn test_eq_compares_registration() {
+        let mut a = Callbacks::new();
+        let mut b = Callbacks::new();
+        a.set(Event::CommandRecognized, Some(ignore));
+        assert_ne!(a, b);
+        b.set(Event::CommandRecognized, Some(record));
+        assert_eq!(a, b);
+    }
+}
@@ -0,0 +1,165 @@
+/*
+ * @file fmtbuf.rs
+ * @brief Fixed-capacity formatting buffer
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: fmtbuf.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Fixed-Capacity Formatting Buffer.
+//!
+//! BRIEF:
+//! Provides a core::fmt::Write sink over a borrowed byte slice.
+//! Lets no_std code format numbers and messages without allocation.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+use core::fmt;
+
+/// Formatting sink backed by a caller-supplied byte slice.
+///
+/// # Details
+/// Implements core::fmt::Write so `write!` can be used without a heap.
+/// Writes that do not fit are rejected with fmt::Error and leave the
+/// already-written prefix intact.
+///
+/// # Fields
+/// * `buf` - Backing storage
+/// * `len` - Number of bytes written so far
+#[derive(Debug)]
+#[allow(dead_code)]
+pub struct FmtBuf<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> FmtBuf<'a> {
+    /// Creates an empty formatting buffer over `buf`.
+    ///
+    /// # Arguments
+    /// * `buf` - Backing storage to format into
+    ///
+    /// # Returns
+    /// * `Self` - New FmtBuf with zero length
+    #[allow(dead_code)]
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, len: 0 }
+    }
+
+    /// Returns the bytes written so far.
+    ///
+    /// # Returns
+    /// * `&[u8]` - Formatted contents
+    #[allow(dead_code)]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    /// Returns the number of bytes written so far.
+    ///
+    /// # Returns
+    /// * `usize` - Formatted length
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether nothing has been written yet.
+    ///
+    /// # Returns
+    /// * `bool` - True if the buffer is empty
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl fmt::Write for FmtBuf<'_> {
+    /// Appends a string slice to the buffer.
+    ///
+    /// # Details
+    /// Fails without writing anything if `s` does not fit.
+    ///
+    /// # Arguments
+    /// * `s` - Text to append
+    ///
+    /// # Returns
+    /// * `fmt::Result` - Ok on success, Err if capacity is exceeded
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buf.len() {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::fmt::Write;
+
+    // ==================== FmtBuf Tests ====================
+
+    #[test]
+    fn test_new_is_empty() {
+        let mut storage = [0u8; 8];
+        let buf = FmtBuf::new(&mut storage);
+        assert!(buf.is_empty());
+        assert_eq!(buf.len(), 0);
+    }
+
+    #[test]
+    fn test_write_number() {
+        let mut storage = [0u8; 8];
+        let mut buf = FmtBuf::new(&mut storage);
+        write!(buf, "{}", 1234).unwrap();
+        assert_eq!(buf.as_bytes(), b"1234");
+    }
+
+    #[test]
+    fn test_write_appends() {
+        let mut storage = [0u8; 8];
+        let mut buf = FmtBuf::new(&mut storage);
+        write!(buf, "ab").unwrap();
+        write!(buf, "{}", 7).unwrap();
+        assert_eq!(buf.as_bytes(), b"ab7");
+    }
+
+    #[test]
+    fn test_overflow_rejected() {
+        let mut storage = [0u8; 4];
+        let mut buf = FmtBuf::new(&mut storage);
+        write!(buf, "abc").unwrap();
+        assert!(write!(buf, "de").is_err());
+        assert_eq!(buf.as_bytes(), b"abc");
+    }
+}
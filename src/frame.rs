@@ -0,0 +1,369 @@
+/*
+ * @file frame.rs
+ * @brief CRC-checked frame codec
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: frame.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART CRC-Checked Frame Codec.
+//!
+//! BRIEF:
+//! Encodes and decodes length-prefixed frames carrying a CRC-16.
+//! Lets the device reject corrupt payloads with NAK.
+//! Multi-byte fields follow a configurable byte order.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+use crate::config::FRAME_MAX_PAYLOAD;
+use crate::crc::crc16;
+
+/// Byte order of the 16-bit length and CRC fields.
+///
+/// # Variants
+/// * `Big` - Most significant byte first
+/// * `Little` - Least significant byte first
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum Endianness {
+    Big,
+    Little,
+}
+
+impl Endianness {
+    /// Converts a 16-bit value to wire bytes.
+    ///
+    /// # Arguments
+    /// * `value` - Value to convert
+    ///
+    /// # Returns
+    /// * `[u8; 2]` - Bytes in this order
+    #[allow(dead_code)]
+    pub fn to_bytes(self, value: u16) -> [u8; 2] {
+        match self {
+            Endianness::Big => value.to_be_bytes(),
+            Endianness::Little => value.to_le_bytes(),
+        }
+    }
+
+    /// Reads a 16-bit value from wire bytes.
+    ///
+    /// # Arguments
+    /// * `bytes` - Bytes in this order
+    ///
+    /// # Returns
+    /// * `u16` - Decoded value
+    #[allow(dead_code)]
+    pub fn from_bytes(self, bytes: [u8; 2]) -> u16 {
+        match self {
+            Endianness::Big => u16::from_be_bytes(bytes),
+            Endianness::Little => u16::from_le_bytes(bytes),
+        }
+    }
+}
+
+/// Result of feeding one byte to the frame decoder.
+///
+/// # Variants
+/// * `Pending` - Frame not yet complete
+/// * `Valid` - Frame complete and its CRC matches
+/// * `Corrupt` - Frame complete with a bad CRC, or its length is too large
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum FrameEvent {
+    Pending,
+    Valid,
+    Corrupt,
+}
+
+/// Byte-at-a-time decoder for `len16, payload, crc16` frames.
+///
+/// # Details
+/// The CRC-16 covers the payload. Both 16-bit fields use the
+/// decoder's byte order. A length above FRAME_MAX_PAYLOAD is
+/// rejected as soon as both length bytes arrive.
+///
+/// # Fields
+/// * `buf` - Payload followed by the received CRC bytes
+/// * `header` - Length bytes received so far
+/// * `header_len` - Number of length bytes received
+/// * `expected` - Payload length once both length bytes arrived
+/// * `len` - Bytes of payload and CRC received so far
+/// * `order` - Byte order of the length and CRC fields
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct FrameDecoder {
+    buf: [u8; FRAME_MAX_PAYLOAD + 2],
+    header: [u8; 2],
+    header_len: usize,
+    expected: Option<usize>,
+    len: usize,
+    order: Endianness,
+}
+
+impl Default for FrameDecoder {
+    /// Returns default FrameDecoder instance.
+    ///
+    /// # Details
+    /// Delegates to new() for initialization.
+    ///
+    /// # Returns
+    /// * `Self` - New FrameDecoder awaiting a length byte
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FrameDecoder {
+    /// Creates a big-endian decoder awaiting a frame's length.
+    ///
+    /// # Returns
+    /// * `Self` - New FrameDecoder
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self::with_endianness(Endianness::Big)
+    }
+
+    /// Creates a decoder using the given byte order.
+    ///
+    /// # Arguments
+    /// * `order` - Byte order of the length and CRC fields
+    ///
+    /// # Returns
+    /// * `Self` - New FrameDecoder
+    #[allow(dead_code)]
+    pub fn with_endianness(order: Endianness) -> Self {
+        Self {
+            buf: [0; FRAME_MAX_PAYLOAD + 2],
+            header: [0; 2],
+            header_len: 0,
+            expected: None,
+            len: 0,
+            order,
+        }
+    }
+
+    /// Feeds one received byte to the decoder.
+    ///
+    /// # Details
+    /// After Valid or Corrupt the decoder awaits the next frame.
+    ///
+    /// # Arguments
+    /// * `ch` - The byte received
+    ///
+    /// # Returns
+    /// * `FrameEvent` - Whether a frame was completed
+    #[allow(dead_code)]
+    pub fn feed(&mut self, ch: u8) -> FrameEvent {
+        let expected = match self.expected {
+            None => {
+                self.header[self.header_len] = ch;
+                self.header_len += 1;
+                if self.header_len < 2 {
+                    return FrameEvent::Pending;
+                }
+                self.header_len = 0;
+                let n = usize::from(self.order.from_bytes(self.header));
+                if n > FRAME_MAX_PAYLOAD {
+                    return FrameEvent::Corrupt;
+                }
+                self.expected = Some(n);
+                self.len = 0;
+                return FrameEvent::Pending;
+            }
+            Some(n) => n,
+        };
+        self.buf[self.len] = ch;
+        self.len += 1;
+        if self.len < expected + 2 {
+            return FrameEvent::Pending;
+        }
+        self.expected = None;
+        let received = self
+            .order
+            .from_bytes([self.buf[expected], self.buf[expected + 1]]);
+        if crc16(&self.buf[..expected]) == received {
+            FrameEvent::Valid
+        } else {
+            FrameEvent::Corrupt
+        }
+    }
+
+    /// Returns the payload of the last completed frame.
+    ///
+    /// # Returns
+    /// * `&[u8]` - Payload bytes without the CRC
+    #[allow(dead_code)]
+    pub fn payload(&self) -> &[u8] {
+        &self.buf[..self.len.saturating_sub(2)]
+    }
+}
+
+/// Encodes a payload as a length-prefixed frame with a CRC-16.
+///
+/// # Arguments
+/// * `payload` - Bytes to frame
+/// * `order` - Byte order of the length and CRC fields
+/// * `out` - Destination buffer
+///
+/// # Returns
+/// * `Option<usize>` - Frame length, or None if the payload is too long or `out` too small
+#[allow(dead_code)]
+pub fn encode(payload: &[u8], order: Endianness, out: &mut [u8]) -> Option<usize> {
+    let len = payload.len() + 4;
+    if payload.len() > FRAME_MAX_PAYLOAD || out.len() < len {
+        return None;
+    }
+    out[..2].copy_from_slice(&order.to_bytes(payload.len() as u16));
+    out[2..len - 2].copy_from_slice(payload);
+    out[len - 2..len].copy_from_slice(&order.to_bytes(crc16(payload)));
+    Some(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode_all(decoder: &mut FrameDecoder, frame: &[u8]) -> FrameEvent {
+        let mut event = FrameEvent::Pending;
+        for &b in frame {
+            event = decoder.feed(b);
+        }
+        event
+    }
+
+    // ==================== Encode Tests ====================
+
+    #[test]
+    fn test_encode_layout() {
+        let mut out = [0u8; 8];
+        assert_eq!(encode(b"", Endianness::Big, &mut out), Some(4));
+        assert_eq!(&out[..4], &[0, 0, 0xFF, 0xFF]);
+        assert_eq!(encode(b"AB", Endianness::Big, &mut out), Some(6));
+        assert_eq!(&out[..2], &[0, 2]);
+        assert_eq!(&out[2..4], b"AB");
+        assert_eq!(&out[4..6], &crc16(b"AB").to_be_bytes());
+    }
+
+    #[test]
+    fn test_encode_little_endian_crc_order() {
+        let mut out = [0u8; 8];
+        assert_eq!(encode(b"AB", Endianness::Little, &mut out), Some(6));
+        assert_eq!(&out[..2], &[2, 0]);
+        assert_eq!(&out[4..6], &crc16(b"AB").to_le_bytes());
+    }
+
+    #[test]
+    fn test_encode_rejects_small_buffer() {
+        let mut out = [0u8; 5];
+        assert_eq!(encode(b"AB", Endianness::Big, &mut out), None);
+    }
+
+    #[test]
+    fn test_encode_rejects_long_payload() {
+        let payload = [0u8; FRAME_MAX_PAYLOAD + 1];
+        let mut out = [0u8; FRAME_MAX_PAYLOAD + 5];
+        assert_eq!(encode(&payload, Endianness::Big, &mut out), None);
+    }
+
+    // ==================== Endianness Tests ====================
+
+    #[test]
+    fn test_endianness_round_trip() {
+        for order in [Endianness::Big, Endianness::Little] {
+            assert_eq!(order.from_bytes(order.to_bytes(0x1234)), 0x1234);
+        }
+        assert_eq!(Endianness::Big.to_bytes(0x1234), [0x12, 0x34]);
+        assert_eq!(Endianness::Little.to_bytes(0x1234), [0x34, 0x12]);
+    }
+
+    #[test]
+    fn test_decode_length_under_each_endianness() {
+        let mut big = FrameDecoder::with_endianness(Endianness::Big);
+        assert_eq!(decode_all(&mut big, &[0x00, 0x03]), FrameEvent::Pending);
+        let mut little = FrameDecoder::with_endianness(Endianness::Little);
+        assert_eq!(decode_all(&mut little, &[0x03, 0x00]), FrameEvent::Pending);
+        for decoder in [&mut big, &mut little] {
+            assert_eq!(decoder.expected, Some(3));
+        }
+    }
+
+    #[test]
+    fn test_decode_wrong_endianness_corrupt() {
+        let mut decoder = FrameDecoder::with_endianness(Endianness::Little);
+        assert_eq!(decode_all(&mut decoder, &[0x00, 0x03]), FrameEvent::Corrupt);
+    }
+
+    #[test]
+    fn test_decode_little_endian_frame() {
+        let mut out = [0u8; 16];
+        let n = encode(b"hello", Endianness::Little, &mut out).unwrap();
+        let mut decoder = FrameDecoder::with_endianness(Endianness::Little);
+        assert_eq!(decode_all(&mut decoder, &out[..n]), FrameEvent::Valid);
+        assert_eq!(decoder.payload(), b"hello");
+    }
+
+    // ==================== Decode Tests ====================
+
+    #[test]
+    fn test_decode_valid_frame() {
+        let mut out = [0u8; 16];
+        let n = encode(b"hello", Endianness::Big, &mut out).unwrap();
+        let mut decoder = FrameDecoder::new();
+        assert_eq!(decode_all(&mut decoder, &out[..n]), FrameEvent::Valid);
+        assert_eq!(decoder.payload(), b"hello");
+    }
+
+    #[test]
+    fn test_decode_flipped_bit_corrupt() {
+        let mut out = [0u8; 16];
+        let n = encode(b"hello", Endianness::Big, &mut out).unwrap();
+        out[3] ^= 0x04;
+        let mut decoder = FrameDecoder::new();
+        assert_eq!(decode_all(&mut decoder, &out[..n]), FrameEvent::Corrupt);
+    }
+
+    #[test]
+    fn test_decode_oversized_length_corrupt() {
+        let mut decoder = FrameDecoder::new();
+        assert_eq!(decoder.feed(0x00), FrameEvent::Pending);
+        assert_eq!(decoder.feed(0xFF), FrameEvent::Corrupt);
+    }
+
+    #[test]
+    fn test_decode_back_to_back_frames() {
+        let mut out = [0u8; 16];
+        let mut decoder = FrameDecoder::new();
+        let n = encode(b"a", Endianness::Big, &mut out).unwrap();
+        assert_eq!(decode_all(&mut decoder, &out[..n]), FrameEvent::Valid);
+        let n = encode(b"bc", Endianness::Big, &mut out).unwrap();
+        assert_eq!(decode_all(&mut decoder, &out[..n]), FrameEvent::Valid);
+        assert_eq!(decoder.payload(), b"bc");
+    }
+}
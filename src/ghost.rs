@@ -0,0 +1,133 @@
+/*
+ * @file ghost.rs
+ * @brief Ghost typing delays
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: ghost.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Ghost Typing.
+//!
+//! BRIEF:
+//! Generates human-looking per-character echo delays for demos.
+//! A seeded LCG keeps the sequence reproducible.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+use crate::config::{GHOST_MAX_DELAY_MS, GHOST_MIN_DELAY_MS};
+
+/// LCG multiplier (Numerical Recipes).
+const LCG_MUL: u32 = 1_664_525;
+
+/// LCG increment (Numerical Recipes).
+const LCG_INC: u32 = 1_013_904_223;
+
+/// Deterministic generator of typing delays.
+///
+/// # Details
+/// Each delay advances a 32-bit linear congruential generator and maps
+/// its high bits into GHOST_MIN_DELAY_MS..=GHOST_MAX_DELAY_MS. The same
+/// seed always yields the same sequence.
+///
+/// # Fields
+/// * `state` - Current LCG state
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct GhostTyper {
+    state: u32,
+}
+
+impl GhostTyper {
+    /// Creates a generator from a seed.
+    ///
+    /// # Arguments
+    /// * `seed` - Initial LCG state
+    ///
+    /// # Returns
+    /// * `Self` - New GhostTyper
+    #[allow(dead_code)]
+    pub const fn new(seed: u32) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the next delay in the sequence.
+    ///
+    /// # Details
+    /// The low bits of an LCG cycle quickly, so only bits 16..32 are
+    /// used.
+    ///
+    /// # Returns
+    /// * `u32` - Delay in milliseconds
+    #[allow(dead_code)]
+    pub fn next_delay(&mut self) -> u32 {
+        self.state = self.state.wrapping_mul(LCG_MUL).wrapping_add(LCG_INC);
+        let span = GHOST_MAX_DELAY_MS - GHOST_MIN_DELAY_MS + 1;
+        GHOST_MIN_DELAY_MS + (self.state >> 16) % span
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sequence(seed: u32, n: usize) -> Vec<u32> {
+        let mut ghost = GhostTyper::new(seed);
+        (0..n).map(|_| ghost.next_delay()).collect()
+    }
+
+    // ==================== Delay Tests ====================
+
+    #[test]
+    fn test_known_sequence_from_seed() {
+        assert_eq!(sequence(1, 3), [38, 30, 43]);
+    }
+
+    #[test]
+    fn test_same_seed_reproducible() {
+        assert_eq!(sequence(0xDEAD_BEEF, 32), sequence(0xDEAD_BEEF, 32));
+    }
+
+    #[test]
+    fn test_different_seeds_differ() {
+        assert_ne!(sequence(1, 8), sequence(2, 8));
+    }
+
+    #[test]
+    fn test_delays_within_range() {
+        for delay in sequence(42, 1000) {
+            assert!((GHOST_MIN_DELAY_MS..=GHOST_MAX_DELAY_MS).contains(&delay));
+        }
+    }
+
+    #[test]
+    fn test_delays_vary() {
+        let delays = sequence(7, 16);
+        assert!(delays.iter().any(|&d| d != delays[0]));
+    }
+}
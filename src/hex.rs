@@ -0,0 +1,471 @@
+/*
+ * @file hex.rs
+ * @brief Hexadecimal formatting helpers
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: hex.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Hexadecimal Formatting Helpers.
+//!
+//! BRIEF:
+//! Converts bytes to ASCII hex digits for dumps and diagnostics.
+//! Writes into caller buffers so no allocation is needed.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Converts the low nibble of a byte to an ASCII hex digit.
+///
+/// # Arguments
+/// * `nibble` - Value whose low four bits are converted
+/// * `upper` - True for 'A'-'F', false for 'a'-'f'
+///
+/// # Returns
+/// * `u8` - ASCII character '0'-'9' or a letter digit
+#[allow(dead_code)]
+pub fn nibble_to_hex(nibble: u8, upper: bool) -> u8 {
+    let letters = if upper { b'A' } else { b'a' };
+    match nibble & 0x0F {
+        n @ 0..=9 => b'0' + n,
+        n => letters + (n - 10),
+    }
+}
+
+/// Writes a byte as two ASCII hex digits.
+///
+/// # Arguments
+/// * `byte` - Value to format
+/// * `upper` - True for uppercase letter digits
+///
+/// # Returns
+/// * `[u8; 2]` - High and low hex digits
+#[allow(dead_code)]
+pub fn byte_to_hex(byte: u8, upper: bool) -> [u8; 2] {
+    [nibble_to_hex(byte >> 4, upper), nibble_to_hex(byte, upper)]
+}
+
+/// Formats bytes as space-separated hex pairs.
+///
+/// # Details
+/// Produces e.g. "41 42" for b"AB". Stops at the last byte that fits
+/// completely in `out`.
+///
+/// # Arguments
+/// * `bytes` - Bytes to format
+/// * `upper` - True for uppercase letter digits
+/// * `out` - Destination buffer
+///
+/// # Returns
+/// * `usize` - Number of bytes written to `out`
+#[allow(dead_code)]
+pub fn hex_dump(bytes: &[u8], upper: bool, out: &mut [u8]) -> usize {
+    let mut len = 0;
+    for (i, &b) in bytes.iter().enumerate() {
+        let sep = usize::from(i > 0);
+        if len + sep + 2 > out.len() {
+            break;
+        }
+        if sep == 1 {
+            out[len] = b' ';
+        }
+        out[len + sep..len + sep + 2].copy_from_slice(&byte_to_hex(b, upper));
+        len += sep + 2;
+    }
+    len
+}
+
+/// Converts an ASCII hex digit to its value.
+///
+/// # Arguments
+/// * `ch` - Character '0'-'9', 'a'-'f', or 'A'-'F'
+///
+/// # Returns
+/// * `Option<u8>` - Nibble value, or None if not a hex digit
+#[allow(dead_code)]
+pub fn hex_to_nibble(ch: u8) -> Option<u8> {
+    match ch {
+        b'0'..=b'9' => Some(ch - b'0'),
+        b'a'..=b'f' => Some(ch - b'a' + 10),
+        b'A'..=b'F' => Some(ch - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Parses hex text into bytes.
+///
+/// # Details
+/// Accepts pairs of hex digits with optional spaces between bytes,
+/// e.g. "41 42" or "4142".
+///
+/// # Arguments
+/// * `text` - Hex text
+/// * `out` - Destination buffer
+///
+/// # Returns
+/// * `Option<usize>` - Number of bytes parsed, or None if malformed or `out` is too small
+#[allow(dead_code)]
+pub fn parse_hex(text: &[u8], out: &mut [u8]) -> Option<usize> {
+    let mut len = 0;
+    let mut rest = text;
+    loop {
+        while let [b' ', tail @ ..] = rest {
+            rest = tail;
+        }
+        match rest {
+            [] => return Some(len),
+            [hi, lo, tail @ ..] => {
+                *out.get_mut(len)? = (hex_to_nibble(*hi)? << 4) | hex_to_nibble(*lo)?;
+                len += 1;
+                rest = tail;
+            }
+            _ => return None,
+        }
+    }
+}
+
+/// Accumulates typed hex digits into bytes.
+///
+/// # Details
+/// Two nibbles make a byte, high nibble first. A single space may
+/// separate complete bytes, so "41 42" and "4142" both assemble to
+/// 0x41 0x42. Each backspace() undoes one accepted keystroke.
+///
+/// # Fields
+/// * `buf` - Assembled bytes
+/// * `len` - Number of valid bytes in `buf`
+/// * `high` - High nibble waiting for its low nibble, if any
+/// * `sep` - True where a space follows the byte at that index
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct HexEntry<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+    high: Option<u8>,
+    sep: [bool; N],
+}
+
+impl<const N: usize> Default for HexEntry<N> {
+    /// Returns default HexEntry instance.
+    ///
+    /// # Details
+    /// Delegates to new() for initialization.
+    ///
+    /// # Returns
+    /// * `Self` - New empty HexEntry
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> HexEntry<N> {
+    /// Creates an empty hex entry.
+    ///
+    /// # Returns
+    /// * `Self` - New HexEntry with no digits
+    #[allow(dead_code)]
+    pub const fn new() -> Self {
+        Self {
+            buf: [0; N],
+            len: 0,
+            high: None,
+            sep: [false; N],
+        }
+    }
+
+    /// Accepts a typed character.
+    ///
+    /// # Details
+    /// Hex digits are accumulated. A space is accepted only directly
+    /// after a complete byte.
+    ///
+    /// # Arguments
+    /// * `ch` - The character typed
+    ///
+    /// # Returns
+    /// * `bool` - True if accepted, false if not hex, misplaced, or full
+    #[allow(dead_code)]
+    pub fn push(&mut self, ch: u8) -> bool {
+        if ch == b' ' {
+            let ok = self.high.is_none() && self.len > 0 && !self.sep[self.len - 1];
+            if ok {
+                self.sep[self.len - 1] = true;
+            }
+            return ok;
+        }
+        let Some(nibble) = hex_to_nibble(ch) else {
+            return false;
+        };
+        match self.high.take() {
+            Some(high) => {
+                self.buf[self.len] = (high << 4) | nibble;
+                self.sep[self.len] = false;
+                self.len += 1;
+            }
+            None if self.len == N => return false,
+            None => self.high = Some(nibble),
+        }
+        true
+    }
+
+    /// Undoes the last accepted character.
+    ///
+    /// # Returns
+    /// * `bool` - True if something was removed
+    #[allow(dead_code)]
+    pub fn backspace(&mut self) -> bool {
+        if self.high.take().is_some() {
+            return true;
+        }
+        if self.len == 0 {
+            return false;
+        }
+        if self.sep[self.len - 1] {
+            self.sep[self.len - 1] = false;
+        } else {
+            self.len -= 1;
+            self.high = Some(self.buf[self.len] >> 4);
+        }
+        true
+    }
+
+    /// Returns the complete bytes assembled so far.
+    ///
+    /// # Returns
+    /// * `&[u8]` - Assembled bytes, excluding any pending nibble
+    #[allow(dead_code)]
+    pub fn bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    /// Returns whether no half-entered byte is pending.
+    ///
+    /// # Returns
+    /// * `bool` - True if every digit belongs to a complete byte
+    #[allow(dead_code)]
+    pub fn is_complete(&self) -> bool {
+        self.high.is_none()
+    }
+
+    /// Discards all digits.
+    #[allow(dead_code)]
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.high = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enter(text: &[u8]) -> HexEntry<8> {
+        let mut entry = HexEntry::new();
+        for &b in text {
+            entry.push(b);
+        }
+        entry
+    }
+
+    // ==================== Nibble Conversion Tests ====================
+
+    #[test]
+    fn test_nibble_digits() {
+        assert_eq!(nibble_to_hex(0, true), b'0');
+        assert_eq!(nibble_to_hex(9, false), b'9');
+    }
+
+    #[test]
+    fn test_nibble_letters() {
+        assert_eq!(nibble_to_hex(10, true), b'A');
+        assert_eq!(nibble_to_hex(15, true), b'F');
+    }
+
+    #[test]
+    fn test_nibble_lowercase_letters() {
+        assert_eq!(nibble_to_hex(10, false), b'a');
+        assert_eq!(nibble_to_hex(15, false), b'f');
+    }
+
+    #[test]
+    fn test_nibble_ignores_high_bits() {
+        assert_eq!(nibble_to_hex(0xF3, true), b'3');
+    }
+
+    #[test]
+    fn test_byte_to_hex() {
+        assert_eq!(&byte_to_hex(0xAB, true), b"AB");
+        assert_eq!(&byte_to_hex(0x05, true), b"05");
+    }
+
+    #[test]
+    fn test_byte_to_hex_lowercase() {
+        assert_eq!(&byte_to_hex(0xAB, false), b"ab");
+    }
+
+    // ==================== Hex Parse Tests ====================
+
+    #[test]
+    fn test_hex_to_nibble() {
+        assert_eq!(hex_to_nibble(b'7'), Some(7));
+        assert_eq!(hex_to_nibble(b'c'), Some(12));
+        assert_eq!(hex_to_nibble(b'F'), Some(15));
+        assert_eq!(hex_to_nibble(b'g'), None);
+    }
+
+    #[test]
+    fn test_parse_hex_spaced_and_packed() {
+        let mut out = [0u8; 4];
+        assert_eq!(parse_hex(b"41 42", &mut out), Some(2));
+        assert_eq!(&out[..2], b"AB");
+        assert_eq!(parse_hex(b"6f6B", &mut out), Some(2));
+        assert_eq!(&out[..2], b"ok");
+    }
+
+    #[test]
+    fn test_parse_hex_rejects_malformed() {
+        let mut out = [0u8; 4];
+        assert_eq!(parse_hex(b"4", &mut out), None);
+        assert_eq!(parse_hex(b"4 1", &mut out), None);
+        assert_eq!(parse_hex(b"zz", &mut out), None);
+    }
+
+    #[test]
+    fn test_parse_hex_rejects_overflow() {
+        let mut out = [0u8; 1];
+        assert_eq!(parse_hex(b"41 42", &mut out), None);
+    }
+
+    // ==================== Hex Entry Tests ====================
+
+    #[test]
+    fn test_hex_entry_spaced_pairs() {
+        let entry = enter(b"41 42");
+        assert_eq!(entry.bytes(), &[0x41, 0x42]);
+        assert!(entry.is_complete());
+    }
+
+    #[test]
+    fn test_hex_entry_packed_pairs() {
+        assert_eq!(enter(b"4142").bytes(), &[0x41, 0x42]);
+    }
+
+    #[test]
+    fn test_hex_entry_mixed_case() {
+        assert_eq!(enter(b"aB").bytes(), &[0xAB]);
+    }
+
+    #[test]
+    fn test_hex_entry_rejects_non_hex() {
+        let mut entry: HexEntry<8> = HexEntry::new();
+        assert!(!entry.push(b'g'));
+        assert!(!entry.push(b'\r'));
+        assert_eq!(entry.bytes(), b"");
+    }
+
+    #[test]
+    fn test_hex_entry_rejects_misplaced_space() {
+        let mut entry: HexEntry<8> = HexEntry::new();
+        assert!(!entry.push(b' '));
+        entry.push(b'4');
+        assert!(!entry.push(b' '));
+        entry.push(b'1');
+        assert!(entry.push(b' '));
+        assert!(!entry.push(b' '));
+    }
+
+    #[test]
+    fn test_hex_entry_pending_nibble_incomplete() {
+        let entry = enter(b"414");
+        assert_eq!(entry.bytes(), &[0x41]);
+        assert!(!entry.is_complete());
+    }
+
+    #[test]
+    fn test_hex_entry_backspace_steps_back() {
+        let mut entry = enter(b"41 4");
+        assert!(entry.backspace());
+        assert!(entry.is_complete());
+        assert!(entry.backspace());
+        assert!(entry.backspace());
+        assert!(!entry.is_complete());
+        assert!(entry.push(b'2'));
+        assert_eq!(entry.bytes(), &[0x42]);
+        assert!(entry.backspace());
+        assert!(entry.backspace());
+        assert!(!entry.backspace());
+    }
+
+    #[test]
+    fn test_hex_entry_full_rejects_digit() {
+        let mut entry: HexEntry<1> = HexEntry::new();
+        assert!(entry.push(b'4'));
+        assert!(entry.push(b'1'));
+        assert!(!entry.push(b'4'));
+        assert_eq!(entry.bytes(), &[0x41]);
+    }
+
+    #[test]
+    fn test_hex_entry_clear() {
+        let mut entry = enter(b"414");
+        entry.clear();
+        assert_eq!(entry.bytes(), b"");
+        assert!(entry.is_complete());
+    }
+
+    // ==================== Hex Dump Tests ====================
+
+    #[test]
+    fn test_hex_dump_two_bytes() {
+        let mut out = [0u8; 16];
+        let n = hex_dump(b"AB", true, &mut out);
+        assert_eq!(&out[..n], b"41 42");
+    }
+
+    #[test]
+    fn test_hex_dump_case() {
+        let mut out = [0u8; 16];
+        let n = hex_dump(&[0xAB, 0xCD], false, &mut out);
+        assert_eq!(&out[..n], b"ab cd");
+        let n = hex_dump(&[0xAB, 0xCD], true, &mut out);
+        assert_eq!(&out[..n], b"AB CD");
+    }
+
+    #[test]
+    fn test_hex_dump_empty() {
+        let mut out = [0u8; 4];
+        assert_eq!(hex_dump(b"", true, &mut out), 0);
+    }
+
+    #[test]
+    fn test_hex_dump_truncates_whole_bytes() {
+        let mut out = [0u8; 7];
+        let n = hex_dump(&[0x01, 0x02, 0x03], true, &mut out);
+        assert_eq!(&out[..n], b"01 02");
+    }
+}
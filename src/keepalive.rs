@@ -0,0 +1,89 @@
+/*
+ * @file keepalive.rs
+ * @brief Idle keepalive timing
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: keepalive.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Idle Keepalive.
+//!
+//! BRIEF:
+//! Decides when to emit a keepalive byte on an idle link.
+//! Lets a host or adapter that times out silent links see one
+//! KEEPALIVE_BYTE per KEEPALIVE_INTERVAL_MS of silence.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Decides whether a keepalive byte is due.
+///
+/// # Details
+/// Checked on each idle tick. The main loop restarts the idle time
+/// after received traffic and after each keepalive, so one byte goes
+/// out per `interval_ms` of silence. An interval of 0 disables
+/// keepalives.
+///
+/// # Arguments
+/// * `idle_ms` - Time since the last traffic or keepalive
+/// * `interval_ms` - Desired time between keepalives
+///
+/// # Returns
+/// * `bool` - True if a keepalive byte should be sent now
+#[allow(dead_code)]
+pub fn keepalive_due(idle_ms: u64, interval_ms: u64) -> bool {
+    interval_ms > 0 && idle_ms >= interval_ms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Keepalive Decision Tests ====================
+
+    #[test]
+    fn test_not_due_before_interval() {
+        assert!(!keepalive_due(999, 1000));
+    }
+
+    #[test]
+    fn test_due_at_interval() {
+        assert!(keepalive_due(1000, 1000));
+    }
+
+    #[test]
+    fn test_due_after_long_silence() {
+        assert!(keepalive_due(60_000, 1000));
+    }
+
+    #[test]
+    fn test_zero_interval_disabled() {
+        assert!(!keepalive_due(0, 0));
+        assert!(!keepalive_due(60_000, 0));
+    }
+}
@@ -0,0 +1,107 @@
+/*
+ * @file keyname.rs
+ * @brief Control key names
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: keyname.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Control Key Names.
+//!
+//! BRIEF:
+//! Maps C0 control codes and DEL to their conventional names.
+//! Used to echo control keys as readable tokens for teaching.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Conventional names of the C0 control codes, indexed by code.
+static C0_NAMES: [&[u8]; 32] = [
+    b"<NUL>", b"<SOH>", b"<STX>", b"<ETX>", b"<EOT>", b"<ENQ>", b"<ACK>", b"<BEL>", b"<BS>",
+    b"<TAB>", b"<LF>", b"<VT>", b"<FF>", b"<CR>", b"<SO>", b"<SI>", b"<DLE>", b"<DC1>", b"<DC2>",
+    b"<DC3>", b"<DC4>", b"<NAK>", b"<SYN>", b"<ETB>", b"<CAN>", b"<EM>", b"<SUB>", b"<ESC>",
+    b"<FS>", b"<GS>", b"<RS>", b"<US>",
+];
+
+/// Looks up the token for a control key.
+///
+/// # Details
+/// Covers the C0 set (0x00-0x1F) and DEL (0x7F), e.g. Enter as
+/// `<CR>` and backspace as `<BS>`.
+///
+/// # Arguments
+/// * `ch` - Byte received
+///
+/// # Returns
+/// * `Option<&'static [u8]>` - Bracketed name, or None if not a control key
+#[allow(dead_code)]
+pub fn control_name(ch: u8) -> Option<&'static [u8]> {
+    match ch {
+        0x00..=0x1F => Some(C0_NAMES[usize::from(ch)]),
+        0x7F => Some(b"<DEL>"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Control Name Tests ====================
+
+    #[test]
+    fn test_common_keys_named() {
+        assert_eq!(control_name(0x0D), Some(&b"<CR>"[..]));
+        assert_eq!(control_name(0x09), Some(&b"<TAB>"[..]));
+        assert_eq!(control_name(0x1B), Some(&b"<ESC>"[..]));
+        assert_eq!(control_name(0x08), Some(&b"<BS>"[..]));
+    }
+
+    #[test]
+    fn test_c0_range_ends_named() {
+        assert_eq!(control_name(0x00), Some(&b"<NUL>"[..]));
+        assert_eq!(control_name(0x1F), Some(&b"<US>"[..]));
+        assert_eq!(control_name(0x7F), Some(&b"<DEL>"[..]));
+    }
+
+    #[test]
+    fn test_printable_not_named() {
+        for ch in 0x20..=0x7Eu8 {
+            assert_eq!(control_name(ch), None);
+        }
+        assert_eq!(control_name(0x80), None);
+    }
+
+    #[test]
+    fn test_every_c0_name_bracketed() {
+        for ch in 0x00..=0x1Fu8 {
+            let name = control_name(ch).unwrap();
+            assert!(name.starts_with(b"<") && name.ends_with(b">"));
+        }
+    }
+}
@@ -38,8 +38,55 @@
 //!
 //! AUTHOR: Kevin Thomas
 //! CREATION DATE: December 4, 2025
-//! UPDATE DATE: December 4, 2025
+//! UPDATE DATE: October 16, 2026
 
 #![cfg_attr(not(test), no_std)]
+pub mod banner;
+pub mod base64;
+pub mod beep;
+pub mod boot;
+pub mod calc;
+pub mod command;
 pub mod config;
+pub mod crc;
+pub mod debounce;
+pub mod ebcdic;
+pub mod errlog;
+pub mod error;
+pub mod escape;
+pub mod events;
+pub mod fmtbuf;
+pub mod frame;
+pub mod ghost;
+pub mod hex;
+pub mod keepalive;
+pub mod keyname;
+pub mod led;
+pub mod loopback;
+pub mod mem;
+pub mod menu;
+pub mod mute;
+pub mod numeric;
+pub mod offload;
+pub mod panic;
+pub mod parity;
+pub mod prbs;
+pub mod prompt;
+pub mod rate;
+pub mod repeat;
+pub mod rle;
+pub mod screensaver;
+pub mod script;
+pub mod sink;
+pub mod stats;
+pub mod status;
+pub mod suppress;
+pub mod sweep;
+pub mod telnet;
+pub mod timestamp;
+pub mod transcript;
+pub mod transform;
+pub mod txqueue;
 pub mod uart;
+pub mod utf8;
+pub mod watchdog;
@@ -0,0 +1,217 @@
+/*
+ * @file loopback.rs
+ * @brief Loopback guard
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: loopback.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Loopback Echo Guard.
+//!
+//! BRIEF:
+//! Remembers recently transmitted bytes so a TX-to-RX loopback
+//! does not re-echo them into an endless echo storm.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Window of recently transmitted bytes.
+///
+/// # Details
+/// Holds the last `N` transmitted bytes, oldest first. A received byte
+/// found in the window is taken as loopback and removed, so each
+/// transmitted byte suppresses at most one received byte.
+///
+/// # Fields
+/// * `buf` - Recent bytes, oldest first
+/// * `len` - Number of valid bytes in `buf`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct RecentTx<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> Default for RecentTx<N> {
+    /// Returns default RecentTx instance.
+    ///
+    /// # Details
+    /// Delegates to new() for initialization.
+    ///
+    /// # Returns
+    /// * `Self` - New empty RecentTx
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> RecentTx<N> {
+    /// Creates an empty window.
+    ///
+    /// # Returns
+    /// * `Self` - New RecentTx with no remembered bytes
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self {
+            buf: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the number of remembered bytes.
+    ///
+    /// # Returns
+    /// * `usize` - Bytes in the window
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the window is empty.
+    ///
+    /// # Returns
+    /// * `bool` - True if no bytes are remembered
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Remembers a transmitted byte.
+    ///
+    /// # Details
+    /// Forgets the oldest byte when the window is full.
+    ///
+    /// # Arguments
+    /// * `byte` - Byte that was transmitted
+    #[allow(dead_code)]
+    pub fn push(&mut self, byte: u8) {
+        if N == 0 {
+            return;
+        }
+        if self.len == N {
+            self.buf.copy_within(1.., 0);
+            self.len -= 1;
+        }
+        self.buf[self.len] = byte;
+        self.len += 1;
+    }
+
+    /// Checks a received byte against the window and removes a match.
+    ///
+    /// # Arguments
+    /// * `byte` - Byte that was received
+    ///
+    /// # Returns
+    /// * `bool` - True if the byte was recently transmitted
+    #[allow(dead_code)]
+    pub fn take(&mut self, byte: u8) -> bool {
+        match self.buf[..self.len].iter().position(|&b| b == byte) {
+            Some(i) => {
+                self.buf.copy_within(i + 1..self.len, i);
+                self.len -= 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Forgets all remembered bytes.
+    #[allow(dead_code)]
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== RecentTx Tests ====================
+
+    #[test]
+    fn test_new_is_empty() {
+        let recent: RecentTx<4> = RecentTx::new();
+        assert!(recent.is_empty());
+        assert_eq!(recent.len(), 0);
+    }
+
+    #[test]
+    fn test_take_matching_byte() {
+        let mut recent: RecentTx<4> = RecentTx::new();
+        recent.push(b'a');
+        assert!(recent.take(b'a'));
+        assert!(recent.is_empty());
+    }
+
+    #[test]
+    fn test_take_unrelated_byte() {
+        let mut recent: RecentTx<4> = RecentTx::new();
+        recent.push(b'a');
+        assert!(!recent.take(b'b'));
+        assert_eq!(recent.len(), 1);
+    }
+
+    #[test]
+    fn test_take_consumes_one_occurrence() {
+        let mut recent: RecentTx<4> = RecentTx::new();
+        recent.push(b'a');
+        assert!(recent.take(b'a'));
+        assert!(!recent.take(b'a'));
+    }
+
+    #[test]
+    fn test_take_from_middle_keeps_order() {
+        let mut recent: RecentTx<4> = RecentTx::new();
+        recent.push(b'a');
+        recent.push(b'b');
+        recent.push(b'c');
+        assert!(recent.take(b'b'));
+        assert!(recent.take(b'a'));
+        assert!(recent.take(b'c'));
+        assert!(recent.is_empty());
+    }
+
+    #[test]
+    fn test_push_full_forgets_oldest() {
+        let mut recent: RecentTx<2> = RecentTx::new();
+        recent.push(b'a');
+        recent.push(b'b');
+        recent.push(b'c');
+        assert_eq!(recent.len(), 2);
+        assert!(!recent.take(b'a'));
+        assert!(recent.take(b'c'));
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut recent: RecentTx<4> = RecentTx::new();
+        recent.push(b'a');
+        recent.clear();
+        assert!(!recent.take(b'a'));
+    }
+}
@@ -38,30 +38,450 @@
 //!
 //! AUTHOR: Kevin Thomas
 //! CREATION DATE: December 4, 2025
-//! UPDATE DATE: December 5, 2025
+//! UPDATE DATE: October 16, 2026
 
 #![no_std]
 #![no_main]
 
+mod banner;
+mod base64;
+mod beep;
+mod boot;
+mod calc;
+mod command;
 mod config;
+mod crc;
+mod debounce;
+mod ebcdic;
+mod errlog;
+mod error;
+mod escape;
+mod events;
+mod fmtbuf;
+mod frame;
+mod ghost;
+mod hex;
+mod keepalive;
+mod keyname;
+mod led;
+mod loopback;
+mod mem;
+mod menu;
+mod mute;
+mod numeric;
+mod offload;
+#[cfg(feature = "panic-uart")]
+mod panic;
+mod parity;
+mod prbs;
+mod prompt;
+mod rate;
+mod repeat;
+mod rle;
+mod screensaver;
+mod script;
+mod sink;
+mod stats;
+mod status;
+mod suppress;
+mod sweep;
+mod telnet;
+mod timestamp;
+mod transcript;
+mod transform;
+mod txqueue;
 mod uart;
+mod utf8;
+mod watchdog;
 
-use config::UART_BAUD_RATE;
+use banner::banner_refresh_due;
+use beep::beep_active;
+use boot::dots_for_elapsed;
+use config::{
+    BANNER_REFRESH_MS, BEEP_FREQ_HZ, BEEP_MS, BELL, BOOT_DELAY_MS, BOOT_DOT_INTERVAL_MS,
+    BUTTON_DEBOUNCE_MS, DEBOUNCE_US, DEMO_INTERVAL_MS, DEMO_SCRIPT, ECHO_BUF_SIZE, FLASH_BASE,
+    FLUSH_ACK, GHOST_TYPING, IDLE_TICK_MS, KEEPALIVE_BYTE, KEEPALIVE_INTERVAL_MS, LED_ON_TIME_MS,
+    LOOP_REPORT_MS, MUTE_WINDOW_MS, RATE_BUCKETS, RATE_BUCKET_MS, REPEAT_FILTER_MS, RX_BATCH_SIZE,
+    RX_RING_SIZE, SCREENSAVER_FRAME_MS, SCREENSAVER_IDLE_MS, SCREENSAVER_WIDTH, TEE_UART1,
+    TX_FIFO_DEPTH, TX_QUEUE_SIZE, TX_THROTTLE_THRESHOLD, UART_BAUD_RATE, WATCHDOG_FEED_MS,
+    WATCHDOG_TIMEOUT_MS,
+};
+#[cfg(feature = "core1-offload")]
+use config::{CORE1_STACK_SIZE, OFFLOAD_QUEUE_DEPTH};
+use cortex_m::peripheral::SCB;
+use crc::crc32;
+use debounce::is_bounce;
 use embassy_executor::Spawner;
-use embassy_rp::uart::{Config, Uart};
-use embassy_rp::{bind_interrupts, peripherals::UART0, uart::InterruptHandler};
+use embassy_rp::clocks::clk_sys_freq;
+use embassy_rp::gpio::{Input, Level, Output, Pull};
+#[cfg(feature = "core1-offload")]
+use embassy_rp::multicore::{spawn_core1, Stack};
+use embassy_rp::pwm::{Config as PwmConfig, Pwm};
+use embassy_rp::uart::{Async, Config, Error as UartRxError, Uart, UartTx};
+use embassy_rp::watchdog::Watchdog;
+use embassy_rp::{bind_interrupts, pac, peripherals::UART0, uart::InterruptHandler};
+#[cfg(feature = "core1-offload")]
+use embassy_sync::{blocking_mutex::raw::CriticalSectionRawMutex, channel::Channel};
+use embassy_time::{with_timeout, Duration, Instant, Timer};
+use keepalive::keepalive_due;
+use led::led_state;
+use mem::free_bytes;
+use mute::is_muted;
+#[cfg(feature = "core1-offload")]
+use offload::{Job, JobResult};
+#[cfg(not(feature = "panic-uart"))]
 use panic_halt as _;
-use uart::UartController;
+use rate::RateMeter;
+use repeat::accept_repeat;
+use screensaver::{screensaver_clear, screensaver_frame, screensaver_tick};
+use script::ScriptPlayer;
+use sink::{fan_out, ByteSink};
+use stats::per_second;
+use txqueue::{should_throttle, TxQueue};
+use uart::{initial_mode, UartController};
+use watchdog::should_feed;
 
 bind_interrupts!(struct Irqs {
     UART0_IRQ => InterruptHandler<UART0>;
 });
 
+/// Jobs queued by core0 for the core1 worker.
+#[cfg(feature = "core1-offload")]
+static OFFLOAD_JOBS: Channel<CriticalSectionRawMutex, Job, OFFLOAD_QUEUE_DEPTH> = Channel::new();
+
+/// Results queued by the core1 worker for core0.
+#[cfg(feature = "core1-offload")]
+static OFFLOAD_RESULTS: Channel<CriticalSectionRawMutex, JobResult, OFFLOAD_QUEUE_DEPTH> =
+    Channel::new();
+
+/// Stack handed to core1 at launch.
+#[cfg(feature = "core1-offload")]
+static mut CORE1_STACK: Stack<CORE1_STACK_SIZE> = Stack::new();
+
+/// Estimates free space in the UART0 transmit FIFO.
+///
+/// # Details
+/// Reads the PL011 flag register, which only reports empty and full.
+/// A partially filled FIFO is reported as having one free slot.
+///
+/// # Returns
+/// * `usize` - Estimated free TX FIFO slots
+fn tx_fifo_free() -> usize {
+    let flags = pac::UART0.uartfr().read();
+    if flags.txfe() {
+        TX_FIFO_DEPTH
+    } else if flags.txff() {
+        0
+    } else {
+        1
+    }
+}
+
+/// Writes the oldest contiguous chunk of queued output.
+///
+/// # Arguments
+/// * `uart` - UART transmitter to send on
+/// * `tx_queue` - Queue holding deferred echo output
+///
+/// # Returns
+/// * `usize` - Number of bytes written
+async fn send_chunk(uart: &mut UartTx<'_, Async>, tx_queue: &mut TxQueue<TX_QUEUE_SIZE>) -> usize {
+    let chunk = tx_queue.peek();
+    let _ = uart.write(chunk).await;
+    let sent = chunk.len();
+    tx_queue.consume(sent);
+    sent
+}
+
+/// Sends queued output until both queues can take `need` more bytes.
+///
+/// # Details
+/// Called between received bytes so a batch cannot overrun the queues
+/// before the loop drains them. With FLUSH_ACK set, each UART0 chunk
+/// is flushed and credited to the controller.
+///
+/// # Arguments
+/// * `need` - Free bytes required in each queue
+/// * `tx` - UART0 transmitter
+/// * `tx_queue` - UART0 output queue
+/// * `tee` - UART1 transmitter, present only with TEE_UART1 set
+/// * `tee_queue` - UART1 mirror queue
+/// * `controller` - Controller credited with flushed bytes
+async fn make_room(
+    need: usize,
+    tx: &mut UartTx<'_, Async>,
+    tx_queue: &mut TxQueue<TX_QUEUE_SIZE>,
+    tee: &mut Option<UartTx<'_, Async>>,
+    tee_queue: &mut TxQueue<TX_QUEUE_SIZE>,
+    controller: &mut UartController,
+) {
+    while tx_queue.free() < need {
+        let sent = send_chunk(tx, tx_queue).await;
+        if FLUSH_ACK && tx.blocking_flush().is_ok() {
+            controller.record_flushed(sent);
+        }
+    }
+    if let Some(tee) = tee {
+        while tee_queue.free() < need {
+            send_chunk(tee, tee_queue).await;
+        }
+    }
+}
+
+/// Waits BOOT_DELAY_MS before startup, sending progress dots.
+///
+/// # Details
+/// Sends one dot per BOOT_DOT_INTERVAL_MS on UART0, then CRLF. Does
+/// nothing when BOOT_DELAY_MS is zero.
+///
+/// # Arguments
+/// * `uart` - UART transmitter to send on
+async fn boot_delay(uart: &mut UartTx<'_, Async>) {
+    if BOOT_DELAY_MS == 0 {
+        return;
+    }
+    let start = Instant::now().as_millis();
+    let mut sent = 0;
+    loop {
+        let elapsed = (Instant::now().as_millis() - start).min(BOOT_DELAY_MS);
+        while sent < dots_for_elapsed(elapsed, BOOT_DOT_INTERVAL_MS) {
+            let _ = uart.write(b".").await;
+            sent += 1;
+        }
+        if elapsed >= BOOT_DELAY_MS {
+            break;
+        }
+        Timer::after_millis(BOOT_DOT_INTERVAL_MS).await;
+    }
+    let _ = uart.write(b"\r\n").await;
+}
+
+/// Queues output for UART0 and, when enabled, the UART1 tee.
+///
+/// # Details
+/// A response that does not fit is dropped whole and counted by its
+/// queue; the main loop logs the drop as TxQueueFull.
+///
+/// # Arguments
+/// * `bytes` - Bytes to send
+/// * `tx_queue` - UART0 output queue
+/// * `tee_queue` - UART1 mirror queue
+fn emit(
+    bytes: &[u8],
+    tx_queue: &mut TxQueue<TX_QUEUE_SIZE>,
+    tee_queue: &mut TxQueue<TX_QUEUE_SIZE>,
+) {
+    let outputs = if TEE_UART1 { 2 } else { 1 };
+    let _ = fan_out(
+        bytes,
+        &mut [tx_queue as &mut dyn ByteSink, tee_queue][..outputs],
+    );
+}
+
+/// Reports a panic on UART0 and halts.
+///
+/// # Details
+/// Enabled by the `panic-uart` feature in place of panic_halt.
+/// Interrupts are disabled and the report is written straight to the
+/// UART0 data register, so it lands even if a DMA transfer was in
+/// flight. Each byte waits at most PANIC_TX_SPIN_LIMIT polls for FIFO
+/// space.
+///
+/// # Arguments
+/// * `info` - Panic location and message
+///
+/// # Returns
+/// * `!` - Never returns
+#[cfg(feature = "panic-uart")]
+#[panic_handler]
+fn panic(info: &core::panic::PanicInfo) -> ! {
+    cortex_m::interrupt::disable();
+    let mut msg = [0u8; config::PANIC_MSG_SIZE];
+    let n = match info.location() {
+        Some(loc) => panic::panic_message(loc.file(), loc.line(), &mut msg),
+        None => panic::panic_message("unknown", 0, &mut msg),
+    };
+    for &b in &msg[..n] {
+        let mut spins = 0;
+        while pac::UART0.uartfr().read().txff() && spins < config::PANIC_TX_SPIN_LIMIT {
+            spins += 1;
+        }
+        pac::UART0.uartdr().write(|w| w.set_data(b));
+    }
+    loop {
+        cortex_m::asm::nop();
+    }
+}
+
+/// Runs offloaded jobs on core1 forever.
+///
+/// # Details
+/// Enabled by the `core1-offload` feature. Polls OFFLOAD_JOBS without
+/// an executor, so core1 needs no interrupts of its own. A result waits
+/// for space in OFFLOAD_RESULTS rather than being dropped.
+///
+/// # Returns
+/// * `!` - Never returns
+#[cfg(feature = "core1-offload")]
+fn offload_worker() -> ! {
+    loop {
+        if let Ok(job) = OFFLOAD_JOBS.try_receive() {
+            let result = offload::run(&job);
+            while OFFLOAD_RESULTS.try_send(result).is_err() {
+                cortex_m::asm::nop();
+            }
+        }
+    }
+}
+
+/// Queues a job for the core1 worker.
+///
+/// # Arguments
+/// * `job` - The job to run
+///
+/// # Returns
+/// * `bool` - False if OFFLOAD_JOBS is full and the job was not queued
+#[cfg(feature = "core1-offload")]
+#[allow(dead_code)]
+fn offload_submit(job: Job) -> bool {
+    OFFLOAD_JOBS.try_send(job).is_ok()
+}
+
+/// Emits every result core1 has finished since the last call.
+///
+/// # Details
+/// A checksum is shown as "crc: XXXX", output bytes are sent as-is, and
+/// a failed job reports "offload failed". Each result ends with CRLF.
+///
+/// # Arguments
+/// * `hex_uppercase` - True for uppercase checksum digits
+/// * `tx_queue` - UART0 output queue
+/// * `tee_queue` - UART1 mirror queue
+#[cfg(feature = "core1-offload")]
+fn offload_drain(
+    hex_uppercase: bool,
+    tx_queue: &mut TxQueue<TX_QUEUE_SIZE>,
+    tee_queue: &mut TxQueue<TX_QUEUE_SIZE>,
+) {
+    while let Ok(result) = OFFLOAD_RESULTS.try_receive() {
+        match result {
+            JobResult::Crc16(crc) => {
+                emit(b"crc: ", tx_queue, tee_queue);
+                for b in crc.to_be_bytes() {
+                    emit(&hex::byte_to_hex(b, hex_uppercase), tx_queue, tee_queue);
+                }
+            }
+            JobResult::Bytes(bytes) => emit(bytes.as_slice(), tx_queue, tee_queue),
+            JobResult::Failed => emit(b"offload failed", tx_queue, tee_queue),
+        }
+        emit(b"\r\n", tx_queue, tee_queue);
+    }
+}
+
+/// Returns the first address past static data.
+///
+/// # Details
+/// Reads the `__sheap` symbol placed by the cortex-m-rt linker script
+/// after .bss and .data. The stack grows down toward it.
+///
+/// # Returns
+/// * `u32` - Heap start address
+fn heap_start() -> u32 {
+    extern "C" {
+        static __sheap: u8;
+    }
+    core::ptr::addr_of!(__sheap) as u32
+}
+
+/// Returns the application image in flash.
+///
+/// # Details
+/// The image runs from FLASH_BASE through the end of the .data load
+/// image, found from the cortex-m-rt `__sidata`, `__sdata`, and
+/// `__edata` symbols.
+///
+/// # Returns
+/// * `&'static [u8]` - Memory-mapped application image
+fn flash_image() -> &'static [u8] {
+    extern "C" {
+        static __sidata: u8;
+        static __sdata: u8;
+        static __edata: u8;
+    }
+    let data_len = core::ptr::addr_of!(__edata) as usize - core::ptr::addr_of!(__sdata) as usize;
+    let end = core::ptr::addr_of!(__sidata) as usize + data_len;
+    // SAFETY: XIP flash is mapped read-only from FLASH_BASE through the image end.
+    unsafe { core::slice::from_raw_parts(FLASH_BASE as *const u8, end - FLASH_BASE) }
+}
+
+/// Reboots into the RP2350 ROM's UF2 bootloader.
+///
+/// # Details
+/// The ROM reboot call with the BOOTSEL type is the RP2350 form of
+/// reset_to_usb_boot. Both USB interfaces stay enabled and no GPIO
+/// activity LED is configured.
+///
+/// # Returns
+/// * `!` - Never returns; the chip reboots
+fn reset_to_usb_boot() -> ! {
+    const REBOOT_TYPE_BOOTSEL: u32 = 0x0002;
+    // SAFETY: the ROM reboot call takes plain values and only resets the chip.
+    unsafe {
+        embassy_rp::rom_data::reboot(REBOOT_TYPE_BOOTSEL, 10, 0, 0);
+    }
+    loop {
+        cortex_m::asm::nop();
+    }
+}
+
 /// Main application entry point.
 ///
 /// # Details
 /// Initializes Embassy runtime and runs the main UART echo loop.
 /// Uses UartController for state management.
+/// Waits out BOOT_DELAY_MS, then sends a startup banner before echoing.
+/// Received bytes are captured continuously by a DMA ring buffer and
+/// read in batches of up to RX_BATCH_SIZE, each byte processed in order
+/// once the TX queues have room for a full response.
+/// Output dropped because a TX queue was full is logged as TxQueueFull.
+/// Idle ticks let the controller resolve a lone ESC keypress and send
+/// KEEPALIVE_BYTE after each KEEPALIVE_INTERVAL_MS without input.
+/// Loop iterations are counted and, with LOOP_REPORT_MS set, reported
+/// per second on an idle tick once each interval.
+/// The demo script plays while idle until the first live byte arrives.
+/// With BANNER_REFRESH_MS set, the banner is re-sent once per idle period.
+/// With SCREENSAVER_IDLE_MS set, an idle line shows a bouncing character
+/// until the next key erases it.
+/// Echo output is queued and deferred while the TX FIFO is full.
+/// With FLUSH_ACK set, each UART0 write is flushed before its bytes
+/// count toward the controller's flushed_count().
+/// With TEE_UART1 set, UART1 is claimed and the same output is mirrored
+/// to it; otherwise its pins and DMA channel are left free.
+/// With GHOST_TYPING set, each character waits a generated delay first,
+/// after the previous character's echo has been sent, with the watchdog
+/// fed before the wait.
+/// A delay set by the delay command is waited before each character too,
+/// after the previous character's echo has been sent; the watchdog is
+/// fed before each wait so a batch cannot starve it.
+/// Input held during command output is replayed once the queue drains.
+/// Typed input is not echoed for MUTE_WINDOW_MS after a command runs.
+/// With REPEAT_FILTER_MS set, a held key is accepted once per interval.
+/// An export streams one chunk each time the TX queue drains.
+/// A PRBS-7 test pattern streams the same way.
+/// The mem command reports the gap between the stack and heap start.
+/// The flashinfo command reports the size and CRC-32 of the flash image.
+/// Pressing the BUTTON_PIN button emits the stats report.
+/// With BEEP_MS set, a BEL received or emitted sounds the BUZZER_PIN
+/// buzzer for BEEP_MS; otherwise its PWM slice and pin are not claimed.
+/// The MODE_SELECT_PIN jumper, read once at boot, selects echo or
+/// command-only mode.
+/// Errors are timestamped with the loop's clock for the errors command.
+/// With the `core1-offload` feature, core1 runs queued offload jobs and
+/// their results are emitted from this loop.
+/// A confirmed RESET command flushes pending output and resets the chip.
+/// A confirmed bootsel command flushes and reboots into the bootloader.
+/// The hardware watchdog is fed once per WATCHDOG_FEED_MS, including
+/// on idle ticks, so only a hung loop triggers a reset.
 ///
 /// # Arguments
 /// * `_spawner` - Embassy task spawner (reserved for future async tasks).
@@ -71,17 +491,290 @@ bind_interrupts!(struct Irqs {
 #[embassy_executor::main]
 async fn main(_spawner: Spawner) {
     let p = embassy_rp::init(Default::default());
+    #[cfg(feature = "core1-offload")]
+    spawn_core1(
+        p.CORE1,
+        // SAFETY: CORE1_STACK is borrowed only here, once, before core1 starts.
+        unsafe { &mut *core::ptr::addr_of_mut!(CORE1_STACK) },
+        offload_worker,
+    );
     let mut config = Config::default();
     config.baudrate = UART_BAUD_RATE;
-    let mut uart = Uart::new(
+    let uart = Uart::new(
         p.UART0, p.PIN_0, p.PIN_1, Irqs, p.DMA_CH0, p.DMA_CH1, config,
     );
+    let (mut tx, rx) = uart.split();
+    let mut ring = [0u8; RX_RING_SIZE];
+    let mut rx = rx.into_ring_buffered(&mut ring);
+    let mut tee = TEE_UART1.then(|| UartTx::new(p.UART1, p.PIN_4, p.DMA_CH2, config));
+    boot_delay(&mut tx).await;
+    let mode_select = Input::new(p.PIN_14, Pull::Up);
     let mut controller = UartController::new();
-    let mut buf = [0u8; 1];
+    controller.set_ghost_typing(GHOST_TYPING);
+    let mut tx_queue: TxQueue<TX_QUEUE_SIZE> = TxQueue::new();
+    let mut tee_queue: TxQueue<TX_QUEUE_SIZE> = TxQueue::new();
+    let mut player = ScriptPlayer::new(DEMO_SCRIPT, DEMO_INTERVAL_MS);
+    let mut led = Output::new(p.PIN_25, Level::Low);
+    let button = Input::new(p.PIN_15, Pull::Up);
+    let mut button_was_low = false;
+    let mut last_press: Option<u64> = None;
+    let mut last_activity: Option<u64> = None;
+    let mut beep_cfg = PwmConfig::default();
+    beep_cfg.top = (clk_sys_freq() / BEEP_FREQ_HZ - 1) as u16;
+    let beep_duty = beep_cfg.top / 2;
+    let mut buzzer =
+        (BEEP_MS > 0).then(|| Pwm::new_output_a(p.PWM_SLICE0, p.PIN_16, beep_cfg.clone()));
+    let mut beep_start: Option<u64> = None;
+    let mut beeping = false;
+    let mut last_rx: Option<(u8, u64)> = None;
+    let mut accepted_at: [Option<u64>; 256] = [None; 256];
+    let mut mute_until: u64 = 0;
+    let mut last_traffic = Instant::now().as_millis();
+    let mut last_key = last_traffic;
+    let mut saver_tick: Option<u64> = None;
+    let mut banner_refreshed = false;
+    let mut saver_buf = [0u8; SCREENSAVER_WIDTH + 2];
+    let mut loop_count: u64 = 0;
+    let mut loop_window_start = last_traffic;
+    let mut meter: RateMeter<RATE_BUCKETS> = RateMeter::new(RATE_BUCKET_MS);
+    let mut batch = [0u8; RX_BATCH_SIZE];
+    let mut watchdog = Watchdog::new(p.WATCHDOG);
+    watchdog.start(Duration::from_millis(WATCHDOG_TIMEOUT_MS));
+    let mut last_feed = Instant::now().as_millis();
+    emit(controller.banner(), &mut tx_queue, &mut tee_queue);
+    let mode = initial_mode(mode_select.is_high());
+    emit(
+        controller.set_initial_mode(mode),
+        &mut tx_queue,
+        &mut tee_queue,
+    );
     loop {
-        if uart.read(&mut buf).await.is_ok() {
-            let echo_bytes = controller.process_char(buf[0]);
-            let _ = uart.write(echo_bytes).await;
+        loop_count += 1;
+        #[cfg(feature = "core1-offload")]
+        offload_drain(controller.hex_uppercase(), &mut tx_queue, &mut tee_queue);
+        let tick = Duration::from_millis(IDLE_TICK_MS);
+        let received = match with_timeout(tick, rx.read(&mut batch)).await {
+            Ok(Ok(n)) => n,
+            Ok(Err(UartRxError::Overrun)) => {
+                controller.set_time(Instant::now().as_millis());
+                controller.record_overrun();
+                0
+            }
+            Ok(Err(_)) => 0,
+            Err(_) => {
+                emit(controller.idle(), &mut tx_queue, &mut tee_queue);
+                let now = Instant::now().as_millis();
+                if keepalive_due(now - last_traffic, KEEPALIVE_INTERVAL_MS) {
+                    emit(&[KEEPALIVE_BYTE], &mut tx_queue, &mut tee_queue);
+                    last_traffic = now;
+                }
+                if banner_refresh_due(now - last_key, BANNER_REFRESH_MS, banner_refreshed) {
+                    emit(b"\r\n", &mut tx_queue, &mut tee_queue);
+                    emit(controller.banner(), &mut tx_queue, &mut tee_queue);
+                    banner_refreshed = true;
+                }
+                let due =
+                    screensaver_tick(now - last_key, SCREENSAVER_IDLE_MS, SCREENSAVER_FRAME_MS);
+                if let Some(frame) = due.filter(|&t| saver_tick != Some(t)) {
+                    let n = screensaver_frame(frame, SCREENSAVER_WIDTH, &mut saver_buf);
+                    emit(&saver_buf[..n], &mut tx_queue, &mut tee_queue);
+                    saver_tick = Some(frame);
+                }
+                let elapsed = now - loop_window_start;
+                if LOOP_REPORT_MS > 0 && elapsed >= LOOP_REPORT_MS {
+                    let rate = per_second(loop_count, elapsed);
+                    emit(controller.loop_report(rate), &mut tx_queue, &mut tee_queue);
+                    loop_count = 0;
+                    loop_window_start = now;
+                }
+                0
+            }
+        };
+        let live_bytes = batch[..received].iter().copied().map(Some);
+        for live in live_bytes.chain((received == 0).then_some(None)) {
+            let live = live.filter(|&ch| {
+                let now = Instant::now().as_micros();
+                let bounce =
+                    last_rx.is_some_and(|(prev, at)| is_bounce(at, now, DEBOUNCE_US, prev == ch));
+                last_rx = Some((ch, now));
+                !bounce
+            });
+            let live = live.filter(|&ch| {
+                let now = Instant::now().as_millis();
+                let last = &mut accepted_at[usize::from(ch)];
+                let accept = last.is_none_or(|at| accept_repeat(at, now, REPEAT_FILTER_MS));
+                if accept {
+                    *last = Some(now);
+                }
+                accept
+            });
+            if live.is_some() {
+                last_traffic = Instant::now().as_millis();
+                last_key = last_traffic;
+                banner_refreshed = false;
+                meter.record(last_traffic, 1);
+                if saver_tick.take().is_some() {
+                    let n = screensaver_clear(SCREENSAVER_WIDTH, &mut saver_buf);
+                    emit(&saver_buf[..n], &mut tx_queue, &mut tee_queue);
+                }
+            }
+            if let Some(ch) = player.select(live, Instant::now().as_millis()) {
+                make_room(
+                    ECHO_BUF_SIZE,
+                    &mut tx,
+                    &mut tx_queue,
+                    &mut tee,
+                    &mut tee_queue,
+                    &mut controller,
+                )
+                .await;
+                if controller.ghost_typing() {
+                    make_room(
+                        TX_QUEUE_SIZE,
+                        &mut tx,
+                        &mut tx_queue,
+                        &mut tee,
+                        &mut tee_queue,
+                        &mut controller,
+                    )
+                    .await;
+                    watchdog.feed();
+                    last_feed = Instant::now().as_millis();
+                    Timer::after_millis(u64::from(controller.next_delay())).await;
+                }
+                if controller.echo_delay() > 0 {
+                    make_room(
+                        TX_QUEUE_SIZE,
+                        &mut tx,
+                        &mut tx_queue,
+                        &mut tee,
+                        &mut tee_queue,
+                        &mut controller,
+                    )
+                    .await;
+                    watchdog.feed();
+                    last_feed = Instant::now().as_millis();
+                    Timer::after_millis(u64::from(controller.echo_delay())).await;
+                }
+                controller.set_muted(is_muted(Instant::now().as_millis(), mute_until));
+                controller.set_time(Instant::now().as_millis());
+                let echo = controller.process_char(ch);
+                if !echo.is_empty() {
+                    last_activity = Some(Instant::now().as_millis());
+                }
+                if ch == BELL || echo.contains(&BELL) {
+                    beep_start = Some(Instant::now().as_millis());
+                }
+                emit(echo, &mut tx_queue, &mut tee_queue);
+                if controller.take_command_executed() {
+                    mute_until = Instant::now().as_millis() + MUTE_WINDOW_MS;
+                }
+                if controller.take_line_committed() {
+                    make_room(
+                        ECHO_BUF_SIZE,
+                        &mut tx,
+                        &mut tx_queue,
+                        &mut tee,
+                        &mut tee_queue,
+                        &mut controller,
+                    )
+                    .await;
+                    let now = Instant::now().as_millis();
+                    if controller.line_timing() {
+                        let report = controller.line_timing_report(now);
+                        emit(report, &mut tx_queue, &mut tee_queue);
+                    }
+                    if controller.timestamp_lines() {
+                        let line = controller.timestamped_line(now);
+                        emit(line, &mut tx_queue, &mut tee_queue);
+                    }
+                }
+            }
+        }
+        let now = Instant::now().as_millis();
+        if tx_queue.take_dropped() + tee_queue.take_dropped() > 0 {
+            controller.set_time(now);
+            controller.record_tx_drop();
+        }
+        if should_feed(last_feed, now, WATCHDOG_FEED_MS) {
+            watchdog.feed();
+            last_feed = now;
+        }
+        let button_low = button.is_low();
+        if button_low && !button_was_low {
+            if last_press.is_none_or(|at| now - at >= BUTTON_DEBOUNCE_MS) {
+                emit(controller.press_button(), &mut tx_queue, &mut tee_queue);
+            }
+            last_press = Some(now);
+        }
+        button_was_low = button_low;
+        let lit = last_activity.is_some_and(|t| led_state(t, now, LED_ON_TIME_MS));
+        led.set_level(Level::from(lit));
+        let beep = beep_start.is_some_and(|t| beep_active(t, now, BEEP_MS));
+        if beep != beeping {
+            beep_cfg.compare_a = if beep { beep_duty } else { 0 };
+            if let Some(buzzer) = buzzer.as_mut() {
+                buzzer.set_config(&beep_cfg);
+            }
+            beeping = beep;
+        }
+        if !tx_queue.is_empty() && !should_throttle(tx_fifo_free(), TX_THROTTLE_THRESHOLD) {
+            let sent = send_chunk(&mut tx, &mut tx_queue).await;
+            if FLUSH_ACK && tx.blocking_flush().is_ok() {
+                controller.record_flushed(sent);
+            }
+        }
+        if let Some(tee) = tee.as_mut() {
+            if !tee_queue.is_empty() {
+                send_chunk(tee, &mut tee_queue).await;
+            }
+        }
+        if tx_queue.is_empty() && controller.output_pending() {
+            emit(controller.finish_output(), &mut tx_queue, &mut tee_queue);
+        }
+        if tx_queue.is_empty() && controller.export_pending() {
+            emit(controller.export_chunk(), &mut tx_queue, &mut tee_queue);
+        }
+        if tx_queue.is_empty() && controller.pattern_pending() {
+            emit(controller.pattern_chunk(), &mut tx_queue, &mut tee_queue);
+        }
+        if controller.take_status_request() {
+            let flags = pac::UART0.uartfr().read().0;
+            emit(controller.uart_status(flags), &mut tx_queue, &mut tee_queue);
+        }
+        if controller.take_rate_request() {
+            let rate = meter.current_rate(Instant::now().as_millis());
+            emit(controller.rate_report(rate), &mut tx_queue, &mut tee_queue);
+        }
+        if controller.take_flash_info_request() {
+            let image = flash_image();
+            let report = controller.flash_info_report(image.len() as u32, crc32(image));
+            emit(report, &mut tx_queue, &mut tee_queue);
+        }
+        if controller.take_mem_request() {
+            let free = free_bytes(cortex_m::register::msp::read(), heap_start());
+            emit(controller.mem_report(free), &mut tx_queue, &mut tee_queue);
+        }
+        if controller.take_reset_request() {
+            while !tx_queue.is_empty() {
+                send_chunk(&mut tx, &mut tx_queue).await;
+            }
+            let _ = tx.blocking_flush();
+            SCB::sys_reset();
+        }
+        if controller.take_bootsel_request() {
+            while !tx_queue.is_empty() {
+                send_chunk(&mut tx, &mut tx_queue).await;
+            }
+            let _ = tx.blocking_flush();
+            reset_to_usb_boot();
+        }
+        if let Some(rate) = controller.take_baud_request() {
+            while !tx_queue.is_empty() {
+                send_chunk(&mut tx, &mut tx_queue).await;
+            }
+            let _ = tx.blocking_flush();
+            tx.set_baudrate(rate);
         }
     }
 }
@@ -0,0 +1,124 @@
+/*
+ * @file mem.rs
+ * @brief Free memory reporting
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: mem.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Free Memory Reporting.
+//!
+//! BRIEF:
+//! Formats the approximate free RAM for the mem command.
+//! The stack pointer and heap start are read in main.rs.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+use crate::fmtbuf::FmtBuf;
+use core::fmt::Write;
+
+/// Computes the free RAM between the end of static data and the stack.
+///
+/// # Details
+/// The stack grows down toward the heap start, so the gap between the
+/// two is what remains. A stack pointer below the heap start reports 0.
+///
+/// # Arguments
+/// * `stack_pointer` - Current stack pointer address
+/// * `heap_start` - Address just past .bss and .data
+///
+/// # Returns
+/// * `u32` - Approximate free bytes
+#[allow(dead_code)]
+pub fn free_bytes(stack_pointer: u32, heap_start: u32) -> u32 {
+    stack_pointer.saturating_sub(heap_start)
+}
+
+/// Formats a free memory report.
+///
+/// # Arguments
+/// * `free` - Free bytes
+/// * `out` - Destination buffer
+///
+/// # Returns
+/// * `usize` - Bytes written, or 0 if `out` is too small
+#[allow(dead_code)]
+pub fn format_mem(free: u32, out: &mut [u8]) -> usize {
+    let mut text = FmtBuf::new(out);
+    match write!(text, "free: {} bytes", free) {
+        Ok(()) => text.len(),
+        Err(_) => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Free Bytes Tests ====================
+
+    #[test]
+    fn test_free_is_gap_to_stack() {
+        assert_eq!(free_bytes(0x2008_0000, 0x2000_1000), 0x7_F000);
+    }
+
+    #[test]
+    fn test_overlapping_stack_reports_zero() {
+        assert_eq!(free_bytes(0x2000_0800, 0x2000_1000), 0);
+    }
+
+    // ==================== Format Tests ====================
+
+    #[test]
+    fn test_format_mem() {
+        let mut buf = [0u8; 32];
+        let n = format_mem(4096, &mut buf);
+        assert_eq!(&buf[..n], b"free: 4096 bytes");
+    }
+
+    #[test]
+    fn test_format_mem_zero() {
+        let mut buf = [0u8; 32];
+        let n = format_mem(0, &mut buf);
+        assert_eq!(&buf[..n], b"free: 0 bytes");
+    }
+
+    #[test]
+    fn test_format_mem_max() {
+        let mut buf = [0u8; 32];
+        let n = format_mem(u32::MAX, &mut buf);
+        assert_eq!(&buf[..n], b"free: 4294967295 bytes");
+    }
+
+    #[test]
+    fn test_format_mem_buffer_too_small() {
+        let mut buf = [0u8; 8];
+        assert_eq!(format_mem(4096, &mut buf), 0);
+    }
+}
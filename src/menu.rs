@@ -0,0 +1,105 @@
+/*
+ * @file menu.rs
+ * @brief Settings menu
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: menu.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Settings Menu.
+//!
+//! BRIEF:
+//! Maps single keypresses in the settings menu to actions.
+//! The controller owns the settings and renders the menu.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Actions selectable from the settings menu.
+///
+/// # Variants
+/// * `ToggleEcho` - Turn character echo on or off
+/// * `CycleCase` - Advance to the next case mode
+/// * `CycleNewline` - Advance to the next newline mode
+/// * `Exit` - Leave the menu and return to echo mode
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum MenuAction {
+    ToggleEcho,
+    CycleCase,
+    CycleNewline,
+    Exit,
+}
+
+/// Maps a menu keypress to its action.
+///
+/// # Details
+/// Options are numbered from 1; `q` in either case exits.
+///
+/// # Arguments
+/// * `key` - Key pressed while the menu is open
+///
+/// # Returns
+/// * `Option<MenuAction>` - Selected action, or None for other keys
+#[allow(dead_code)]
+pub fn select(key: u8) -> Option<MenuAction> {
+    match key {
+        b'1' => Some(MenuAction::ToggleEcho),
+        b'2' => Some(MenuAction::CycleCase),
+        b'3' => Some(MenuAction::CycleNewline),
+        b'q' | b'Q' => Some(MenuAction::Exit),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Select Tests ====================
+
+    #[test]
+    fn test_select_numbered_options() {
+        assert_eq!(select(b'1'), Some(MenuAction::ToggleEcho));
+        assert_eq!(select(b'2'), Some(MenuAction::CycleCase));
+        assert_eq!(select(b'3'), Some(MenuAction::CycleNewline));
+    }
+
+    #[test]
+    fn test_select_exit() {
+        assert_eq!(select(b'q'), Some(MenuAction::Exit));
+        assert_eq!(select(b'Q'), Some(MenuAction::Exit));
+    }
+
+    #[test]
+    fn test_select_other_keys() {
+        assert_eq!(select(b'0'), None);
+        assert_eq!(select(b'4'), None);
+        assert_eq!(select(b'\r'), None);
+    }
+}
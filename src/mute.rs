@@ -0,0 +1,86 @@
+/*
+ * @file mute.rs
+ * @brief Echo muting window
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: mute.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Echo Muting Window.
+//!
+//! BRIEF:
+//! Decides whether typed input echo is muted after a command ran.
+//! Keeps a command's own output from being interleaved with the echo of
+//! keys typed while it prints.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Decides whether echo is still muted.
+///
+/// # Details
+/// The main loop sets `until_ms` to the command time plus
+/// MUTE_WINDOW_MS. Echo resumes once `now_ms` reaches it.
+///
+/// # Arguments
+/// * `now_ms` - Current time
+/// * `until_ms` - End of the mute window
+///
+/// # Returns
+/// * `bool` - True if typed input should not be echoed
+#[allow(dead_code)]
+pub fn is_muted(now_ms: u64, until_ms: u64) -> bool {
+    now_ms < until_ms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Mute Window Tests ====================
+
+    #[test]
+    fn test_muted_inside_window() {
+        assert!(is_muted(1000, 1200));
+    }
+
+    #[test]
+    fn test_unmuted_at_window_end() {
+        assert!(!is_muted(1200, 1200));
+    }
+
+    #[test]
+    fn test_unmuted_after_window() {
+        assert!(!is_muted(5000, 1200));
+    }
+
+    #[test]
+    fn test_zero_until_never_muted() {
+        assert!(!is_muted(0, 0));
+    }
+}
@@ -0,0 +1,237 @@
+/*
+ * @file numeric.rs
+ * @brief Numeric entry field
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: numeric.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Numeric Entry Field.
+//!
+//! BRIEF:
+//! Accumulates typed digits into a bounded number for configuration.
+//! Out-of-range input is rejected with the bell.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+use crate::config::{BACKSPACE, BACKSPACE_SEQ, BELL, DELETE};
+
+/// Single-character echo for each decimal digit.
+static DIGITS: [u8; 10] = *b"0123456789";
+
+/// Numeric input field with min/max clamping.
+///
+/// # Details
+/// Digits append to the value, backspace removes the last digit, and
+/// Enter commits. A digit that would exceed `max`, an Enter below
+/// `min`, and any other byte are rejected with the bell.
+///
+/// # Fields
+/// * `value` - Number typed so far
+/// * `digits` - Number of digits typed
+/// * `min` - Smallest value that may be committed
+/// * `max` - Largest value that may be typed
+/// * `committed` - True once Enter accepted the value
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct NumericField {
+    value: u32,
+    digits: usize,
+    min: u32,
+    max: u32,
+    committed: bool,
+}
+
+impl NumericField {
+    /// Creates an empty field accepting values in `min..=max`.
+    ///
+    /// # Arguments
+    /// * `min` - Smallest value that may be committed
+    /// * `max` - Largest value that may be typed
+    ///
+    /// # Returns
+    /// * `Self` - New empty NumericField
+    #[allow(dead_code)]
+    pub fn new(min: u32, max: u32) -> Self {
+        Self {
+            value: 0,
+            digits: 0,
+            min,
+            max,
+            committed: false,
+        }
+    }
+
+    /// Returns the number typed so far.
+    ///
+    /// # Returns
+    /// * `u32` - Current value, 0 when empty
+    #[allow(dead_code)]
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// Returns whether Enter has accepted the value.
+    ///
+    /// # Returns
+    /// * `bool` - True once committed
+    #[allow(dead_code)]
+    pub fn is_committed(&self) -> bool {
+        self.committed
+    }
+
+    /// Processes a keypress.
+    ///
+    /// # Details
+    /// Input after a commit is rejected.
+    ///
+    /// # Arguments
+    /// * `ch` - The character received
+    ///
+    /// # Returns
+    /// * `&'static [u8]` - Bytes to echo back; the bell on rejection
+    #[allow(dead_code)]
+    pub fn feed(&mut self, ch: u8) -> &'static [u8] {
+        if self.committed {
+            return &[BELL];
+        }
+        match ch {
+            b'0'..=b'9' => {
+                let digit = ch - b'0';
+                let next = self
+                    .value
+                    .checked_mul(10)
+                    .and_then(|v| v.checked_add(u32::from(digit)));
+                match next {
+                    Some(v) if v <= self.max => {
+                        self.value = v;
+                        self.digits += 1;
+                        &DIGITS[digit as usize..digit as usize + 1]
+                    }
+                    _ => &[BELL],
+                }
+            }
+            BACKSPACE | DELETE if self.digits > 0 => {
+                self.value /= 10;
+                self.digits -= 1;
+                &BACKSPACE_SEQ
+            }
+            b'\r' | b'\n' if self.digits > 0 && self.value >= self.min => {
+                self.committed = true;
+                b"\r\n"
+            }
+            _ => &[BELL],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_all(field: &mut NumericField, keys: &[u8]) -> Vec<u8> {
+        keys.iter().flat_map(|&k| field.feed(k).to_vec()).collect()
+    }
+
+    // ==================== Numeric Field Tests ====================
+
+    #[test]
+    fn test_new_field_is_empty() {
+        let field = NumericField::new(0, 500);
+        assert_eq!(field.value(), 0);
+        assert!(!field.is_committed());
+    }
+
+    #[test]
+    fn test_digits_accumulate() {
+        let mut field = NumericField::new(0, 500);
+        assert_eq!(type_all(&mut field, b"42"), b"42");
+        assert_eq!(field.value(), 42);
+    }
+
+    #[test]
+    fn test_over_max_rejected() {
+        let mut field = NumericField::new(0, 500);
+        assert_eq!(type_all(&mut field, b"999"), [b'9', b'9', BELL]);
+        assert_eq!(field.value(), 99);
+    }
+
+    #[test]
+    fn test_backspace_removes_digit() {
+        let mut field = NumericField::new(0, 500);
+        type_all(&mut field, b"999");
+        assert_eq!(field.feed(BACKSPACE), &BACKSPACE_SEQ);
+        assert_eq!(field.value(), 9);
+    }
+
+    #[test]
+    fn test_backspace_when_empty_rejected() {
+        let mut field = NumericField::new(0, 500);
+        assert_eq!(field.feed(DELETE), &[BELL]);
+    }
+
+    #[test]
+    fn test_commit() {
+        let mut field = NumericField::new(0, 500);
+        type_all(&mut field, b"999\x08");
+        assert_eq!(field.feed(b'\r'), b"\r\n");
+        assert!(field.is_committed());
+        assert_eq!(field.value(), 9);
+        assert_eq!(field.feed(b'1'), &[BELL]);
+    }
+
+    #[test]
+    fn test_commit_below_min_rejected() {
+        let mut field = NumericField::new(10, 500);
+        type_all(&mut field, b"5");
+        assert_eq!(field.feed(b'\r'), &[BELL]);
+        assert!(!field.is_committed());
+    }
+
+    #[test]
+    fn test_commit_empty_rejected() {
+        let mut field = NumericField::new(0, 500);
+        assert_eq!(field.feed(b'\r'), &[BELL]);
+    }
+
+    #[test]
+    fn test_non_digit_rejected() {
+        let mut field = NumericField::new(0, 500);
+        assert_eq!(field.feed(b'a'), &[BELL]);
+        assert_eq!(field.feed(b'-'), &[BELL]);
+        assert_eq!(field.value(), 0);
+    }
+
+    #[test]
+    fn test_max_u32_does_not_overflow() {
+        let mut field = NumericField::new(0, u32::MAX);
+        type_all(&mut field, b"99999999999");
+        assert_eq!(field.value(), 999_999_999);
+    }
+}
@@ -0,0 +1,279 @@
+/*
+ * @file offload.rs
+ * @brief Core1 offload protocol
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: offload.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Core1 Offload.
+//!
+//! BRIEF:
+//! Message types passed between the UART loop on core0 and core1.
+//! The worker is a pure function so it runs the same on host.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+use crate::base64;
+use crate::config::{OFFLOAD_PAYLOAD_SIZE, RLE_CHUNK_SIZE};
+use crate::crc::crc16;
+use crate::rle::RleDecoder;
+
+/// Fixed-size byte buffer carried by offload messages.
+///
+/// # Details
+/// Messages cross cores through a channel by value, so the payload is
+/// an inline array rather than a borrowed slice.
+///
+/// # Fields
+/// * `data` - Payload storage
+/// * `len` - Number of valid bytes in data
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct Payload {
+    data: [u8; OFFLOAD_PAYLOAD_SIZE],
+    len: usize,
+}
+
+impl Payload {
+    /// Copies a slice into a new payload.
+    ///
+    /// # Arguments
+    /// * `bytes` - Bytes to carry
+    ///
+    /// # Returns
+    /// * `Option<Self>` - Payload, or None if bytes exceed OFFLOAD_PAYLOAD_SIZE
+    #[allow(dead_code)]
+    pub fn from_slice(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() > OFFLOAD_PAYLOAD_SIZE {
+            return None;
+        }
+        let mut data = [0u8; OFFLOAD_PAYLOAD_SIZE];
+        data[..bytes.len()].copy_from_slice(bytes);
+        Some(Self {
+            data,
+            len: bytes.len(),
+        })
+    }
+
+    /// Returns the valid bytes of the payload.
+    ///
+    /// # Returns
+    /// * `&[u8]` - Payload contents
+    #[allow(dead_code)]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.data[..self.len]
+    }
+}
+
+/// Work sent from core0 to core1.
+///
+/// # Variants
+/// * `Crc16` - Compute the CRC-16/CCITT-FALSE of the payload
+/// * `Base64` - Decode the payload as Base64
+/// * `RleExpand` - Expand the payload as count/value run pairs
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum Job {
+    Crc16(Payload),
+    Base64(Payload),
+    RleExpand(Payload),
+}
+
+/// Reply sent from core1 back to core0.
+///
+/// # Variants
+/// * `Crc16` - Checksum of a Crc16 job
+/// * `Bytes` - Output of a Base64 or RleExpand job
+/// * `Failed` - Input was invalid or output exceeded OFFLOAD_PAYLOAD_SIZE
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum JobResult {
+    Crc16(u16),
+    Bytes(Payload),
+    Failed,
+}
+
+/// Runs one offloaded job to completion.
+///
+/// # Details
+/// Called in a loop by the core1 worker. Holds no state between jobs,
+/// so any number of jobs may be queued in any order.
+///
+/// # Arguments
+/// * `job` - The job to run
+///
+/// # Returns
+/// * `JobResult` - Reply for core0
+#[allow(dead_code)]
+pub fn run(job: &Job) -> JobResult {
+    match job {
+        Job::Crc16(input) => JobResult::Crc16(crc16(input.as_slice())),
+        Job::Base64(input) => {
+            let mut out = [0u8; OFFLOAD_PAYLOAD_SIZE];
+            match base64::decode(input.as_slice(), &mut out) {
+                Ok(len) => bytes_result(&out[..len]),
+                Err(_) => JobResult::Failed,
+            }
+        }
+        Job::RleExpand(input) => rle_expand(input.as_slice()),
+    }
+}
+
+/// Wraps output bytes in a result.
+///
+/// # Arguments
+/// * `bytes` - Job output
+///
+/// # Returns
+/// * `JobResult` - Bytes, or Failed if they exceed OFFLOAD_PAYLOAD_SIZE
+fn bytes_result(bytes: &[u8]) -> JobResult {
+    Payload::from_slice(bytes).map_or(JobResult::Failed, JobResult::Bytes)
+}
+
+/// Expands run-length pairs into a single payload.
+///
+/// # Details
+/// A trailing count byte without its value is ignored, matching the
+/// streaming decoder.
+///
+/// # Arguments
+/// * `input` - Count/value pairs
+///
+/// # Returns
+/// * `JobResult` - Expanded bytes, or Failed if they overflow
+fn rle_expand(input: &[u8]) -> JobResult {
+    let mut decoder = RleDecoder::new();
+    let mut out = [0u8; OFFLOAD_PAYLOAD_SIZE];
+    let mut len = 0;
+    let mut chunk = [0u8; RLE_CHUNK_SIZE];
+    for &b in input {
+        if !decoder.feed(b) {
+            continue;
+        }
+        loop {
+            let n = decoder.drain(&mut chunk);
+            if n == 0 {
+                break;
+            }
+            if len + n > OFFLOAD_PAYLOAD_SIZE {
+                return JobResult::Failed;
+            }
+            out[len..len + n].copy_from_slice(&chunk[..n]);
+            len += n;
+        }
+    }
+    bytes_result(&out[..len])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payload(bytes: &[u8]) -> Payload {
+        Payload::from_slice(bytes).unwrap()
+    }
+
+    // ==================== Payload Tests ====================
+
+    #[test]
+    fn test_payload_round_trip() {
+        assert_eq!(payload(b"abc").as_slice(), b"abc");
+    }
+
+    #[test]
+    fn test_payload_empty() {
+        assert!(payload(b"").as_slice().is_empty());
+    }
+
+    #[test]
+    fn test_payload_full_size_fits() {
+        let bytes = [0x5Au8; OFFLOAD_PAYLOAD_SIZE];
+        assert_eq!(payload(&bytes).as_slice(), &bytes[..]);
+    }
+
+    #[test]
+    fn test_payload_oversize_rejected() {
+        let bytes = [0u8; OFFLOAD_PAYLOAD_SIZE + 1];
+        assert_eq!(Payload::from_slice(&bytes), None);
+    }
+
+    #[test]
+    fn test_payload_equality_ignores_unused_bytes() {
+        let long = payload(b"abcd");
+        let mut short = payload(b"ab");
+        assert_ne!(long, short);
+        short = payload(b"abcd");
+        assert_eq!(long, short);
+    }
+
+    // ==================== Worker Tests ====================
+
+    #[test]
+    fn test_run_crc16() {
+        let job = Job::Crc16(payload(b"123456789"));
+        assert_eq!(run(&job), JobResult::Crc16(0x29B1));
+    }
+
+    #[test]
+    fn test_run_base64() {
+        let job = Job::Base64(payload(b"aGVsbG8="));
+        assert_eq!(run(&job), JobResult::Bytes(payload(b"hello")));
+    }
+
+    #[test]
+    fn test_run_base64_invalid_fails() {
+        let job = Job::Base64(payload(b"a$=="));
+        assert_eq!(run(&job), JobResult::Failed);
+    }
+
+    #[test]
+    fn test_run_rle_expand() {
+        let job = Job::RleExpand(payload(b"\x03A\x02B"));
+        assert_eq!(run(&job), JobResult::Bytes(payload(b"AAABB")));
+    }
+
+    #[test]
+    fn test_run_rle_trailing_count_ignored() {
+        let job = Job::RleExpand(payload(b"\x02C\x05"));
+        assert_eq!(run(&job), JobResult::Bytes(payload(b"CC")));
+    }
+
+    #[test]
+    fn test_run_rle_overflow_fails() {
+        let job = Job::RleExpand(payload(b"\xFFz"));
+        assert_eq!(run(&job), JobResult::Failed);
+    }
+
+    #[test]
+    fn test_run_is_stateless() {
+        let job = Job::Crc16(payload(b"A"));
+        assert_eq!(run(&job), run(&job));
+    }
+}
@@ -0,0 +1,97 @@
+/*
+ * @file panic.rs
+ * @brief Panic message formatting
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: panic.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Panic Message Formatting.
+//!
+//! BRIEF:
+//! Builds the PANIC report written to the UART before halting.
+//! Kept pure so the format can be tested on the host.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+use crate::fmtbuf::FmtBuf;
+use core::fmt::Write;
+
+/// Formats a panic report as `PANIC at file:line` on its own line.
+///
+/// # Details
+/// Starts with CRLF so the report is not appended to a partial line.
+/// If the location does not fit, `PANIC` is reported without it.
+///
+/// # Arguments
+/// * `file` - Source file of the panic
+/// * `line` - Source line of the panic
+/// * `out` - Destination buffer
+///
+/// # Returns
+/// * `usize` - Number of bytes written to `out`
+#[allow(dead_code)]
+pub fn panic_message(file: &str, line: u32, out: &mut [u8]) -> usize {
+    let mut text = FmtBuf::new(out);
+    if write!(text, "\r\nPANIC at {}:{}\r\n", file, line).is_ok() {
+        return text.len();
+    }
+    let mut text = FmtBuf::new(out);
+    match text.write_str("\r\nPANIC\r\n") {
+        Ok(()) => text.len(),
+        Err(_) => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Panic Message Tests ====================
+
+    #[test]
+    fn test_panic_message_with_location() {
+        let mut out = [0u8; 64];
+        let n = panic_message("src/main.rs", 42, &mut out);
+        assert_eq!(&out[..n], b"\r\nPANIC at src/main.rs:42\r\n");
+    }
+
+    #[test]
+    fn test_panic_message_long_file_keeps_panic() {
+        let mut out = [0u8; 16];
+        let n = panic_message("src/a/very/long/path.rs", 7, &mut out);
+        assert_eq!(&out[..n], b"\r\nPANIC\r\n");
+    }
+
+    #[test]
+    fn test_panic_message_tiny_buffer() {
+        let mut out = [0u8; 4];
+        assert_eq!(panic_message("x.rs", 1, &mut out), 0);
+    }
+}
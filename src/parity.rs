@@ -0,0 +1,133 @@
+/*
+ * @file parity.rs
+ * @brief Software parity checking
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: parity.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Software Parity Check.
+//!
+//! BRIEF:
+//! Verifies bit 7 as the parity bit of 7-bit data.
+//! For links whose hardware does not check parity itself.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Parity carried in bit 7 of each received byte.
+///
+/// # Variants
+/// * `None` - No parity; bytes pass through unchanged
+/// * `Even` - The byte has an even number of set bits
+/// * `Odd` - The byte has an odd number of set bits
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum ParityMode {
+    None,
+    Even,
+    Odd,
+}
+
+impl ParityMode {
+    /// Checks a received byte's parity bit.
+    ///
+    /// # Arguments
+    /// * `byte` - Seven data bits with the parity bit in bit 7
+    ///
+    /// # Returns
+    /// * `bool` - True if the parity matches, always true for None
+    #[allow(dead_code)]
+    pub fn check(self, byte: u8) -> bool {
+        match self {
+            ParityMode::None => true,
+            ParityMode::Even => byte.count_ones().is_multiple_of(2),
+            ParityMode::Odd => !byte.count_ones().is_multiple_of(2),
+        }
+    }
+
+    /// Removes the parity bit from a received byte.
+    ///
+    /// # Arguments
+    /// * `byte` - Received byte
+    ///
+    /// # Returns
+    /// * `u8` - The low seven bits, or the byte unchanged for None
+    #[allow(dead_code)]
+    pub fn strip(self, byte: u8) -> u8 {
+        match self {
+            ParityMode::None => byte,
+            ParityMode::Even | ParityMode::Odd => byte & 0x7F,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Check Tests ====================
+
+    #[test]
+    fn test_none_accepts_everything() {
+        assert!((0..=255).all(|b| ParityMode::None.check(b)));
+    }
+
+    #[test]
+    fn test_even_parity() {
+        assert!(ParityMode::Even.check(b'A'));
+        assert!(ParityMode::Even.check(b'C' | 0x80));
+        assert!(!ParityMode::Even.check(b'C'));
+        assert!(!ParityMode::Even.check(b'A' | 0x80));
+    }
+
+    #[test]
+    fn test_odd_parity() {
+        assert!(ParityMode::Odd.check(b'C'));
+        assert!(ParityMode::Odd.check(b'A' | 0x80));
+        assert!(!ParityMode::Odd.check(b'A'));
+    }
+
+    #[test]
+    fn test_even_and_odd_disagree() {
+        assert!((0..=255).all(|b| ParityMode::Even.check(b) != ParityMode::Odd.check(b)));
+    }
+
+    // ==================== Strip Tests ====================
+
+    #[test]
+    fn test_strip_clears_bit_7() {
+        assert_eq!(ParityMode::Even.strip(b'C' | 0x80), b'C');
+        assert_eq!(ParityMode::Odd.strip(b'A' | 0x80), b'A');
+    }
+
+    #[test]
+    fn test_strip_none_unchanged() {
+        assert_eq!(ParityMode::None.strip(0xC1), 0xC1);
+    }
+}
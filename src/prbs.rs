@@ -0,0 +1,156 @@
+/*
+ * @file prbs.rs
+ * @brief PRBS-7 test pattern generator
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: prbs.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART PRBS-7 Test Pattern.
+//!
+//! BRIEF:
+//! Generates the ITU-T O.150 PRBS-7 sequence for bit-error-rate tests.
+//! Output is deterministic so the host can regenerate and compare it.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Seed loaded into the PRBS-7 register.
+///
+/// # Value
+/// 0x7F (all ones)
+#[allow(dead_code)]
+pub const PRBS7_SEED: u8 = 0x7F;
+
+/// PRBS-7 generator for the polynomial x^7 + x^6 + 1.
+///
+/// # Details
+/// A 7-bit Fibonacci LFSR. Each step XORs register bits 6 and 5,
+/// shifts the result in at bit 0, and outputs it. Bytes are packed
+/// MSB first, so the all-ones seed starts 0x02 0x0C 0x28 0xF2. The
+/// sequence repeats every 127 bits.
+///
+/// # Fields
+/// * `state` - Seven register bits, never zero
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct Prbs7 {
+    state: u8,
+}
+
+impl Default for Prbs7 {
+    /// Returns default Prbs7 instance.
+    ///
+    /// # Details
+    /// Delegates to new() for initialization.
+    ///
+    /// # Returns
+    /// * `Self` - New Prbs7 loaded with PRBS7_SEED
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Prbs7 {
+    /// Creates a generator loaded with PRBS7_SEED.
+    ///
+    /// # Returns
+    /// * `Self` - New Prbs7
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self { state: PRBS7_SEED }
+    }
+
+    /// Produces the next bit of the sequence.
+    ///
+    /// # Returns
+    /// * `u8` - 0 or 1
+    #[allow(dead_code)]
+    pub fn next_bit(&mut self) -> u8 {
+        let bit = ((self.state >> 6) ^ (self.state >> 5)) & 1;
+        self.state = ((self.state << 1) | bit) & 0x7F;
+        bit
+    }
+
+    /// Produces the next eight bits packed MSB first.
+    ///
+    /// # Returns
+    /// * `u8` - Next pattern byte
+    #[allow(dead_code)]
+    pub fn next_byte(&mut self) -> u8 {
+        (0..8).fold(0, |byte, _| (byte << 1) | self.next_bit())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Sequence Tests ====================
+
+    #[test]
+    fn test_first_bits_match_prbs7() {
+        let mut prbs = Prbs7::new();
+        let bits: Vec<u8> = (0..16).map(|_| prbs.next_bit()).collect();
+        assert_eq!(bits, [0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0]);
+    }
+
+    #[test]
+    fn test_first_bytes_match_prbs7() {
+        let mut prbs = Prbs7::new();
+        let bytes: Vec<u8> = (0..16).map(|_| prbs.next_byte()).collect();
+        assert_eq!(
+            bytes,
+            [
+                0x02, 0x0C, 0x28, 0xF2, 0x2C, 0xEA, 0x7D, 0x0E, 0x24, 0xDA, 0xDE, 0xC6, 0x97, 0x73,
+                0x2A, 0xFE
+            ]
+        );
+    }
+
+    #[test]
+    fn test_period_is_127_bits() {
+        let mut prbs = Prbs7::new();
+        let first: Vec<u8> = (0..127).map(|_| prbs.next_bit()).collect();
+        let second: Vec<u8> = (0..127).map(|_| prbs.next_bit()).collect();
+        assert_eq!(first, second);
+        assert_eq!(prbs, Prbs7::new());
+    }
+
+    #[test]
+    fn test_period_balance() {
+        let mut prbs = Prbs7::new();
+        let ones: u32 = (0..127).map(|_| u32::from(prbs.next_bit())).sum();
+        assert_eq!(ones, 64);
+    }
+
+    #[test]
+    fn test_default_matches_new() {
+        assert_eq!(Prbs7::default(), Prbs7::new());
+    }
+}
@@ -0,0 +1,208 @@
+/*
+ * @file prompt.rs
+ * @brief Command prompt builder
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: prompt.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Command Prompt.
+//!
+//! BRIEF:
+//! Assembles the command-mode prompt from configurable parts.
+//! Dynamic parts such as the echo count are formatted on demand.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+use crate::config::PROMPT;
+use crate::fmtbuf::FmtBuf;
+use core::fmt::Write;
+
+/// Set of parts shown in the command prompt.
+///
+/// # Details
+/// Bitflags-style mask combined with `|`. Parts appear in a fixed
+/// order: name, echo count, mode, then PROMPT.
+///
+/// # Fields
+/// * `0` - Raw part bits
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct PromptParts(u8);
+
+impl PromptParts {
+    /// Shows only PROMPT.
+    #[allow(dead_code)]
+    pub const NONE: Self = Self(0);
+
+    /// Device name.
+    #[allow(dead_code)]
+    pub const NAME: Self = Self(1 << 0);
+
+    /// Characters echoed so far, as `[N]`.
+    #[allow(dead_code)]
+    pub const ECHO_COUNT: Self = Self(1 << 1);
+
+    /// Controller mode, as `(mode)`.
+    #[allow(dead_code)]
+    pub const MODE: Self = Self(1 << 2);
+
+    /// Returns whether every part in `other` is in this set.
+    ///
+    /// # Arguments
+    /// * `other` - Parts to test
+    ///
+    /// # Returns
+    /// * `bool` - True if all parts in `other` are set
+    #[allow(dead_code)]
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Adds or removes parts.
+    ///
+    /// # Arguments
+    /// * `other` - Parts to change
+    /// * `enabled` - True to add, false to remove
+    #[allow(dead_code)]
+    pub fn set(&mut self, other: Self, enabled: bool) {
+        if enabled {
+            self.0 |= other.0;
+        } else {
+            self.0 &= !other.0;
+        }
+    }
+}
+
+impl core::ops::BitOr for PromptParts {
+    type Output = Self;
+
+    /// Combines two part sets.
+    ///
+    /// # Arguments
+    /// * `rhs` - Set to combine with
+    ///
+    /// # Returns
+    /// * `Self` - Union of both sets
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// Builds a prompt such as `dev[1234](command)> `.
+///
+/// # Details
+/// Only the parts in `parts` are written; PROMPT always ends the text.
+///
+/// # Arguments
+/// * `parts` - Parts to include
+/// * `name` - Device name
+/// * `echo_count` - Characters echoed so far
+/// * `mode` - Mode label
+/// * `out` - Destination buffer
+///
+/// # Returns
+/// * `usize` - Bytes written to `out`, 0 if it does not fit
+#[allow(dead_code)]
+pub fn build_prompt(
+    parts: PromptParts,
+    name: &[u8],
+    echo_count: u64,
+    mode: &[u8],
+    out: &mut [u8],
+) -> usize {
+    let mut text = FmtBuf::new(out);
+    let mut result = Ok(());
+    if parts.contains(PromptParts::NAME) {
+        result = result.and(write!(text, "{}", name.escape_ascii()));
+    }
+    if parts.contains(PromptParts::ECHO_COUNT) {
+        result = result.and(write!(text, "[{}]", echo_count));
+    }
+    if parts.contains(PromptParts::MODE) {
+        result = result.and(write!(text, "({})", mode.escape_ascii()));
+    }
+    result = result.and(write!(text, "{}", PROMPT.escape_ascii()));
+    match result {
+        Ok(()) => text.len(),
+        Err(_) => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(parts: PromptParts, count: u64) -> Vec<u8> {
+        let mut out = [0u8; 64];
+        let n = build_prompt(parts, b"dev", count, b"echo", &mut out);
+        out[..n].to_vec()
+    }
+
+    // ==================== Build Tests ====================
+
+    #[test]
+    fn test_no_parts_is_plain_prompt() {
+        assert_eq!(build(PromptParts::NONE, 5), PROMPT);
+    }
+
+    #[test]
+    fn test_name_only() {
+        assert_eq!(build(PromptParts::NAME, 5), b"dev> ");
+    }
+
+    #[test]
+    fn test_echo_count() {
+        assert_eq!(build(PromptParts::ECHO_COUNT, 1234), b"[1234]> ");
+    }
+
+    #[test]
+    fn test_all_parts_in_order() {
+        let parts = PromptParts::MODE | PromptParts::NAME | PromptParts::ECHO_COUNT;
+        assert_eq!(build(parts, 7), b"dev[7](echo)> ");
+    }
+
+    #[test]
+    fn test_too_small_buffer() {
+        let mut out = [0u8; 4];
+        let n = build_prompt(PromptParts::ECHO_COUNT, b"", 1234, b"", &mut out);
+        assert_eq!(n, 0);
+    }
+
+    // ==================== PromptParts Tests ====================
+
+    #[test]
+    fn test_set_adds_and_removes() {
+        let mut parts = PromptParts::NONE;
+        parts.set(PromptParts::MODE, true);
+        assert!(parts.contains(PromptParts::MODE));
+        parts.set(PromptParts::MODE, false);
+        assert_eq!(parts, PromptParts::NONE);
+    }
+}
@@ -0,0 +1,155 @@
+/*
+ * @file rate.rs
+ * @brief Byte rate meter
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: rate.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Byte Rate Meter.
+//!
+//! BRIEF:
+//! Measures throughput in bytes per second over a sliding window.
+//! Timestamps come from the main loop; the windowing is pure.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Sliding-window byte counter bucketed by time.
+///
+/// # Details
+/// Time is split into `bucket_ms` slots and the last `N` slots form
+/// the window. Each slot remembers which bucket it counts, so slots
+/// left over from an older pass of the ring are ignored.
+///
+/// # Fields
+/// * `counts` - Bytes recorded per slot
+/// * `buckets` - Bucket number each slot currently counts
+/// * `bucket_ms` - Width of one bucket
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct RateMeter<const N: usize> {
+    counts: [u32; N],
+    buckets: [u64; N],
+    bucket_ms: u64,
+}
+
+impl<const N: usize> RateMeter<N> {
+    /// Creates an empty rate meter.
+    ///
+    /// # Arguments
+    /// * `bucket_ms` - Width of one bucket, at least 1
+    ///
+    /// # Returns
+    /// * `Self` - New RateMeter spanning `N * bucket_ms`
+    #[allow(dead_code)]
+    pub fn new(bucket_ms: u64) -> Self {
+        Self {
+            counts: [0; N],
+            buckets: [0; N],
+            bucket_ms: bucket_ms.max(1),
+        }
+    }
+
+    /// Records bytes received at a point in time.
+    ///
+    /// # Arguments
+    /// * `now_ms` - Arrival time
+    /// * `count` - Number of bytes
+    #[allow(dead_code)]
+    pub fn record(&mut self, now_ms: u64, count: u32) {
+        let bucket = now_ms / self.bucket_ms;
+        let slot = (bucket % N as u64) as usize;
+        if self.buckets[slot] != bucket {
+            self.buckets[slot] = bucket;
+            self.counts[slot] = 0;
+        }
+        self.counts[slot] = self.counts[slot].saturating_add(count);
+    }
+
+    /// Returns the average throughput over the window ending now.
+    ///
+    /// # Arguments
+    /// * `now_ms` - Current time
+    ///
+    /// # Returns
+    /// * `u32` - Bytes per second, saturating
+    #[allow(dead_code)]
+    pub fn current_rate(&self, now_ms: u64) -> u32 {
+        let bucket = now_ms / self.bucket_ms;
+        let total: u64 = self
+            .buckets
+            .iter()
+            .zip(&self.counts)
+            .filter(|(&b, _)| b <= bucket && bucket - b < N as u64)
+            .map(|(_, &c)| u64::from(c))
+            .sum();
+        let window_ms = N as u64 * self.bucket_ms;
+        u32::try_from(total * 1000 / window_ms).unwrap_or(u32::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Rate Meter Tests ====================
+
+    #[test]
+    fn test_empty_rate_is_zero() {
+        let meter: RateMeter<10> = RateMeter::new(100);
+        assert_eq!(meter.current_rate(5000), 0);
+    }
+
+    #[test]
+    fn test_steady_input_rate() {
+        let mut meter: RateMeter<10> = RateMeter::new(100);
+        for t in 0..20 {
+            meter.record(t * 100 + 50, 50);
+        }
+        assert_eq!(meter.current_rate(1999), 500);
+    }
+
+    #[test]
+    fn test_window_forgets_old_buckets() {
+        let mut meter: RateMeter<10> = RateMeter::new(100);
+        meter.record(0, 1000);
+        assert_eq!(meter.current_rate(900), 1000);
+        assert_eq!(meter.current_rate(1000), 0);
+        meter.record(1050, 10);
+        assert_eq!(meter.current_rate(1050), 10);
+    }
+
+    #[test]
+    fn test_stale_slot_reused() {
+        let mut meter: RateMeter<4> = RateMeter::new(250);
+        meter.record(100, 40);
+        meter.record(1100, 8);
+        assert_eq!(meter.current_rate(1100), 8);
+    }
+}
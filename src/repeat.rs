@@ -0,0 +1,106 @@
+/*
+ * @file repeat.rs
+ * @brief Key repeat filtering
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: repeat.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Key Repeat Filter.
+//!
+//! BRIEF:
+//! Limits held keys to one character per repeat interval.
+//! Unlike debouncing, timing is measured from the last accepted press.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Decides whether a repeated byte should be accepted.
+///
+/// # Details
+/// The terminal's auto-repeat sends the same byte rapidly while a key
+/// is held. Measuring from the last accepted copy, rather than the
+/// last received one, caps a held key at one byte per `interval_ms`.
+/// A zero interval disables the filter.
+///
+/// # Arguments
+/// * `last_ms` - Time the same byte was last accepted
+/// * `now_ms` - Arrival time of this byte
+/// * `interval_ms` - Minimum spacing between accepted copies
+///
+/// # Returns
+/// * `bool` - True if the byte should be accepted
+#[allow(dead_code)]
+pub fn accept_repeat(last_ms: u64, now_ms: u64, interval_ms: u64) -> bool {
+    now_ms.saturating_sub(last_ms) >= interval_ms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Repeat Tests ====================
+
+    #[test]
+    fn test_rapid_repeat_filtered() {
+        assert!(!accept_repeat(1000, 1030, 200));
+    }
+
+    #[test]
+    fn test_spaced_press_accepted() {
+        assert!(accept_repeat(1000, 1500, 200));
+    }
+
+    #[test]
+    fn test_exact_interval_accepted() {
+        assert!(accept_repeat(1000, 1200, 200));
+    }
+
+    #[test]
+    fn test_held_key_one_per_interval() {
+        let mut last = 0;
+        let mut accepted = 0;
+        for now in (1000..2000).step_by(30) {
+            if accept_repeat(last, now, 200) {
+                last = now;
+                accepted += 1;
+            }
+        }
+        assert_eq!(accepted, 5);
+    }
+
+    #[test]
+    fn test_zero_interval_disables() {
+        assert!(accept_repeat(1000, 1000, 0));
+    }
+
+    #[test]
+    fn test_clock_behind_last_filtered() {
+        assert!(!accept_repeat(1000, 900, 200));
+    }
+}
@@ -0,0 +1,181 @@
+/*
+ * @file rle.rs
+ * @brief Run-length decoding
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: rle.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Run-Length Decoding.
+//!
+//! BRIEF:
+//! Expands count/value byte pairs from a compressed uplink.
+//! Runs drain in chunks so any size output buffer works.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Streaming run-length decoder.
+///
+/// # Details
+/// Input is a sequence of count byte, value byte pairs. Once a pair is
+/// complete the run is drained into the caller's buffer, one chunk per
+/// drain() call, until empty. A zero count expands to nothing.
+///
+/// # Fields
+/// * `count` - Count byte waiting for its value, if any
+/// * `value` - Byte value of the current run
+/// * `remaining` - Bytes of the current run not yet drained
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct RleDecoder {
+    count: Option<u8>,
+    value: u8,
+    remaining: u8,
+}
+
+impl RleDecoder {
+    /// Creates a decoder expecting a count byte.
+    ///
+    /// # Returns
+    /// * `Self` - New RleDecoder
+    #[allow(dead_code)]
+    pub const fn new() -> Self {
+        Self {
+            count: None,
+            value: 0,
+            remaining: 0,
+        }
+    }
+
+    /// Feeds one received byte to the decoder.
+    ///
+    /// # Details
+    /// Starting a new pair discards any undrained part of the previous
+    /// run.
+    ///
+    /// # Arguments
+    /// * `ch` - The byte received
+    ///
+    /// # Returns
+    /// * `bool` - True if a run is ready to drain
+    #[allow(dead_code)]
+    pub fn feed(&mut self, ch: u8) -> bool {
+        match self.count.take() {
+            None => {
+                self.count = Some(ch);
+                self.remaining = 0;
+                false
+            }
+            Some(count) => {
+                self.value = ch;
+                self.remaining = count;
+                count > 0
+            }
+        }
+    }
+
+    /// Copies the next chunk of the current run into a buffer.
+    ///
+    /// # Arguments
+    /// * `out` - Destination buffer
+    ///
+    /// # Returns
+    /// * `usize` - Bytes written; 0 once the run is exhausted
+    #[allow(dead_code)]
+    pub fn drain(&mut self, out: &mut [u8]) -> usize {
+        let len = out.len().min(usize::from(self.remaining));
+        out[..len].fill(self.value);
+        self.remaining -= len as u8;
+        len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(input: &[u8], chunk: usize) -> Vec<u8> {
+        let mut decoder = RleDecoder::new();
+        let mut buf = vec![0u8; chunk];
+        let mut out = Vec::new();
+        for &b in input {
+            if decoder.feed(b) {
+                loop {
+                    let len = decoder.drain(&mut buf);
+                    if len == 0 {
+                        break;
+                    }
+                    out.extend_from_slice(&buf[..len]);
+                }
+            }
+        }
+        out
+    }
+
+    // ==================== Decoder Tests ====================
+
+    #[test]
+    fn test_decodes_runs() {
+        assert_eq!(decode(b"\x03A\x02B", 16), b"AAABB");
+    }
+
+    #[test]
+    fn test_zero_count_expands_to_nothing() {
+        assert_eq!(decode(b"\x00A\x01B", 16), b"B");
+        let mut decoder = RleDecoder::new();
+        decoder.feed(0);
+        assert!(!decoder.feed(b'A'));
+        assert_eq!(decoder.drain(&mut [0u8; 4]), 0);
+    }
+
+    #[test]
+    fn test_long_run_drains_in_chunks() {
+        let mut decoder = RleDecoder::new();
+        let mut buf = [0u8; 4];
+        decoder.feed(10);
+        assert!(decoder.feed(b'x'));
+        assert_eq!(decoder.drain(&mut buf), 4);
+        assert_eq!(decoder.drain(&mut buf), 4);
+        assert_eq!(decoder.drain(&mut buf), 2);
+        assert_eq!(&buf[..2], b"xx");
+        assert_eq!(decoder.drain(&mut buf), 0);
+    }
+
+    #[test]
+    fn test_max_count_run() {
+        assert_eq!(decode(b"\xFFz", 7), vec![b'z'; 255]);
+    }
+
+    #[test]
+    fn test_count_alone_is_pending() {
+        let mut decoder = RleDecoder::new();
+        assert!(!decoder.feed(3));
+        assert_eq!(decoder.drain(&mut [0u8; 4]), 0);
+    }
+}
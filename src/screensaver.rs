@@ -0,0 +1,198 @@
+/*
+ * @file screensaver.rs
+ * @brief Idle screensaver animation
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: screensaver.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Idle Screensaver.
+//!
+//! BRIEF:
+//! Generates frames of a character bouncing across one terminal line.
+//! Timing comes from the main loop; frame generation is pure.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+use crate::config::SCREENSAVER_CHAR;
+
+/// Returns the screensaver frame index due after some idle time.
+///
+/// # Details
+/// Checked on each idle tick. The first frame is due once `delay_ms`
+/// of silence has passed and the index advances every `frame_ms`.
+/// A delay of 0 disables the screensaver.
+///
+/// # Arguments
+/// * `idle_ms` - Time since the last received key
+/// * `delay_ms` - Idle time before the animation starts
+/// * `frame_ms` - Time between frames
+///
+/// # Returns
+/// * `Option<u64>` - Frame index, or None while the screensaver is off
+#[allow(dead_code)]
+pub fn screensaver_tick(idle_ms: u64, delay_ms: u64, frame_ms: u64) -> Option<u64> {
+    if delay_ms == 0 || idle_ms < delay_ms {
+        return None;
+    }
+    Some((idle_ms - delay_ms) / frame_ms.max(1))
+}
+
+/// Renders one animation frame.
+///
+/// # Details
+/// Writes CR, then `width` columns with SCREENSAVER_CHAR at a position
+/// that bounces between the first and last column, so each frame
+/// overwrites the previous one in place.
+///
+/// # Arguments
+/// * `tick` - Frame index
+/// * `width` - Number of columns to animate across
+/// * `out` - Destination buffer
+///
+/// # Returns
+/// * `usize` - Bytes written, or 0 if width is 0 or `out` is too small
+#[allow(dead_code)]
+pub fn screensaver_frame(tick: u64, width: usize, out: &mut [u8]) -> usize {
+    if width == 0 || out.len() < width + 1 {
+        return 0;
+    }
+    let period = 2 * (width as u64 - 1);
+    let phase = tick.checked_rem(period).unwrap_or(0);
+    let pos = phase.min(period - phase) as usize;
+    out[0] = b'\r';
+    out[1..=width].fill(b' ');
+    out[1 + pos] = SCREENSAVER_CHAR;
+    width + 1
+}
+
+/// Renders the sequence that erases the animation line.
+///
+/// # Arguments
+/// * `width` - Number of columns the animation used
+/// * `out` - Destination buffer
+///
+/// # Returns
+/// * `usize` - Bytes written, or 0 if `out` is too small
+#[allow(dead_code)]
+pub fn screensaver_clear(width: usize, out: &mut [u8]) -> usize {
+    if out.len() < width + 2 {
+        return 0;
+    }
+    out[0] = b'\r';
+    out[1..=width].fill(b' ');
+    out[width + 1] = b'\r';
+    width + 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(tick: u64, width: usize) -> usize {
+        let mut out = [0u8; 16];
+        let n = screensaver_frame(tick, width, &mut out);
+        out[1..n]
+            .iter()
+            .position(|&b| b == SCREENSAVER_CHAR)
+            .unwrap()
+    }
+
+    // ==================== Tick Tests ====================
+
+    #[test]
+    fn test_tick_disabled_by_zero_delay() {
+        assert_eq!(screensaver_tick(60_000, 0, 100), None);
+    }
+
+    #[test]
+    fn test_tick_none_before_delay() {
+        assert_eq!(screensaver_tick(999, 1000, 100), None);
+    }
+
+    #[test]
+    fn test_tick_advances_per_frame() {
+        assert_eq!(screensaver_tick(1000, 1000, 100), Some(0));
+        assert_eq!(screensaver_tick(1099, 1000, 100), Some(0));
+        assert_eq!(screensaver_tick(1250, 1000, 100), Some(2));
+    }
+
+    // ==================== Frame Tests ====================
+
+    #[test]
+    fn test_frame_layout() {
+        let mut out = [0u8; 8];
+        assert_eq!(screensaver_frame(1, 4, &mut out), 5);
+        assert_eq!(out[0], b'\r');
+        assert_eq!(out[2], SCREENSAVER_CHAR);
+        assert_eq!(out[1], b' ');
+    }
+
+    #[test]
+    fn test_consecutive_ticks_move_character() {
+        for tick in 0..20 {
+            assert_ne!(position(tick, 5), position(tick + 1, 5));
+        }
+    }
+
+    #[test]
+    fn test_frame_bounces_at_edges() {
+        let path: Vec<usize> = (0..9).map(|t| position(t, 4)).collect();
+        assert_eq!(path, [0, 1, 2, 3, 2, 1, 0, 1, 2]);
+    }
+
+    #[test]
+    fn test_frame_stays_within_width() {
+        let mut out = [0u8; 16];
+        for width in 1..=8 {
+            for tick in 0..40 {
+                let n = screensaver_frame(tick, width, &mut out);
+                assert_eq!(n, width + 1);
+                assert!(position(tick, width) < width);
+            }
+        }
+    }
+
+    #[test]
+    fn test_frame_rejects_zero_width_and_small_buffer() {
+        let mut out = [0u8; 4];
+        assert_eq!(screensaver_frame(0, 0, &mut out), 0);
+        assert_eq!(screensaver_frame(0, 4, &mut out), 0);
+    }
+
+    // ==================== Clear Tests ====================
+
+    #[test]
+    fn test_clear_blanks_line() {
+        let mut out = [0u8; 8];
+        assert_eq!(screensaver_clear(3, &mut out), 5);
+        assert_eq!(&out[..5], b"\r   \r");
+        assert_eq!(screensaver_clear(7, &mut out), 0);
+    }
+}
@@ -0,0 +1,188 @@
+/*
+ * @file script.rs
+ * @brief Canned input script player
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: script.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Demo Script Player.
+//!
+//! BRIEF:
+//! Replays an embedded input script at a fixed pace when nobody is typing.
+//! Live input preempts the script permanently.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Paced player for an embedded input script.
+///
+/// # Details
+/// Yields one script byte per interval until the script ends or a live
+/// byte arrives, after which only live input is passed through.
+///
+/// # Fields
+/// * `script` - Bytes to replay as if typed
+/// * `pos` - Index of the next script byte
+/// * `interval_ms` - Minimum time between script bytes
+/// * `last_ms` - Time the previous script byte was played
+/// * `preempted` - True once live input has taken over
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct ScriptPlayer {
+    script: &'static [u8],
+    pos: usize,
+    interval_ms: u64,
+    last_ms: u64,
+    preempted: bool,
+}
+
+impl ScriptPlayer {
+    /// Creates a player positioned at the start of a script.
+    ///
+    /// # Arguments
+    /// * `script` - Bytes to replay, empty to disable playback
+    /// * `interval_ms` - Minimum time between script bytes
+    ///
+    /// # Returns
+    /// * `Self` - New ScriptPlayer
+    #[allow(dead_code)]
+    pub fn new(script: &'static [u8], interval_ms: u64) -> Self {
+        Self {
+            script,
+            pos: 0,
+            interval_ms,
+            last_ms: 0,
+            preempted: false,
+        }
+    }
+
+    /// Returns whether the player will produce no more bytes.
+    ///
+    /// # Returns
+    /// * `bool` - True if preempted or the script is exhausted
+    #[allow(dead_code)]
+    pub fn is_done(&self) -> bool {
+        self.preempted || self.pos >= self.script.len()
+    }
+
+    /// Stops playback in favour of live input.
+    #[allow(dead_code)]
+    pub fn preempt(&mut self) {
+        self.preempted = true;
+    }
+
+    /// Returns the next script byte if its interval has elapsed.
+    ///
+    /// # Arguments
+    /// * `now_ms` - Current time in milliseconds
+    ///
+    /// # Returns
+    /// * `Option<u8>` - Script byte to process, or None
+    #[allow(dead_code)]
+    pub fn next(&mut self, now_ms: u64) -> Option<u8> {
+        if self.is_done() || now_ms.saturating_sub(self.last_ms) < self.interval_ms {
+            return None;
+        }
+        let ch = self.script[self.pos];
+        self.pos += 1;
+        self.last_ms = now_ms;
+        Some(ch)
+    }
+
+    /// Merges live input with script playback.
+    ///
+    /// # Details
+    /// A live byte always wins and ends playback; otherwise the next
+    /// script byte is returned when due.
+    ///
+    /// # Arguments
+    /// * `live` - Byte received from the UART, if any
+    /// * `now_ms` - Current time in milliseconds
+    ///
+    /// # Returns
+    /// * `Option<u8>` - Byte to process, or None
+    #[allow(dead_code)]
+    pub fn select(&mut self, live: Option<u8>, now_ms: u64) -> Option<u8> {
+        match live {
+            Some(ch) => {
+                self.preempt();
+                Some(ch)
+            }
+            None => self.next(now_ms),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Playback Tests ====================
+
+    #[test]
+    fn test_waits_for_interval() {
+        let mut player = ScriptPlayer::new(b"ab", 100);
+        assert_eq!(player.next(50), None);
+        assert_eq!(player.next(100), Some(b'a'));
+    }
+
+    #[test]
+    fn test_advances_through_script() {
+        let mut player = ScriptPlayer::new(b"ab", 100);
+        assert_eq!(player.next(100), Some(b'a'));
+        assert_eq!(player.next(150), None);
+        assert_eq!(player.next(200), Some(b'b'));
+        assert_eq!(player.next(300), None);
+        assert!(player.is_done());
+    }
+
+    #[test]
+    fn test_empty_script_done() {
+        let mut player = ScriptPlayer::new(b"", 10);
+        assert!(player.is_done());
+        assert_eq!(player.next(1000), None);
+    }
+
+    // ==================== Live Input Tests ====================
+
+    #[test]
+    fn test_live_input_preempts_script() {
+        let mut player = ScriptPlayer::new(b"abc", 100);
+        assert_eq!(player.select(None, 100), Some(b'a'));
+        assert_eq!(player.select(Some(b'x'), 200), Some(b'x'));
+        assert_eq!(player.select(None, 300), None);
+        assert!(player.is_done());
+    }
+
+    #[test]
+    fn test_live_input_wins_when_script_due() {
+        let mut player = ScriptPlayer::new(b"abc", 100);
+        assert_eq!(player.select(Some(b'z'), 1000), Some(b'z'));
+    }
+}
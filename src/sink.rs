@@ -0,0 +1,167 @@
+/*
+ * @file sink.rs
+ * @brief Output fan-out
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: sink.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Output Fan-Out.
+//!
+//! BRIEF:
+//! Byte sinks and the fan-out that copies echo output to each of them.
+//! Used to tee UART0 output to a read-only UART1 tap.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+use crate::error::UartError;
+use crate::txqueue::TxQueue;
+
+/// Destination for outgoing bytes.
+#[allow(dead_code)]
+pub trait ByteSink {
+    /// Accepts bytes for transmission.
+    ///
+    /// # Arguments
+    /// * `bytes` - Bytes to send
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or the reason the bytes were dropped
+    fn write(&mut self, bytes: &[u8]) -> Result<(), UartError>;
+}
+
+impl<const N: usize> ByteSink for TxQueue<N> {
+    /// Queues bytes, dropping the response if the queue is full.
+    ///
+    /// # Arguments
+    /// * `bytes` - Bytes to queue
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or TxQueueFull if dropped
+    fn write(&mut self, bytes: &[u8]) -> Result<(), UartError> {
+        self.push(bytes)
+    }
+}
+
+/// Writes the same bytes to every sink.
+///
+/// # Details
+/// Sinks receive the bytes in slice order, so every output sees an
+/// identical byte sequence. A sink that drops the bytes does not stop
+/// the others from receiving them.
+///
+/// # Arguments
+/// * `bytes` - Bytes to send
+/// * `sinks` - Outputs to fan out to
+///
+/// # Returns
+/// * `Result<(), UartError>` - Ok, or the first sink's failure
+#[allow(dead_code)]
+pub fn fan_out(bytes: &[u8], sinks: &mut [&mut dyn ByteSink]) -> Result<(), UartError> {
+    if bytes.is_empty() {
+        return Ok(());
+    }
+    let mut result = Ok(());
+    for sink in sinks.iter_mut() {
+        let written = sink.write(bytes);
+        if result.is_ok() {
+            result = written;
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sink that records everything written to it.
+    struct MockSink(Vec<u8>);
+
+    impl ByteSink for MockSink {
+        fn write(&mut self, bytes: &[u8]) -> Result<(), UartError> {
+            self.0.extend_from_slice(bytes);
+            Ok(())
+        }
+    }
+
+    // ==================== Fan-Out Tests ====================
+
+    #[test]
+    fn test_fan_out_both_sinks_identical() {
+        let mut primary = MockSink(Vec::new());
+        let mut tee = MockSink(Vec::new());
+        fan_out(b"ab", &mut [&mut primary, &mut tee]).unwrap();
+        fan_out(b"\r\n", &mut [&mut primary, &mut tee]).unwrap();
+        assert_eq!(primary.0, b"ab\r\n");
+        assert_eq!(primary.0, tee.0);
+    }
+
+    #[test]
+    fn test_fan_out_single_sink() {
+        let mut primary = MockSink(Vec::new());
+        fan_out(b"x", &mut [&mut primary]).unwrap();
+        assert_eq!(primary.0, b"x");
+    }
+
+    #[test]
+    fn test_fan_out_no_sinks() {
+        fan_out(b"x", &mut []).unwrap();
+    }
+
+    #[test]
+    fn test_txqueue_sink_queues() {
+        let mut queue: TxQueue<8> = TxQueue::new();
+        let mut tee: TxQueue<8> = TxQueue::new();
+        fan_out(b"hey", &mut [&mut queue, &mut tee]).unwrap();
+        assert_eq!(queue.peek(), b"hey");
+        assert_eq!(tee.peek(), b"hey");
+    }
+
+    #[test]
+    fn test_txqueue_sink_drops_when_full() {
+        let mut queue: TxQueue<2> = TxQueue::new();
+        assert_eq!(
+            fan_out(b"abc", &mut [&mut queue]),
+            Err(UartError::TxQueueFull)
+        );
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_full_sink_does_not_starve_others() {
+        let mut queue: TxQueue<2> = TxQueue::new();
+        let mut tee = MockSink(Vec::new());
+        assert_eq!(
+            fan_out(b"abc", &mut [&mut queue, &mut tee]),
+            Err(UartError::TxQueueFull)
+        );
+        assert_eq!(tee.0, b"abc");
+    }
+}
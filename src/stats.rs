@@ -0,0 +1,151 @@
+/*
+ * @file stats.rs
+ * @brief Link statistics
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: stats.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Link Statistics.
+//!
+//! BRIEF:
+//! Pure helpers that turn raw link counters into reportable figures.
+//! Counters live in the controller; the math lives here.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Computes the RX overrun rate in overruns per 1000 bytes.
+///
+/// # Details
+/// Returns 0 when no bytes have been received. The result saturates
+/// at u32::MAX.
+///
+/// # Arguments
+/// * `overruns` - Number of RX overrun errors
+/// * `total` - Number of bytes received
+///
+/// # Returns
+/// * `u32` - Overruns per mille of received bytes
+#[allow(dead_code)]
+pub fn overrun_rate(overruns: u64, total: u64) -> u32 {
+    if total == 0 {
+        return 0;
+    }
+    let rate = overruns.saturating_mul(1000) / total;
+    u32::try_from(rate).unwrap_or(u32::MAX)
+}
+
+/// Computes an event rate in events per second.
+///
+/// # Details
+/// Used for the main loop iteration rate. Returns 0 when no time has
+/// elapsed. The result saturates at u32::MAX.
+///
+/// # Arguments
+/// * `count` - Events counted over the window
+/// * `elapsed_ms` - Length of the window
+///
+/// # Returns
+/// * `u32` - Events per second
+#[allow(dead_code)]
+pub fn per_second(count: u64, elapsed_ms: u64) -> u32 {
+    if elapsed_ms == 0 {
+        return 0;
+    }
+    let rate = count.saturating_mul(1000) / elapsed_ms;
+    u32::try_from(rate).unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Overrun Rate Tests ====================
+
+    #[test]
+    fn test_overrun_rate_zero_total() {
+        assert_eq!(overrun_rate(0, 0), 0);
+        assert_eq!(overrun_rate(5, 0), 0);
+    }
+
+    #[test]
+    fn test_overrun_rate_no_overruns() {
+        assert_eq!(overrun_rate(0, 1234), 0);
+    }
+
+    #[test]
+    fn test_overrun_rate_per_mille() {
+        assert_eq!(overrun_rate(1, 1000), 1);
+        assert_eq!(overrun_rate(5, 100), 50);
+        assert_eq!(overrun_rate(1, 3), 333);
+    }
+
+    #[test]
+    fn test_overrun_rate_truncates() {
+        assert_eq!(overrun_rate(1, 1001), 0);
+    }
+
+    #[test]
+    fn test_overrun_rate_more_overruns_than_bytes() {
+        assert_eq!(overrun_rate(3, 1), 3000);
+    }
+
+    #[test]
+    fn test_overrun_rate_saturates() {
+        assert_eq!(overrun_rate(u64::MAX, 1), u32::MAX);
+    }
+
+    // ==================== Per Second Tests ====================
+
+    #[test]
+    fn test_per_second_zero_elapsed() {
+        assert_eq!(per_second(500, 0), 0);
+    }
+
+    #[test]
+    fn test_per_second_exact_second() {
+        assert_eq!(per_second(12_345, 1000), 12_345);
+    }
+
+    #[test]
+    fn test_per_second_scales_window() {
+        assert_eq!(per_second(500, 250), 2000);
+        assert_eq!(per_second(3000, 1500), 2000);
+    }
+
+    #[test]
+    fn test_per_second_truncates() {
+        assert_eq!(per_second(1, 1001), 0);
+    }
+
+    #[test]
+    fn test_per_second_saturates() {
+        assert_eq!(per_second(u64::MAX, 1), u32::MAX);
+    }
+}
@@ -0,0 +1,185 @@
+/*
+ * @file status.rs
+ * @brief UART status flags
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: status.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Flag Register Decoder.
+//!
+//! BRIEF:
+//! Decodes UARTFR bits into readable text for the status command.
+//! The register read lives in main.rs; this module is pure.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// UARTFR clear-to-send bit.
+///
+/// # Value
+/// 1 << 0
+#[allow(dead_code)]
+pub const FLAG_CTS: u32 = 1 << 0;
+
+/// UARTFR busy bit, set while the transmitter is sending.
+///
+/// # Value
+/// 1 << 3
+#[allow(dead_code)]
+pub const FLAG_BUSY: u32 = 1 << 3;
+
+/// UARTFR receive FIFO empty bit.
+///
+/// # Value
+/// 1 << 4
+#[allow(dead_code)]
+pub const FLAG_RXFE: u32 = 1 << 4;
+
+/// UARTFR transmit FIFO full bit.
+///
+/// # Value
+/// 1 << 5
+#[allow(dead_code)]
+pub const FLAG_TXFF: u32 = 1 << 5;
+
+/// UARTFR receive FIFO full bit.
+///
+/// # Value
+/// 1 << 6
+#[allow(dead_code)]
+pub const FLAG_RXFF: u32 = 1 << 6;
+
+/// UARTFR transmit FIFO empty bit.
+///
+/// # Value
+/// 1 << 7
+#[allow(dead_code)]
+pub const FLAG_TXFE: u32 = 1 << 7;
+
+/// Flag names and masks in report order.
+const FLAGS: [(&str, u32); 6] = [
+    ("TXFE", FLAG_TXFE),
+    ("TXFF", FLAG_TXFF),
+    ("RXFE", FLAG_RXFE),
+    ("RXFF", FLAG_RXFF),
+    ("BUSY", FLAG_BUSY),
+    ("CTS", FLAG_CTS),
+];
+
+/// Formats UART flag register bits as readable text.
+///
+/// # Details
+/// Writes each flag as `NAME=0` or `NAME=1`, space-separated, e.g.
+/// "TXFE=1 TXFF=0 RXFE=1 RXFF=0 BUSY=0 CTS=0". Stops before a field
+/// that does not fit.
+///
+/// # Arguments
+/// * `flags` - Raw UARTFR register value
+/// * `out` - Destination buffer
+///
+/// # Returns
+/// * `usize` - Number of bytes written
+#[allow(dead_code)]
+pub fn format_uart_flags(flags: u32, out: &mut [u8]) -> usize {
+    let mut len = 0;
+    for (i, (name, mask)) in FLAGS.iter().enumerate() {
+        let sep = usize::from(i > 0);
+        let field = sep + name.len() + 2;
+        if len + field > out.len() {
+            break;
+        }
+        if sep == 1 {
+            out[len] = b' ';
+        }
+        let start = len + sep;
+        out[start..start + name.len()].copy_from_slice(name.as_bytes());
+        out[start + name.len()] = b'=';
+        out[start + name.len() + 1] = if flags & mask != 0 { b'1' } else { b'0' };
+        len += field;
+    }
+    len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(flags: u32) -> String {
+        let mut buf = [0u8; 64];
+        let n = format_uart_flags(flags, &mut buf);
+        String::from_utf8(buf[..n].to_vec()).unwrap()
+    }
+
+    // ==================== Flag Bit Tests ====================
+
+    #[test]
+    fn test_flag_bit_values() {
+        assert_eq!(FLAG_CTS, 0x01);
+        assert_eq!(FLAG_BUSY, 0x08);
+        assert_eq!(FLAG_RXFE, 0x10);
+        assert_eq!(FLAG_TXFF, 0x20);
+        assert_eq!(FLAG_RXFF, 0x40);
+        assert_eq!(FLAG_TXFE, 0x80);
+    }
+
+    // ==================== Format Tests ====================
+
+    #[test]
+    fn test_format_idle_uart() {
+        assert_eq!(
+            format(FLAG_TXFE | FLAG_RXFE),
+            "TXFE=1 TXFF=0 RXFE=1 RXFF=0 BUSY=0 CTS=0"
+        );
+    }
+
+    #[test]
+    fn test_format_busy_full_tx() {
+        assert_eq!(
+            format(FLAG_TXFF | FLAG_BUSY | FLAG_RXFE),
+            "TXFE=0 TXFF=1 RXFE=1 RXFF=0 BUSY=1 CTS=0"
+        );
+    }
+
+    #[test]
+    fn test_format_all_clear() {
+        assert_eq!(format(0), "TXFE=0 TXFF=0 RXFE=0 RXFF=0 BUSY=0 CTS=0");
+    }
+
+    #[test]
+    fn test_format_ignores_unlisted_bits() {
+        assert_eq!(format(0xFFFF_FF00), format(0));
+    }
+
+    #[test]
+    fn test_format_truncates_at_field() {
+        let mut buf = [0u8; 10];
+        let n = format_uart_flags(FLAG_TXFE, &mut buf);
+        assert_eq!(&buf[..n], b"TXFE=1");
+    }
+}
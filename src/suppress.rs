@@ -0,0 +1,185 @@
+/*
+ * @file suppress.rs
+ * @brief Echo suppression mask
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: suppress.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Echo Suppression Mask.
+//!
+//! BRIEF:
+//! Bit mask of character classes whose echo is suppressed.
+//! Suppressed characters are still counted and buffered.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Set of character classes whose echo is suppressed.
+///
+/// # Details
+/// Bitflags-style mask combined with `|`. The empty mask suppresses
+/// nothing.
+///
+/// # Fields
+/// * `0` - Raw class bits
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct SuppressMask(u8);
+
+impl SuppressMask {
+    /// Suppresses nothing.
+    #[allow(dead_code)]
+    pub const NONE: Self = Self(0);
+
+    /// ASCII letters.
+    #[allow(dead_code)]
+    pub const LETTERS: Self = Self(1 << 0);
+
+    /// ASCII digits.
+    #[allow(dead_code)]
+    pub const DIGITS: Self = Self(1 << 1);
+
+    /// Space, tab, CR, LF, and form feed.
+    #[allow(dead_code)]
+    pub const WHITESPACE: Self = Self(1 << 2);
+
+    /// ASCII punctuation.
+    #[allow(dead_code)]
+    pub const PUNCTUATION: Self = Self(1 << 3);
+
+    /// Returns whether every class in `other` is in this mask.
+    ///
+    /// # Arguments
+    /// * `other` - Classes to test
+    ///
+    /// # Returns
+    /// * `bool` - True if all classes in `other` are set
+    #[allow(dead_code)]
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Adds or removes classes.
+    ///
+    /// # Arguments
+    /// * `other` - Classes to change
+    /// * `enabled` - True to add, false to remove
+    #[allow(dead_code)]
+    pub fn set(&mut self, other: Self, enabled: bool) {
+        if enabled {
+            self.0 |= other.0;
+        } else {
+            self.0 &= !other.0;
+        }
+    }
+
+    /// Returns whether a character belongs to a suppressed class.
+    ///
+    /// # Arguments
+    /// * `ch` - Character to test
+    ///
+    /// # Returns
+    /// * `bool` - True if the character's echo is suppressed
+    #[allow(dead_code)]
+    pub fn matches(self, ch: u8) -> bool {
+        let class = if ch.is_ascii_alphabetic() {
+            Self::LETTERS
+        } else if ch.is_ascii_digit() {
+            Self::DIGITS
+        } else if ch.is_ascii_whitespace() {
+            Self::WHITESPACE
+        } else if ch.is_ascii_punctuation() {
+            Self::PUNCTUATION
+        } else {
+            return false;
+        };
+        self.contains(class)
+    }
+}
+
+impl core::ops::BitOr for SuppressMask {
+    type Output = Self;
+
+    /// Combines two masks.
+    ///
+    /// # Arguments
+    /// * `rhs` - Mask to combine with
+    ///
+    /// # Returns
+    /// * `Self` - Union of both masks
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== SuppressMask Tests ====================
+
+    #[test]
+    fn test_none_matches_nothing() {
+        for ch in 0..=255u8 {
+            assert!(!SuppressMask::NONE.matches(ch));
+        }
+    }
+
+    #[test]
+    fn test_digits_mask() {
+        assert!(SuppressMask::DIGITS.matches(b'5'));
+        assert!(!SuppressMask::DIGITS.matches(b'a'));
+    }
+
+    #[test]
+    fn test_classes() {
+        assert!(SuppressMask::LETTERS.matches(b'Z'));
+        assert!(SuppressMask::WHITESPACE.matches(b' '));
+        assert!(SuppressMask::WHITESPACE.matches(b'\r'));
+        assert!(SuppressMask::PUNCTUATION.matches(b'!'));
+        assert!(!SuppressMask::PUNCTUATION.matches(0x08));
+    }
+
+    #[test]
+    fn test_bitor_combines() {
+        let mask = SuppressMask::LETTERS | SuppressMask::DIGITS;
+        assert!(mask.contains(SuppressMask::LETTERS));
+        assert!(mask.contains(SuppressMask::DIGITS));
+        assert!(!mask.contains(SuppressMask::PUNCTUATION));
+    }
+
+    #[test]
+    fn test_set_adds_and_removes() {
+        let mut mask = SuppressMask::NONE;
+        mask.set(SuppressMask::DIGITS, true);
+        assert!(mask.matches(b'1'));
+        mask.set(SuppressMask::DIGITS, false);
+        assert_eq!(mask, SuppressMask::NONE);
+    }
+}
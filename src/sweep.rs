@@ -0,0 +1,112 @@
+/*
+ * @file sweep.rs
+ * @brief Printable character sweep
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: sweep.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Printable Character Sweep.
+//!
+//! BRIEF:
+//! Generates the full printable ASCII range for terminal testing.
+//! Shows at a glance whether a display renders every glyph.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// First character of the sweep.
+///
+/// # Value
+/// 0x20 (space)
+#[allow(dead_code)]
+pub const SWEEP_FIRST: u8 = 0x20;
+
+/// Last character of the sweep.
+///
+/// # Value
+/// 0x7E (tilde)
+#[allow(dead_code)]
+pub const SWEEP_LAST: u8 = 0x7E;
+
+/// Writes one sweep of printable ASCII followed by a newline.
+///
+/// # Details
+/// Produces SWEEP_FIRST through SWEEP_LAST in order, 95 characters,
+/// then `newline`. Writes nothing if the whole line does not fit.
+///
+/// # Arguments
+/// * `newline` - Line ending appended after the sweep
+/// * `out` - Destination buffer
+///
+/// # Returns
+/// * `usize` - Number of bytes written to `out`
+#[allow(dead_code)]
+pub fn sweep(newline: &[u8], out: &mut [u8]) -> usize {
+    let count = usize::from(SWEEP_LAST - SWEEP_FIRST) + 1;
+    let len = count + newline.len();
+    if out.len() < len {
+        return 0;
+    }
+    for (slot, ch) in out.iter_mut().zip(SWEEP_FIRST..=SWEEP_LAST) {
+        *slot = ch;
+    }
+    out[count..len].copy_from_slice(newline);
+    len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Sweep Tests ====================
+
+    #[test]
+    fn test_sweep_contains_95_printables_in_order() {
+        let mut out = [0u8; 128];
+        let n = sweep(b"\r\n", &mut out);
+        assert_eq!(n, 97);
+        assert_eq!(out[0], b' ');
+        assert_eq!(out[94], b'~');
+        assert!(out[..95].windows(2).all(|w| w[1] == w[0] + 1));
+    }
+
+    #[test]
+    fn test_sweep_ends_with_newline() {
+        let mut out = [0u8; 128];
+        let n = sweep(b"\n", &mut out);
+        assert_eq!(n, 96);
+        assert_eq!(out[95], b'\n');
+    }
+
+    #[test]
+    fn test_sweep_too_small_writes_nothing() {
+        let mut out = [0u8; 96];
+        assert_eq!(sweep(b"\r\n", &mut out), 0);
+    }
+}
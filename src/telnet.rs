@@ -0,0 +1,274 @@
+/*
+ * @file telnet.rs
+ * @brief Telnet negotiation
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: telnet.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Telnet Option Negotiation Stub.
+//!
+//! BRIEF:
+//! Recognizes telnet IAC command sequences and refuses every option
+//! so a raw telnet client settles into plain line mode.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Interpret As Command byte that starts every telnet command.
+///
+/// # Value
+/// 0xFF
+#[allow(dead_code)]
+pub const IAC: u8 = 0xFF;
+
+/// Sender wants to enable an option on its side.
+///
+/// # Value
+/// 0xFB
+#[allow(dead_code)]
+pub const WILL: u8 = 0xFB;
+
+/// Sender refuses to enable an option on its side.
+///
+/// # Value
+/// 0xFC
+#[allow(dead_code)]
+pub const WONT: u8 = 0xFC;
+
+/// Sender asks the receiver to enable an option.
+///
+/// # Value
+/// 0xFD
+#[allow(dead_code)]
+pub const DO: u8 = 0xFD;
+
+/// Sender asks the receiver not to enable an option.
+///
+/// # Value
+/// 0xFE
+#[allow(dead_code)]
+pub const DONT: u8 = 0xFE;
+
+/// Start of subnegotiation.
+///
+/// # Value
+/// 0xFA
+#[allow(dead_code)]
+pub const SB: u8 = 0xFA;
+
+/// End of subnegotiation.
+///
+/// # Value
+/// 0xF0
+#[allow(dead_code)]
+pub const SE: u8 = 0xF0;
+
+/// Result of feeding one byte to the telnet parser.
+///
+/// # Variants
+/// * `NotTelnet` - Byte is ordinary data, process normally
+/// * `Pending` - Byte was consumed by an incomplete command
+/// * `Reply` - Completed a negotiation; send these bytes back
+/// * `Consumed` - Completed a command that needs no reply
+/// * `Data` - Escaped `IAC IAC`; process this data byte
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum TelnetEvent {
+    NotTelnet,
+    Pending,
+    Reply([u8; 3]),
+    Consumed,
+    Data(u8),
+}
+
+/// Parser position within a telnet command.
+///
+/// # Variants
+/// * `Idle` - Outside any command
+/// * `Iac` - IAC received, awaiting the command byte
+/// * `Option` - Negotiation verb received, awaiting the option byte
+/// * `Sub` - Inside a subnegotiation
+/// * `SubIac` - IAC received inside a subnegotiation
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TelnetState {
+    Idle,
+    Iac,
+    Option(u8),
+    Sub,
+    SubIac,
+}
+
+/// Byte-at-a-time telnet command parser that refuses all options.
+///
+/// # Details
+/// `DO x` is answered with `WONT x` and `WILL x` with `DONT x`.
+/// `WONT` and `DONT` already match the refused state and get no reply.
+/// Subnegotiations and other two-byte commands are swallowed.
+///
+/// # Fields
+/// * `state` - Current position within a command
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct TelnetParser {
+    state: TelnetState,
+}
+
+impl Default for TelnetParser {
+    /// Returns default TelnetParser instance.
+    ///
+    /// # Details
+    /// Delegates to new() for initialization.
+    ///
+    /// # Returns
+    /// * `Self` - New idle TelnetParser
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TelnetParser {
+    /// Creates an idle telnet parser.
+    ///
+    /// # Returns
+    /// * `Self` - New TelnetParser outside any command
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self {
+            state: TelnetState::Idle,
+        }
+    }
+
+    /// Feeds one received byte to the parser.
+    ///
+    /// # Arguments
+    /// * `ch` - The byte received
+    ///
+    /// # Returns
+    /// * `TelnetEvent` - What the caller should do with the byte
+    #[allow(dead_code)]
+    pub fn feed(&mut self, ch: u8) -> TelnetEvent {
+        let (next, event) = match (self.state, ch) {
+            (TelnetState::Idle, IAC) => (TelnetState::Iac, TelnetEvent::Pending),
+            (TelnetState::Idle, _) => (TelnetState::Idle, TelnetEvent::NotTelnet),
+            (TelnetState::Iac, IAC) => (TelnetState::Idle, TelnetEvent::Data(IAC)),
+            (TelnetState::Iac, WILL | WONT | DO | DONT) => {
+                (TelnetState::Option(ch), TelnetEvent::Pending)
+            }
+            (TelnetState::Iac, SB) => (TelnetState::Sub, TelnetEvent::Pending),
+            (TelnetState::Iac, _) => (TelnetState::Idle, TelnetEvent::Consumed),
+            (TelnetState::Option(DO), _) => {
+                (TelnetState::Idle, TelnetEvent::Reply([IAC, WONT, ch]))
+            }
+            (TelnetState::Option(WILL), _) => {
+                (TelnetState::Idle, TelnetEvent::Reply([IAC, DONT, ch]))
+            }
+            (TelnetState::Option(_), _) => (TelnetState::Idle, TelnetEvent::Consumed),
+            (TelnetState::Sub, IAC) => (TelnetState::SubIac, TelnetEvent::Pending),
+            (TelnetState::Sub, _) => (TelnetState::Sub, TelnetEvent::Pending),
+            (TelnetState::SubIac, SE) => (TelnetState::Idle, TelnetEvent::Consumed),
+            (TelnetState::SubIac, _) => (TelnetState::Sub, TelnetEvent::Pending),
+        };
+        self.state = next;
+        event
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ECHO: u8 = 0x01;
+    const SUPPRESS_GO_AHEAD: u8 = 0x03;
+
+    // ==================== Negotiation Tests ====================
+
+    #[test]
+    fn test_plain_bytes_pass_through() {
+        let mut parser = TelnetParser::new();
+        assert_eq!(parser.feed(b'a'), TelnetEvent::NotTelnet);
+    }
+
+    #[test]
+    fn test_do_echo_refused_with_wont() {
+        let mut parser = TelnetParser::new();
+        assert_eq!(parser.feed(IAC), TelnetEvent::Pending);
+        assert_eq!(parser.feed(DO), TelnetEvent::Pending);
+        assert_eq!(parser.feed(ECHO), TelnetEvent::Reply([IAC, WONT, ECHO]));
+        assert_eq!(parser.feed(b'a'), TelnetEvent::NotTelnet);
+    }
+
+    #[test]
+    fn test_will_refused_with_dont() {
+        let mut parser = TelnetParser::new();
+        parser.feed(IAC);
+        parser.feed(WILL);
+        assert_eq!(
+            parser.feed(SUPPRESS_GO_AHEAD),
+            TelnetEvent::Reply([IAC, DONT, SUPPRESS_GO_AHEAD])
+        );
+    }
+
+    #[test]
+    fn test_wont_and_dont_need_no_reply() {
+        let mut parser = TelnetParser::new();
+        parser.feed(IAC);
+        parser.feed(WONT);
+        assert_eq!(parser.feed(ECHO), TelnetEvent::Consumed);
+        parser.feed(IAC);
+        parser.feed(DONT);
+        assert_eq!(parser.feed(ECHO), TelnetEvent::Consumed);
+    }
+
+    #[test]
+    fn test_escaped_iac_is_data() {
+        let mut parser = TelnetParser::new();
+        parser.feed(IAC);
+        assert_eq!(parser.feed(IAC), TelnetEvent::Data(IAC));
+    }
+
+    #[test]
+    fn test_two_byte_command_consumed() {
+        let mut parser = TelnetParser::new();
+        parser.feed(IAC);
+        assert_eq!(parser.feed(0xF1), TelnetEvent::Consumed);
+        assert_eq!(parser.feed(b'x'), TelnetEvent::NotTelnet);
+    }
+
+    #[test]
+    fn test_subnegotiation_swallowed() {
+        let mut parser = TelnetParser::new();
+        for &b in &[IAC, SB, 0x18, 0x00, b'x', IAC, IAC] {
+            assert_eq!(parser.feed(b), TelnetEvent::Pending);
+        }
+        parser.feed(b'y');
+        parser.feed(IAC);
+        assert_eq!(parser.feed(SE), TelnetEvent::Consumed);
+        assert_eq!(parser.feed(b'z'), TelnetEvent::NotTelnet);
+    }
+}
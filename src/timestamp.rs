@@ -0,0 +1,111 @@
+/*
+ * @file timestamp.rs
+ * @brief Line timestamp formatting
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: timestamp.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Line Timestamp Formatting.
+//!
+//! BRIEF:
+//! Formats millisecond tick counts as fixed-width bracketed prefixes.
+//! Timestamps come from the main loop; the formatting is pure.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+use crate::fmtbuf::FmtBuf;
+use core::fmt::Write;
+
+/// Number of digits in a timestamp prefix.
+///
+/// # Details
+/// Ticks wrap every 10^8 ms (about 27.8 hours) to keep the width.
+///
+/// # Value
+/// 8 digits
+#[allow(dead_code)]
+pub const TIMESTAMP_DIGITS: usize = 8;
+
+/// Formats a tick count as `[NNNNNNNN] `.
+///
+/// # Arguments
+/// * `ms` - Milliseconds since boot
+/// * `out` - Destination buffer, at least TIMESTAMP_DIGITS + 3 bytes
+///
+/// # Returns
+/// * `usize` - Number of bytes written to `out`, 0 if it does not fit
+#[allow(dead_code)]
+pub fn format_timestamp(ms: u64, out: &mut [u8]) -> usize {
+    let mut text = FmtBuf::new(out);
+    let ticks = ms % 10u64.pow(TIMESTAMP_DIGITS as u32);
+    match write!(text, "[{:0width$}] ", ticks, width = TIMESTAMP_DIGITS) {
+        Ok(()) => text.len(),
+        Err(_) => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(ms: u64) -> Vec<u8> {
+        let mut out = [0u8; 16];
+        let n = format_timestamp(ms, &mut out);
+        out[..n].to_vec()
+    }
+
+    // ==================== Timestamp Tests ====================
+
+    #[test]
+    fn test_zero() {
+        assert_eq!(format(0), b"[00000000] ");
+    }
+
+    #[test]
+    fn test_zero_padded() {
+        assert_eq!(format(12345), b"[00012345] ");
+    }
+
+    #[test]
+    fn test_full_width() {
+        assert_eq!(format(99_999_999), b"[99999999] ");
+    }
+
+    #[test]
+    fn test_wraps_past_width() {
+        assert_eq!(format(100_000_042), b"[00000042] ");
+    }
+
+    #[test]
+    fn test_buffer_too_small() {
+        let mut out = [0u8; 10];
+        assert_eq!(format_timestamp(1, &mut out), 0);
+    }
+}
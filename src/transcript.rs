@@ -0,0 +1,233 @@
+/*
+ * @file transcript.rs
+ * @brief Input transcript ring buffer
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: transcript.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Input Transcript Ring Buffer.
+//!
+//! BRIEF:
+//! Records recently typed bytes in a fixed-size ring.
+//! Supports searching for byte patterns across the wrap point.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Ring buffer holding the most recent `N` typed bytes.
+///
+/// # Details
+/// Once full, each new byte overwrites the oldest. Offsets are
+/// logical: 0 is the oldest retained byte regardless of where it sits
+/// in the ring.
+///
+/// # Fields
+/// * `buf` - Ring storage
+/// * `start` - Index of the oldest byte in `buf`
+/// * `len` - Number of valid bytes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct Transcript<const N: usize> {
+    buf: [u8; N],
+    start: usize,
+    len: usize,
+}
+
+impl<const N: usize> Default for Transcript<N> {
+    /// Returns default Transcript instance.
+    ///
+    /// # Details
+    /// Delegates to new() for initialization.
+    ///
+    /// # Returns
+    /// * `Self` - New empty Transcript
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Transcript<N> {
+    /// Creates an empty transcript.
+    ///
+    /// # Returns
+    /// * `Self` - New Transcript with no recorded bytes
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self {
+            buf: [0; N],
+            start: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of recorded bytes.
+    ///
+    /// # Returns
+    /// * `usize` - Bytes in the transcript
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the transcript is empty.
+    ///
+    /// # Returns
+    /// * `bool` - True if nothing is recorded
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Records a byte, overwriting the oldest when full.
+    ///
+    /// # Arguments
+    /// * `byte` - Byte to record
+    #[allow(dead_code)]
+    pub fn push(&mut self, byte: u8) {
+        if N == 0 {
+            return;
+        }
+        if self.len == N {
+            self.buf[self.start] = byte;
+            self.start = (self.start + 1) % N;
+        } else {
+            self.buf[(self.start + self.len) % N] = byte;
+            self.len += 1;
+        }
+    }
+
+    /// Returns the byte at a logical offset.
+    ///
+    /// # Arguments
+    /// * `offset` - Offset from the oldest byte
+    ///
+    /// # Returns
+    /// * `Option<u8>` - Byte, or None past the end
+    #[allow(dead_code)]
+    pub fn get(&self, offset: usize) -> Option<u8> {
+        (offset < self.len).then(|| self.buf[(self.start + offset) % N])
+    }
+
+    /// Finds the first occurrence of a byte pattern.
+    ///
+    /// # Details
+    /// Matches may span the physical end of the ring. An empty pattern
+    /// matches nothing.
+    ///
+    /// # Arguments
+    /// * `pattern` - Bytes to search for
+    ///
+    /// # Returns
+    /// * `Option<usize>` - Logical offset of the first match, or None
+    #[allow(dead_code)]
+    pub fn find(&self, pattern: &[u8]) -> Option<usize> {
+        if pattern.is_empty() || pattern.len() > self.len {
+            return None;
+        }
+        (0..=self.len - pattern.len()).find(|&at| {
+            pattern
+                .iter()
+                .enumerate()
+                .all(|(i, &b)| self.get(at + i) == Some(b))
+        })
+    }
+
+    /// Forgets all recorded bytes.
+    #[allow(dead_code)]
+    pub fn clear(&mut self) {
+        self.start = 0;
+        self.len = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filled<const N: usize>(bytes: &[u8]) -> Transcript<N> {
+        let mut t = Transcript::new();
+        for &b in bytes {
+            t.push(b);
+        }
+        t
+    }
+
+    // ==================== Ring Tests ====================
+
+    #[test]
+    fn test_new_is_empty() {
+        let t: Transcript<4> = Transcript::new();
+        assert!(t.is_empty());
+        assert_eq!(t.get(0), None);
+    }
+
+    #[test]
+    fn test_push_overwrites_oldest() {
+        let t: Transcript<4> = filled(b"abcdef");
+        assert_eq!(t.len(), 4);
+        assert_eq!(t.get(0), Some(b'c'));
+        assert_eq!(t.get(3), Some(b'f'));
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut t: Transcript<4> = filled(b"abc");
+        t.clear();
+        assert!(t.is_empty());
+    }
+
+    // ==================== Find Tests ====================
+
+    #[test]
+    fn test_find_at_known_offset() {
+        let t: Transcript<16> = filled(b"hello world");
+        assert_eq!(t.find(b"wor"), Some(6));
+    }
+
+    #[test]
+    fn test_find_spanning_wrap() {
+        let t: Transcript<8> = filled(b"0123456789AB");
+        assert_eq!(t.get(0), Some(b'4'));
+        assert_eq!(t.find(b"789A"), Some(3));
+    }
+
+    #[test]
+    fn test_find_missing() {
+        let t: Transcript<8> = filled(b"0123456789");
+        assert_eq!(t.find(b"01"), None);
+        assert_eq!(t.find(b"xyz"), None);
+        assert_eq!(t.find(b""), None);
+    }
+
+    #[test]
+    fn test_find_longer_than_transcript() {
+        let t: Transcript<8> = filled(b"ab");
+        assert_eq!(t.find(b"abc"), None);
+    }
+}
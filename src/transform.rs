@@ -0,0 +1,404 @@
+/*
+ * @file transform.rs
+ * @brief Character transforms
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: transform.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Character Transforms.
+//!
+//! BRIEF:
+//! Pure per-character transforms applied to echoed input.
+//! Kept free of controller state so each transform is testable alone.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Letter case applied to echoed characters.
+///
+/// # Variants
+/// * `AsIs` - Characters keep their typed case
+/// * `Upper` - Letters are converted to uppercase
+/// * `Lower` - Letters are converted to lowercase
+/// * `Title` - The first letter of each word is uppercase, the rest lowercase
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum CaseMode {
+    AsIs,
+    Upper,
+    Lower,
+    Title,
+}
+
+impl CaseMode {
+    /// Returns the mode that follows this one in the settings cycle.
+    ///
+    /// # Returns
+    /// * `CaseMode` - AsIs, Upper, Lower, Title, then AsIs again
+    #[allow(dead_code)]
+    pub fn next(self) -> Self {
+        match self {
+            CaseMode::AsIs => CaseMode::Upper,
+            CaseMode::Upper => CaseMode::Lower,
+            CaseMode::Lower => CaseMode::Title,
+            CaseMode::Title => CaseMode::AsIs,
+        }
+    }
+
+    /// Parses a case mode name.
+    ///
+    /// # Details
+    /// Accepts `upper`, `lower`, `asis`, and `title` in any letter case.
+    ///
+    /// # Arguments
+    /// * `name` - Mode name
+    ///
+    /// # Returns
+    /// * `Option<CaseMode>` - Parsed mode, or None if unknown
+    #[allow(dead_code)]
+    pub fn parse(name: &[u8]) -> Option<Self> {
+        [
+            CaseMode::AsIs,
+            CaseMode::Upper,
+            CaseMode::Lower,
+            CaseMode::Title,
+        ]
+        .into_iter()
+        .find(|mode| mode.keyword().eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the keyword used to select the mode by command.
+    ///
+    /// # Returns
+    /// * `&'static [u8]` - Command argument for the mode
+    fn keyword(self) -> &'static [u8] {
+        match self {
+            CaseMode::AsIs => b"asis",
+            _ => self.label(),
+        }
+    }
+
+    /// Returns the display name of the mode.
+    ///
+    /// # Returns
+    /// * `&'static [u8]` - Name shown in the settings menu
+    #[allow(dead_code)]
+    pub fn label(self) -> &'static [u8] {
+        match self {
+            CaseMode::AsIs => b"as-is",
+            CaseMode::Upper => b"upper",
+            CaseMode::Lower => b"lower",
+            CaseMode::Title => b"title",
+        }
+    }
+}
+
+/// Applies a case mode to a character.
+///
+/// # Details
+/// Title case needs the preceding character: a letter following a
+/// non-letter starts a word.
+///
+/// # Arguments
+/// * `ch` - Character to transform
+/// * `mode` - Case mode to apply
+/// * `prev` - Preceding character, or a space at the start of a line
+///
+/// # Returns
+/// * `u8` - Character in the requested case; non-letters unchanged
+#[allow(dead_code)]
+pub fn apply_case(ch: u8, mode: CaseMode, prev: u8) -> u8 {
+    match mode {
+        CaseMode::AsIs => ch,
+        CaseMode::Upper => ch.to_ascii_uppercase(),
+        CaseMode::Lower => ch.to_ascii_lowercase(),
+        CaseMode::Title if prev.is_ascii_alphabetic() => ch.to_ascii_lowercase(),
+        CaseMode::Title => ch.to_ascii_uppercase(),
+    }
+}
+
+/// Rotates an alphabetic character by a Caesar shift.
+///
+/// # Details
+/// Letters wrap within their own case; other bytes pass through.
+/// The shift is reduced modulo 26.
+///
+/// # Arguments
+/// * `ch` - Character to transform
+/// * `shift` - Number of positions to rotate forward
+///
+/// # Returns
+/// * `u8` - Rotated character
+#[allow(dead_code)]
+pub fn caesar(ch: u8, shift: u8) -> u8 {
+    let shift = shift % 26;
+    match ch {
+        b'a'..=b'z' => b'a' + (ch - b'a' + shift) % 26,
+        b'A'..=b'Z' => b'A' + (ch - b'A' + shift) % 26,
+        _ => ch,
+    }
+}
+
+/// Replaces one byte value with another.
+///
+/// # Arguments
+/// * `ch` - Character to transform
+/// * `substitution` - Byte to replace and its replacement, if any
+///
+/// # Returns
+/// * `u8` - Replacement if `ch` matches, otherwise `ch`
+#[allow(dead_code)]
+pub fn substitute(ch: u8, substitution: Option<(u8, u8)>) -> u8 {
+    match substitution {
+        Some((from, to)) if ch == from => to,
+        _ => ch,
+    }
+}
+
+/// Reverses the bit order of a byte.
+///
+/// # Arguments
+/// * `ch` - Character to transform
+///
+/// # Returns
+/// * `u8` - Byte with bit 7 swapped with bit 0, and so on
+#[allow(dead_code)]
+pub fn bit_reverse(ch: u8) -> u8 {
+    ch.reverse_bits()
+}
+
+/// Ordered chain of the per-character echo transforms.
+///
+/// # Details
+/// Applied in a fixed order so combined modes never conflict:
+/// substitution, then case, then Caesar cipher, then bit reversal.
+///
+/// # Fields
+/// * `substitution` - Byte to replace and its replacement, if any
+/// * `case` - Case mode to apply
+/// * `shift` - Caesar shift; 0 disables the cipher
+/// * `reverse` - Reverse the bit order last
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct TransformChain {
+    substitution: Option<(u8, u8)>,
+    case: CaseMode,
+    shift: u8,
+    reverse: bool,
+}
+
+impl TransformChain {
+    /// Creates a transform chain.
+    ///
+    /// # Arguments
+    /// * `substitution` - Byte to replace and its replacement, if any
+    /// * `case` - Case mode to apply
+    /// * `shift` - Caesar shift; 0 disables the cipher
+    /// * `reverse` - True to reverse the bit order last
+    ///
+    /// # Returns
+    /// * `Self` - New TransformChain
+    #[allow(dead_code)]
+    pub const fn new(
+        substitution: Option<(u8, u8)>,
+        case: CaseMode,
+        shift: u8,
+        reverse: bool,
+    ) -> Self {
+        Self {
+            substitution,
+            case,
+            shift,
+            reverse,
+        }
+    }
+
+    /// Runs a character through every stage of the chain.
+    ///
+    /// # Arguments
+    /// * `ch` - Character to transform
+    /// * `prev` - Preceding character, or a space at the start of a line
+    ///
+    /// # Returns
+    /// * `u8` - Transformed character
+    #[allow(dead_code)]
+    pub fn apply(&self, ch: u8, prev: u8) -> u8 {
+        let ch = substitute(ch, self.substitution);
+        let ch = apply_case(ch, self.case, prev);
+        let ch = caesar(ch, self.shift);
+        match self.reverse {
+            true => bit_reverse(ch),
+            false => ch,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Case Mode Tests ====================
+
+    #[test]
+    fn test_apply_case_as_is() {
+        assert_eq!(apply_case(b'a', CaseMode::AsIs, b' '), b'a');
+        assert_eq!(apply_case(b'Q', CaseMode::AsIs, b' '), b'Q');
+    }
+
+    #[test]
+    fn test_apply_case_upper_and_lower() {
+        assert_eq!(apply_case(b'a', CaseMode::Upper, b' '), b'A');
+        assert_eq!(apply_case(b'Q', CaseMode::Lower, b' '), b'q');
+        assert_eq!(apply_case(b'7', CaseMode::Upper, b' '), b'7');
+    }
+
+    #[test]
+    fn test_apply_case_title() {
+        assert_eq!(apply_case(b'h', CaseMode::Title, b' '), b'H');
+        assert_eq!(apply_case(b'E', CaseMode::Title, b'H'), b'e');
+        assert_eq!(apply_case(b'w', CaseMode::Title, b'-'), b'W');
+    }
+
+    #[test]
+    fn test_case_mode_parse() {
+        assert_eq!(CaseMode::parse(b"upper"), Some(CaseMode::Upper));
+        assert_eq!(CaseMode::parse(b"TITLE"), Some(CaseMode::Title));
+        assert_eq!(CaseMode::parse(b"AsIs"), Some(CaseMode::AsIs));
+        assert_eq!(CaseMode::parse(b"lower"), Some(CaseMode::Lower));
+        assert_eq!(CaseMode::parse(b"bogus"), None);
+        assert_eq!(CaseMode::parse(b"as-is"), None);
+    }
+
+    #[test]
+    fn test_case_mode_cycle() {
+        assert_eq!(CaseMode::AsIs.next(), CaseMode::Upper);
+        assert_eq!(CaseMode::Upper.next(), CaseMode::Lower);
+        assert_eq!(CaseMode::Lower.next(), CaseMode::Title);
+        assert_eq!(CaseMode::Title.next(), CaseMode::AsIs);
+    }
+
+    #[test]
+    fn test_case_mode_labels() {
+        assert_eq!(CaseMode::AsIs.label(), b"as-is");
+        assert_eq!(CaseMode::Upper.label(), b"upper");
+        assert_eq!(CaseMode::Lower.label(), b"lower");
+        assert_eq!(CaseMode::Title.label(), b"title");
+    }
+
+    // ==================== Caesar Tests ====================
+
+    #[test]
+    fn test_caesar_shift_three() {
+        assert_eq!(caesar(b'a', 3), b'd');
+        assert_eq!(caesar(b'z', 3), b'c');
+        assert_eq!(caesar(b'X', 3), b'A');
+    }
+
+    #[test]
+    fn test_caesar_shift_zero_identity() {
+        for ch in 0..=255u8 {
+            assert_eq!(caesar(ch, 0), ch);
+        }
+    }
+
+    #[test]
+    fn test_caesar_shift_modulo() {
+        assert_eq!(caesar(b'a', 29), b'd');
+        assert_eq!(caesar(b'a', 26), b'a');
+    }
+
+    #[test]
+    fn test_caesar_non_alpha_passthrough() {
+        assert_eq!(caesar(b'5', 3), b'5');
+        assert_eq!(caesar(b' ', 3), b' ');
+        assert_eq!(caesar(0x08, 3), 0x08);
+    }
+
+    #[test]
+    fn test_caesar_rot13_round_trip() {
+        assert_eq!(caesar(caesar(b'q', 13), 13), b'q');
+    }
+
+    // ==================== Substitution Tests ====================
+
+    #[test]
+    fn test_substitute_matching_byte() {
+        assert_eq!(substitute(b'a', Some((b'a', b'@'))), b'@');
+    }
+
+    #[test]
+    fn test_substitute_other_byte_unchanged() {
+        assert_eq!(substitute(b'b', Some((b'a', b'@'))), b'b');
+        assert_eq!(substitute(b'a', None), b'a');
+    }
+
+    // ==================== Bit Reverse Tests ====================
+
+    #[test]
+    fn test_bit_reverse() {
+        assert_eq!(bit_reverse(0x01), 0x80);
+        assert_eq!(bit_reverse(0x41), 0x82);
+        assert_eq!(bit_reverse(bit_reverse(0x5A)), 0x5A);
+    }
+
+    // ==================== Transform Chain Tests ====================
+
+    #[test]
+    fn test_chain_identity() {
+        let chain = TransformChain::new(None, CaseMode::AsIs, 0, false);
+        for ch in 0..=255u8 {
+            assert_eq!(chain.apply(ch, b' '), ch);
+        }
+    }
+
+    #[test]
+    fn test_chain_upper_then_rot13() {
+        let chain = TransformChain::new(None, CaseMode::Upper, 13, false);
+        assert_eq!(chain.apply(b'a', b' '), b'N');
+    }
+
+    #[test]
+    fn test_chain_substitution_before_case() {
+        let chain = TransformChain::new(Some((b'a', b'b')), CaseMode::Upper, 0, false);
+        assert_eq!(chain.apply(b'a', b' '), b'B');
+        assert_eq!(chain.apply(b'A', b' '), b'A');
+    }
+
+    #[test]
+    fn test_chain_substitution_before_cipher() {
+        let chain = TransformChain::new(Some((b'a', b'b')), CaseMode::AsIs, 1, false);
+        assert_eq!(chain.apply(b'a', b' '), b'c');
+    }
+
+    #[test]
+    fn test_chain_bit_reverse_last() {
+        let chain = TransformChain::new(None, CaseMode::Upper, 13, true);
+        assert_eq!(chain.apply(b'a', b' '), bit_reverse(b'N'));
+    }
+}
@@ -0,0 +1,279 @@
+/*
+ * @file txqueue.rs
+ * @brief Transmit queue and backpressure helpers
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: txqueue.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Transmit Queue.
+//!
+//! BRIEF:
+//! Buffers echo bytes that cannot be written while the TX FIFO is busy.
+//! Provides the pure throttle decision used by the main loop.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+use crate::error::UartError;
+
+/// Decides whether echo output should be deferred.
+///
+/// # Details
+/// Output is throttled while the free space in the TX FIFO is below
+/// the configured threshold; it resumes once `tx_free` reaches it.
+///
+/// # Arguments
+/// * `tx_free` - Free slots currently available in the TX FIFO
+/// * `threshold` - Minimum free slots required to keep writing
+///
+/// # Returns
+/// * `bool` - True if writing should be deferred
+#[allow(dead_code)]
+pub fn should_throttle(tx_free: usize, threshold: usize) -> bool {
+    tx_free < threshold
+}
+
+/// Fixed-capacity FIFO of bytes awaiting transmission.
+///
+/// # Details
+/// Ring buffer that holds echo output while the UART is throttled.
+/// Pushes are all-or-nothing so a response is never split; refused
+/// bytes are counted so the drop can be reported.
+///
+/// # Fields
+/// * `buf` - Ring storage
+/// * `head` - Index of the oldest queued byte
+/// * `len` - Number of queued bytes
+/// * `dropped` - Bytes refused since the last take_dropped()
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct TxQueue<const N: usize> {
+    buf: [u8; N],
+    head: usize,
+    len: usize,
+    dropped: usize,
+}
+
+impl<const N: usize> Default for TxQueue<N> {
+    /// Returns default TxQueue instance.
+    ///
+    /// # Details
+    /// Delegates to new() for initialization.
+    ///
+    /// # Returns
+    /// * `Self` - New empty TxQueue
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> TxQueue<N> {
+    /// Creates an empty transmit queue.
+    ///
+    /// # Returns
+    /// * `Self` - New TxQueue with no queued bytes
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self {
+            buf: [0; N],
+            head: 0,
+            len: 0,
+            dropped: 0,
+        }
+    }
+
+    /// Returns the number of queued bytes.
+    ///
+    /// # Returns
+    /// * `usize` - Bytes awaiting transmission
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the queue is empty.
+    ///
+    /// # Returns
+    /// * `bool` - True if nothing is queued
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the remaining capacity.
+    ///
+    /// # Returns
+    /// * `usize` - Bytes that can still be pushed
+    #[allow(dead_code)]
+    pub fn free(&self) -> usize {
+        N - self.len
+    }
+
+    /// Appends bytes to the tail of the queue.
+    ///
+    /// # Details
+    /// Either all bytes are queued or none are. Refused bytes are added
+    /// to the count returned by take_dropped().
+    ///
+    /// # Arguments
+    /// * `bytes` - Bytes to queue
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or TxQueueFull if they do not fit
+    #[allow(dead_code)]
+    pub fn push(&mut self, bytes: &[u8]) -> Result<(), UartError> {
+        if bytes.len() > self.free() {
+            self.dropped += bytes.len();
+            return Err(UartError::TxQueueFull);
+        }
+        for &b in bytes {
+            self.buf[(self.head + self.len) % N] = b;
+            self.len += 1;
+        }
+        Ok(())
+    }
+
+    /// Returns the oldest queued bytes that are contiguous in storage.
+    ///
+    /// # Details
+    /// When the queued data wraps, only the part up to the end of
+    /// storage is returned; call again after consume() for the rest.
+    ///
+    /// # Returns
+    /// * `&[u8]` - Contiguous bytes ready to transmit
+    #[allow(dead_code)]
+    pub fn peek(&self) -> &[u8] {
+        let end = (self.head + self.len).min(N);
+        &self.buf[self.head..end]
+    }
+
+    /// Removes bytes from the head of the queue.
+    ///
+    /// # Arguments
+    /// * `n` - Number of bytes transmitted, clamped to the queued length
+    #[allow(dead_code)]
+    pub fn consume(&mut self, n: usize) {
+        let n = n.min(self.len);
+        self.head = (self.head + n) % N;
+        self.len -= n;
+    }
+
+    /// Returns and clears the number of bytes refused by push().
+    ///
+    /// # Returns
+    /// * `usize` - Bytes dropped since the last call
+    #[allow(dead_code)]
+    pub fn take_dropped(&mut self) -> usize {
+        core::mem::take(&mut self.dropped)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Throttle Decision Tests ====================
+
+    #[test]
+    fn test_throttle_below_threshold() {
+        assert!(should_throttle(3, 4));
+    }
+
+    #[test]
+    fn test_no_throttle_at_threshold() {
+        assert!(!should_throttle(4, 4));
+    }
+
+    #[test]
+    fn test_no_throttle_above_threshold() {
+        assert!(!should_throttle(5, 4));
+    }
+
+    #[test]
+    fn test_throttle_when_full() {
+        assert!(should_throttle(0, 1));
+        assert!(!should_throttle(1, 1));
+    }
+
+    #[test]
+    fn test_zero_threshold_never_throttles() {
+        assert!(!should_throttle(0, 0));
+    }
+
+    // ==================== TxQueue Tests ====================
+
+    #[test]
+    fn test_new_queue_empty() {
+        let q: TxQueue<8> = TxQueue::new();
+        assert!(q.is_empty());
+        assert_eq!(q.free(), 8);
+        assert_eq!(q.peek(), b"");
+    }
+
+    #[test]
+    fn test_push_and_peek() {
+        let mut q: TxQueue<8> = TxQueue::new();
+        q.push(b"abc").unwrap();
+        assert_eq!(q.len(), 3);
+        assert_eq!(q.peek(), b"abc");
+    }
+
+    #[test]
+    fn test_push_full_rejected() {
+        let mut q: TxQueue<4> = TxQueue::new();
+        q.push(b"abc").unwrap();
+        assert_eq!(q.push(b"de"), Err(UartError::TxQueueFull));
+        assert_eq!(q.len(), 3);
+    }
+
+    #[test]
+    fn test_dropped_bytes_counted_until_taken() {
+        let mut q: TxQueue<4> = TxQueue::new();
+        assert_eq!(q.take_dropped(), 0);
+        q.push(b"abc").unwrap();
+        let _ = q.push(b"de");
+        let _ = q.push(b"fgh");
+        assert_eq!(q.take_dropped(), 5);
+        assert_eq!(q.take_dropped(), 0);
+        assert_eq!(q.len(), 3);
+    }
+
+    #[test]
+    fn test_consume_and_wrap() {
+        let mut q: TxQueue<4> = TxQueue::new();
+        q.push(b"abc").unwrap();
+        q.consume(2);
+        q.push(b"def").unwrap();
+        assert_eq!(q.peek(), b"cd");
+        q.consume(2);
+        assert_eq!(q.peek(), b"ef");
+        q.consume(10);
+        assert!(q.is_empty());
+    }
+}
@@ -38,24 +38,438 @@
 //!
 //! AUTHOR: Kevin Thomas
 //! CREATION DATE: December 4, 2025
-//! UPDATE DATE: December 5, 2025
+//! UPDATE DATE: October 16, 2026
 
-use crate::config::{BACKSPACE, BACKSPACE_SEQ, DELETE};
+use crate::base64;
+use crate::calc::{self, CalcError};
+use crate::command::{self, CmdResult, Command};
+use crate::config::{
+    ACK, BACKSPACE, BACKSPACE_SEQ, BANNER, BAUD_RATES, BELL, CTRL_O, DELETE, DEVICE_NAME_SIZE,
+    ECHO_BUF_SIZE, ERROR_LOG_SIZE, ESCAPE, EXPORT_CHUNK_SIZE, FORM_FEED, GHOST_SEED, GREETING,
+    INPUT_BUF_SIZE, LINE_BUF_SIZE, LOGO, MAX_CMD_LEN, MAX_ECHO_DELAY_MS, MAX_INDENT, NAK,
+    PATTERN_CHUNK_SIZE, PATTERN_MAX_BYTES, PROMPT_BUF_SIZE, RECENT_TX_SIZE, RLE_CHUNK_SIZE,
+    SWEEP_MAX_REPEAT, TRANSCRIPT_SIZE, VERTICAL_TAB, VISIBLE_BELL_SEQ,
+};
+use crate::ebcdic::{self, Encoding};
+use crate::errlog::ErrorLog;
+use crate::error::UartError;
+use crate::escape::{EscapeEvent, EscapeParser};
+use crate::events::{Callback, Callbacks, Event};
+use crate::fmtbuf::FmtBuf;
+use crate::frame::{Endianness, FrameDecoder, FrameEvent};
+use crate::ghost::GhostTyper;
+use crate::hex::{byte_to_hex, hex_dump, parse_hex, HexEntry};
+use crate::loopback::RecentTx;
+use crate::mem::format_mem;
+use crate::menu::{self, MenuAction};
+use crate::parity::ParityMode;
+use crate::prbs::Prbs7;
+use crate::prompt::{build_prompt, PromptParts};
+use crate::rle::RleDecoder;
+use crate::stats::overrun_rate;
+use crate::status::format_uart_flags;
+use crate::suppress::SuppressMask;
+use crate::sweep::sweep;
+use crate::telnet::{TelnetEvent, TelnetParser};
+use crate::timestamp::format_timestamp;
+use crate::transcript::Transcript;
+use crate::transform::{self, CaseMode, TransformChain};
+use crate::utf8::{self, Utf8Decoder, Utf8Event};
+use core::fmt::Write;
+
+mod echo;
+mod settings;
+
+pub use settings::Settings;
+
+/// Input handling mode of the controller.
+///
+/// # Variants
+/// * `Echo` - Characters are echoed and buffered as free text
+/// * `Command` - Characters build a command line run on Enter
+/// * `Menu` - Single keypresses select settings menu options
+/// * `Login` - Characters build a password checked on Enter
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum Mode {
+    Echo,
+    Command,
+    Menu,
+    Login,
+}
+
+impl Mode {
+    /// Returns the name of the mode.
+    ///
+    /// # Returns
+    /// * `&'static [u8]` - Lowercase mode name
+    #[allow(dead_code)]
+    pub fn label(self) -> &'static [u8] {
+        match self {
+            Mode::Echo => b"echo",
+            Mode::Command => b"command",
+            Mode::Menu => b"menu",
+            Mode::Login => b"login",
+        }
+    }
+}
+
+/// Returns the input mode selected by the boot jumper.
+///
+/// # Details
+/// The mode select pin has a pull-up, so an open jumper reads high and
+/// boots into echo mode. A jumper to ground reads low and boots into
+/// command-only mode.
+///
+/// # Arguments
+/// * `pin_high` - Level of MODE_SELECT_PIN sampled at boot
+///
+/// # Returns
+/// * `Mode` - Echo when high, Command when low
+#[allow(dead_code)]
+pub fn initial_mode(pin_high: bool) -> Mode {
+    if pin_high {
+        Mode::Echo
+    } else {
+        Mode::Command
+    }
+}
+
+/// Access level of the current session.
+///
+/// # Variants
+/// * `Admin` - All commands allowed
+/// * `Monitor` - Read-only; privileged commands are refused
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum Privilege {
+    Admin,
+    Monitor,
+}
+
+/// Line ending echoed when Enter is pressed.
+///
+/// # Variants
+/// * `AsReceived` - CR and LF echo as typed
+/// * `Cr` - Enter echoes CR
+/// * `Lf` - Enter echoes LF
+/// * `CrLf` - Enter echoes CRLF
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum NewlineMode {
+    AsReceived,
+    Cr,
+    Lf,
+    CrLf,
+}
+
+/// High-level terminal discipline, in the spirit of termios.
+///
+/// # Variants
+/// * `Cooked` - Line buffering, backspace editing, and CR to CRLF
+/// * `Raw` - Byte-at-a-time passthrough with no editing or translation
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum TerminalMode {
+    Cooked,
+    Raw,
+}
+
+/// How the bell is signalled when input is rejected.
+///
+/// # Variants
+/// * `Audible` - Send BEL (0x07)
+/// * `Visible` - Flash the screen with VISIBLE_BELL_SEQ
+/// * `Both` - Send BEL followed by the flash
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum BellMode {
+    Audible,
+    Visible,
+    Both,
+}
+
+/// How a CR immediately followed by LF ends echo-mode lines.
+///
+/// # Variants
+/// * `Collapse` - CRLF commits one line
+/// * `Separate` - CR and LF each commit, so CRLF adds an empty line
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum CrLfMode {
+    Collapse,
+    Separate,
+}
+
+impl NewlineMode {
+    /// Returns the mode that follows this one in the settings cycle.
+    ///
+    /// # Returns
+    /// * `NewlineMode` - AsReceived, Cr, Lf, CrLf, then AsReceived again
+    #[allow(dead_code)]
+    pub fn next(self) -> Self {
+        match self {
+            NewlineMode::AsReceived => NewlineMode::Cr,
+            NewlineMode::Cr => NewlineMode::Lf,
+            NewlineMode::Lf => NewlineMode::CrLf,
+            NewlineMode::CrLf => NewlineMode::AsReceived,
+        }
+    }
+
+    /// Returns the display name of the mode.
+    ///
+    /// # Returns
+    /// * `&'static [u8]` - Name shown in the settings menu
+    #[allow(dead_code)]
+    pub fn label(self) -> &'static [u8] {
+        match self {
+            NewlineMode::AsReceived => b"as-received",
+            NewlineMode::Cr => b"cr",
+            NewlineMode::Lf => b"lf",
+            NewlineMode::CrLf => b"crlf",
+        }
+    }
+
+    /// Returns the echo for a received line ending.
+    ///
+    /// # Arguments
+    /// * `ch` - CR or LF as received
+    ///
+    /// # Returns
+    /// * `&'static [u8]` - Line ending to echo
+    #[allow(dead_code)]
+    pub fn echo(self, ch: u8) -> &'static [u8] {
+        match self {
+            NewlineMode::AsReceived if ch == b'\r' => b"\r",
+            NewlineMode::AsReceived => b"\n",
+            NewlineMode::Cr => b"\r",
+            NewlineMode::Lf => b"\n",
+            NewlineMode::CrLf => b"\r\n",
+        }
+    }
+}
 
 /// UART controller with echo tracking.
 ///
 /// # Details
 /// Maintains UART echo count for statistics.
 /// Provides methods for character processing with backspace support.
+/// Tracks an approximate terminal cursor to answer VT100 position queries.
+/// Buffers the current line; ESC switches to command mode for one command.
+/// The line capacity is chosen at compile time through `LINE_CAP`.
 ///
 /// # Fields
 /// * `echo_count` - Number of characters echoed
+/// * `rx_bytes` - Bytes received through process_char()
+/// * `tx_bytes` - Response bytes returned to the caller
+/// * `rx_overruns` - Number of RX overrun errors reported by the loop
+/// * `errors` - Most recent errors and when they occurred
+/// * `now_ms` - Time supplied by the loop, used to timestamp errors
+/// * `flushed_count` - Output bytes confirmed transmitted by the loop
+/// * `settings` - Echo settings, saved and restored as a whole by profiles
+/// * `seq` - Next sequence number, wrapping after 255
+/// * `ghost_typing` - Ask the loop to delay each echo like a typist
+/// * `ghost` - Generator of ghost typing delays
+/// * `echo_delay_ms` - Fixed delay the loop waits before each character
+/// * `prev_space` - True if the last buffered echo-mode byte was a space
+/// * `echo_toggle_key` - Ctrl-O toggles echo_enabled in echo mode
+/// * `muted` - Temporarily withhold echo of typed input
+/// * `command_executed` - A command ran since last checked
+/// * `telnet` - Answer telnet option negotiation instead of echoing it
+/// * `telnet_parser` - Telnet command parser state
+/// * `encoding` - Character encoding of received and transmitted bytes
+/// * `parity` - Software parity checked in bit 7 of received bytes
+/// * `parity_drop` - Drop received bytes that fail the parity check
+/// * `parity_errors` - Number of received bytes that failed the parity check
+/// * `crc_framing` - Treat input as CRC-16 frames answered with ACK/NAK
+/// * `frame` - CRC frame decoder state
+/// * `frame_endianness` - Byte order of frame length and CRC fields
+/// * `hex_entry` - Hex digits entered so far
+/// * `rle_input` - Expand run-length encoded input before echoing
+/// * `rle` - Run-length decoder state
+/// * `smart_punctuation` - Map pasted UTF-8 smart quotes and dashes to ASCII
+/// * `utf8` - UTF-8 decoder state
+/// * `sanitize_input` - Silently drop stray C0 control bytes
+/// * `noise_count` - Number of bytes dropped by input sanitizing
+/// * `escape` - Parser for incoming escape sequences
+/// * `row` - Approximate 1-based cursor row, advanced per newline
+/// * `col` - Approximate 1-based cursor column
+/// * `mode` - Current input handling mode
+/// * `command_only` - Stay in command mode after each command and ESC
+/// * `transcript` - Recent bytes typed in echo mode, for the find command
+/// * `export` - Transcript snapshot being exported
+/// * `export_pos` - Offset of the next byte to export, while exporting
+/// * `pattern` - PRBS-7 generator for the pattern command
+/// * `pattern_remaining` - Test pattern bytes still to be produced
+/// * `line` - Bytes typed on the current line
+/// * `line_len` - Number of valid bytes in `line`
+/// * `max_cmd_len` - Longest command line accepted in command mode
+/// * `fold_command_case` - Match command names regardless of letter case
+/// * `transformed` - Secondary copy of `line` run through `pipeline_case`
+/// * `pipeline_case` - Case mode of the secondary pipeline
+/// * `last_line` - Most recently committed echo-mode line
+/// * `last_len` - Number of valid bytes in `last_line`
+/// * `paragraph_mode` - Single Enter buffers a newline; a blank line commits
+/// * `pending_newline` - True if the last buffered byte was a paragraph newline
+/// * `saved_line` - Echo-mode line captured on entering command mode
+/// * `saved_len` - Number of valid bytes in `saved_line`
+/// * `last_cmd` - Previous command line, replayed by `again`
+/// * `last_cmd_len` - Number of valid bytes in `last_cmd`, 0 if none
+/// * `prev_cr` - True if the previous byte was CR, so LF is not a new line
+/// * `skip_lf` - True if an LF following a command's CR should be dropped
+/// * `reset_requested` - True once a reset command has been confirmed
+/// * `bootsel_requested` - True once a bootsel command has been confirmed
+/// * `staged_baud` - Validated rate held by a baud command until it runs
+/// * `baud_request` - Baud rate confirmed and awaiting the main loop
+/// * `interlock` - Ask for y/n confirmation before destructive commands
+/// * `pending_confirmation` - Destructive command awaiting its y/n answer
+/// * `rate_requested` - Rate command awaiting the measured throughput
+/// * `mem_requested` - Mem command awaiting the free RAM measurement
+/// * `flash_info_requested` - Flashinfo command awaiting the image checksum
+/// * `status_requested` - True once a status command awaits the flag register
+/// * `line_timing` - Report the interval between committed lines
+/// * `line_committed` - True once a line commits while timing is enabled
+/// * `last_commit_ms` - Timestamp of the previous timed line, if any
+/// * `byte_budget` - Maximum bytes echoed per session, if capped
+/// * `session_bytes` - Bytes accepted since the session started
+/// * `budget_exhausted` - True once the limit message has been sent
+/// * `command_budget` - Maximum commands executed per session, if capped
+/// * `commands_run` - Commands executed since the session started
+/// * `loopback_guard` - Suppress re-echo of recently transmitted bytes
+/// * `recent_tx` - Window of recently transmitted bytes
+/// * `fault_injection` - Allow the fault command to arm a fault
+/// * `escape_reset` - Let `ESC R ESC R` request a soft reset
+/// * `pending_fault` - Corrupt the next non-empty response once
+/// * `hold_input` - Queue input while command output is pending
+/// * `output_pending` - True while command output is being transmitted
+/// * `input_buffer` - Characters received while output was pending
+/// * `input_len` - Number of valid bytes in `input_buffer`
+/// * `repeat_count` - Occurrences of the last uniq line so far
+/// * `line_no` - Number of the last numbered line
+/// * `echo_committed` - A line was committed and still needs echoing
+/// * `name` - Device name shown in the banner and prompt
+/// * `name_len` - Length of the device name, 0 if unset
+/// * `prompt_parts` - Parts shown in the command prompt
+/// * `password` - Password required before echoing, if any
+/// * `monitor_password` - Password granting a read-only session, if any
+/// * `privilege` - Access level of the current session
+/// * `greeting` - Welcome text shown after a successful login
+/// * `session_count` - Number of successful logins
+/// * `profiles` - Saved settings for profiles a and b, None until first left
+/// * `active_profile` - Index of the profile in use
+/// * `callbacks` - Event callbacks registered by the application
+/// * `out` - Response bytes for the last processed character
+/// * `out_len` - Number of valid bytes in `out`
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[allow(dead_code)]
-pub struct UartController {
+pub struct UartController<const LINE_CAP: usize = LINE_BUF_SIZE> {
     echo_count: u64,
+    rx_bytes: u64,
+    tx_bytes: u64,
+    rx_overruns: u64,
+    errors: ErrorLog<ERROR_LOG_SIZE>,
+    now_ms: u64,
+    flushed_count: u64,
+    settings: Settings,
+    seq: u8,
+    ghost_typing: bool,
+    ghost: GhostTyper,
+    echo_delay_ms: u32,
+    prev_space: bool,
+    echo_toggle_key: bool,
+    muted: bool,
+    command_executed: bool,
+    telnet: bool,
+    telnet_parser: TelnetParser,
+    encoding: Encoding,
+    parity: ParityMode,
+    parity_drop: bool,
+    parity_errors: u64,
+    crc_framing: bool,
+    frame: FrameDecoder,
+    frame_endianness: Endianness,
+    hex_entry: HexEntry<LINE_CAP>,
+    rle_input: bool,
+    rle: RleDecoder,
+    smart_punctuation: bool,
+    utf8: Utf8Decoder,
+    sanitize_input: bool,
+    noise_count: u64,
+    escape: EscapeParser,
+    row: u16,
+    col: u16,
+    mode: Mode,
+    command_only: bool,
+    transcript: Transcript<TRANSCRIPT_SIZE>,
+    export: Transcript<TRANSCRIPT_SIZE>,
+    export_pos: Option<usize>,
+    pattern: Prbs7,
+    pattern_remaining: u32,
+    line: [u8; LINE_CAP],
+    line_len: usize,
+    max_cmd_len: usize,
+    fold_command_case: bool,
+    transformed: [u8; LINE_CAP],
+    pipeline_case: CaseMode,
+    last_line: [u8; LINE_CAP],
+    last_len: usize,
+    paragraph_mode: bool,
+    pending_newline: bool,
+    saved_line: [u8; LINE_CAP],
+    saved_len: usize,
+    last_cmd: [u8; LINE_CAP],
+    last_cmd_len: usize,
+    prev_cr: bool,
+    skip_lf: bool,
+    reset_requested: bool,
+    bootsel_requested: bool,
+    staged_baud: u32,
+    baud_request: Option<u32>,
+    interlock: bool,
+    pending_confirmation: Option<Command>,
+    status_requested: bool,
+    rate_requested: bool,
+    mem_requested: bool,
+    flash_info_requested: bool,
+    line_timing: bool,
+    line_committed: bool,
+    last_commit_ms: Option<u64>,
+    byte_budget: Option<u64>,
+    session_bytes: u64,
+    budget_exhausted: bool,
+    command_budget: Option<u32>,
+    commands_run: u32,
+    loopback_guard: bool,
+    recent_tx: RecentTx<RECENT_TX_SIZE>,
+    fault_injection: bool,
+    escape_reset: bool,
+    pending_fault: bool,
+    hold_input: bool,
+    output_pending: bool,
+    input_buffer: [u8; INPUT_BUF_SIZE],
+    input_len: usize,
+    repeat_count: u32,
+    line_no: u32,
+    echo_committed: bool,
+    name: [u8; DEVICE_NAME_SIZE],
+    name_len: usize,
+    prompt_parts: PromptParts,
+    password: Option<&'static [u8]>,
+    monitor_password: Option<&'static [u8]>,
+    privilege: Privilege,
+    greeting: &'static [u8],
+    session_count: u32,
+    profiles: [Option<Settings>; 2],
+    active_profile: usize,
+    callbacks: Callbacks,
+    out: [u8; ECHO_BUF_SIZE],
+    out_len: usize,
 }
 
+/// Controller with the default LINE_BUF_SIZE line capacity.
+#[allow(dead_code)]
+pub type DefaultController = UartController<LINE_BUF_SIZE>;
+
 impl Default for UartController {
     /// Returns default UartController instance.
     ///
@@ -76,246 +490,7712 @@ impl UartController {
     /// # Details
     /// Initializes controller with zero echo count.
     /// Ready to receive characters immediately.
+    /// Uses the default LINE_BUF_SIZE line capacity.
     ///
     /// # Returns
     /// * `Self` - New UartController instance
     #[allow(dead_code)]
     pub fn new() -> Self {
-        Self { echo_count: 0 }
+        Self::with_capacity()
+    }
+}
+
+impl<const LINE_CAP: usize> UartController<LINE_CAP> {
+    /// Rejects line capacities whose longest response, a full-line dump
+    /// followed by the longest prompt, would not fit the response buffer.
+    const LINE_CAP_FITS: () = assert!(LINE_CAP * 3 + PROMPT_BUF_SIZE <= ECHO_BUF_SIZE);
+
+    /// Creates new UART controller with a `LINE_CAP`-byte line buffer.
+    ///
+    /// # Details
+    /// Same settings as new(), e.g. `UartController::<64>::with_capacity()`.
+    /// A capacity too large for ECHO_BUF_SIZE fails to compile.
+    ///
+    /// # Returns
+    /// * `Self` - New UartController instance
+    #[allow(dead_code)]
+    pub fn with_capacity() -> Self {
+        let () = Self::LINE_CAP_FITS;
+        Self {
+            echo_count: 0,
+            rx_bytes: 0,
+            tx_bytes: 0,
+            rx_overruns: 0,
+            errors: ErrorLog::new(),
+            now_ms: 0,
+            flushed_count: 0,
+            settings: Settings::default(),
+            seq: 0,
+            ghost_typing: false,
+            ghost: GhostTyper::new(GHOST_SEED),
+            echo_delay_ms: 0,
+            prev_space: false,
+            echo_toggle_key: true,
+            muted: false,
+            command_executed: false,
+            telnet: false,
+            telnet_parser: TelnetParser::new(),
+            encoding: Encoding::Ascii,
+            parity: ParityMode::None,
+            parity_drop: true,
+            parity_errors: 0,
+            crc_framing: false,
+            frame: FrameDecoder::new(),
+            frame_endianness: Endianness::Big,
+            hex_entry: HexEntry::new(),
+            rle_input: false,
+            rle: RleDecoder::new(),
+            smart_punctuation: false,
+            utf8: Utf8Decoder::new(),
+            sanitize_input: false,
+            noise_count: 0,
+            escape: EscapeParser::new(),
+            row: 1,
+            col: 1,
+            mode: Mode::Echo,
+            command_only: false,
+            transcript: Transcript::new(),
+            export: Transcript::new(),
+            export_pos: None,
+            pattern: Prbs7::new(),
+            pattern_remaining: 0,
+            line: [0; LINE_CAP],
+            line_len: 0,
+            max_cmd_len: MAX_CMD_LEN,
+            fold_command_case: false,
+            transformed: [0; LINE_CAP],
+            pipeline_case: CaseMode::AsIs,
+            last_line: [0; LINE_CAP],
+            last_len: 0,
+            paragraph_mode: false,
+            pending_newline: false,
+            saved_line: [0; LINE_CAP],
+            saved_len: 0,
+            last_cmd: [0; LINE_CAP],
+            last_cmd_len: 0,
+            prev_cr: false,
+            skip_lf: false,
+            reset_requested: false,
+            bootsel_requested: false,
+            staged_baud: 0,
+            baud_request: None,
+            interlock: false,
+            pending_confirmation: None,
+            status_requested: false,
+            rate_requested: false,
+            mem_requested: false,
+            flash_info_requested: false,
+            line_timing: false,
+            line_committed: false,
+            last_commit_ms: None,
+            byte_budget: None,
+            session_bytes: 0,
+            budget_exhausted: false,
+            command_budget: None,
+            commands_run: 0,
+            loopback_guard: false,
+            recent_tx: RecentTx::new(),
+            fault_injection: false,
+            escape_reset: false,
+            pending_fault: false,
+            hold_input: false,
+            output_pending: false,
+            input_buffer: [0; INPUT_BUF_SIZE],
+            input_len: 0,
+            repeat_count: 0,
+            line_no: 0,
+            echo_committed: false,
+            name: [0; DEVICE_NAME_SIZE],
+            name_len: 0,
+            prompt_parts: PromptParts::NAME,
+            password: None,
+            monitor_password: None,
+            privilege: Privilege::Admin,
+            greeting: GREETING,
+            session_count: 0,
+            profiles: [None; 2],
+            active_profile: 0,
+            callbacks: Callbacks::new(),
+            out: [0; ECHO_BUF_SIZE],
+            out_len: 0,
+        }
     }
 
     /// Processes a received character and returns echo response.
     ///
     /// # Details
     /// Handles backspace by returning erase sequence.
-    /// Normal characters are echoed as-is.
+    /// Normal characters are echoed as-is and buffered until Enter.
+    /// Escape sequences are consumed; `ESC[6n` returns a position report.
+    /// Unsupported bytes and characters beyond a full line echo nothing
+    /// useful: the former are dropped and the latter ring the bell.
     ///
     /// # Arguments
     /// * `ch` - The character received
     ///
     /// # Returns
-    /// * `&'static [u8]` - Bytes to echo back
+    /// * `&[u8]` - Bytes to echo back
     #[allow(dead_code)]
-    pub fn process_char(&mut self, ch: u8) -> &'static [u8] {
-        self.echo_count += 1;
-        if ch == BACKSPACE || ch == DELETE {
-            &BACKSPACE_SEQ
-        } else {
-            match ch {
-                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' => {
-                    static CHARS: [u8; 62] = [
-                        b'A', b'B', b'C', b'D', b'E', b'F', b'G', b'H', b'I', b'J', b'K', b'L',
-                        b'M', b'N', b'O', b'P', b'Q', b'R', b'S', b'T', b'U', b'V', b'W', b'X',
-                        b'Y', b'Z', b'a', b'b', b'c', b'd', b'e', b'f', b'g', b'h', b'i', b'j',
-                        b'k', b'l', b'm', b'n', b'o', b'p', b'q', b'r', b's', b't', b'u', b'v',
-                        b'w', b'x', b'y', b'z', b'0', b'1', b'2', b'3', b'4', b'5', b'6', b'7',
-                        b'8', b'9',
-                    ];
-                    let idx = CHARS.iter().position(|&c| c == ch).unwrap();
-                    &CHARS[idx..idx + 1]
-                }
-                b' ' => b" ",
-                b'!' => b"!",
-                b'"' => b"\"",
-                b'#' => b"#",
-                b'$' => b"$",
-                b'%' => b"%",
-                b'&' => b"&",
-                b'\'' => b"\'",
-                b'(' => b"(",
-                b')' => b")",
-                b'*' => b"*",
-                b'+' => b"+",
-                b',' => b",",
-                b'-' => b"-",
-                b'.' => b".",
-                b'/' => b"/",
-                b':' => b":",
-                b';' => b";",
-                b'<' => b"<",
-                b'=' => b"=",
-                b'>' => b">",
-                b'?' => b"?",
-                b'@' => b"@",
-                b'[' => b"[",
-                b'\\' => b"\\",
-                b']' => b"]",
-                b'^' => b"^",
-                b'_' => b"_",
-                b'`' => b"`",
-                b'{' => b"{",
-                b'|' => b"|",
-                b'}' => b"}",
-                b'~' => b"~",
-                b'\n' => b"\n",
-                b'\r' => b"\r",
-                b'\t' => b"\t",
-                _ => b"",
-            }
+    pub fn process_char(&mut self, ch: u8) -> &[u8] {
+        match self.try_process_char(ch) {
+            Ok(echo) | Err((_, echo)) => echo,
         }
     }
 
-    /// Returns total echo count.
+    /// Processes a received character and reports unsupported bytes.
+    ///
+    /// # Details
+    /// Fallible counterpart of process_char().
+    /// Counts the character, then fails if it has no echo mapping,
+    /// overflows the line buffer, or completes an unknown command.
+    /// A failure still returns the response written before it, such as
+    /// the bell or `unknown command`, which counts toward tx_bytes().
+    ///
+    /// # Arguments
+    /// * `ch` - The character received
     ///
     /// # Returns
-    /// * `u64` - Number of characters echoed
+    /// * `Result<&[u8], (UartError, &[u8])>` - Bytes to echo back, or the
+    ///   failure with the bytes to echo back
     #[allow(dead_code)]
-    pub fn echo_count(&self) -> u64 {
-        self.echo_count
+    pub fn try_process_char(&mut self, ch: u8) -> Result<&[u8], (UartError, &[u8])> {
+        self.out_len = 0;
+        self.rx_bytes += 1;
+        let armed = self.pending_fault;
+        let result = self.receive(ch);
+        match result {
+            Err(UartError::BudgetExhausted) | Ok(()) => {}
+            Err(err) => self.errors.record(err, self.now_ms),
+        }
+        self.inject_fault(armed);
+        self.tx_bytes += self.out_len as u64;
+        let echo = &self.out[..self.out_len];
+        result.map(|()| echo).map_err(|err| (err, echo))
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    // ==================== UartController Construction Tests ====================
-
-    #[test]
-    fn test_new_controller() {
-        let ctrl = UartController::new();
-        assert_eq!(ctrl.echo_count(), 0);
+    /// Processes a received character into a caller-supplied buffer.
+    ///
+    /// # Details
+    /// Copies the echo response into `out` without allocating. The
+    /// buffer must hold ECHO_BUF_SIZE bytes, the largest response one
+    /// character can produce. A smaller buffer is rejected before the
+    /// character is processed, so the controller is left unchanged and
+    /// no callbacks fire. A failed character still writes its response.
+    ///
+    /// # Arguments
+    /// * `ch` - The character received
+    /// * `out` - Destination buffer for the echo bytes
+    ///
+    /// # Returns
+    /// * `Result<usize, (UartError, usize)>` - Number of bytes written, or
+    ///   the failure with the number of bytes written
+    #[allow(dead_code)]
+    pub fn try_process(&mut self, ch: u8, out: &mut [u8]) -> Result<usize, (UartError, usize)> {
+        if out.len() < ECHO_BUF_SIZE {
+            return Err((UartError::BufferTooSmall, 0));
+        }
+        let (result, echo) = match self.try_process_char(ch) {
+            Ok(echo) => (Ok(()), echo),
+            Err((err, echo)) => (Err(err), echo),
+        };
+        out[..echo.len()].copy_from_slice(echo);
+        result.map(|()| echo.len()).map_err(|err| (err, echo.len()))
     }
 
-    #[test]
-    fn test_default_equals_new() {
-        let default = UartController::default();
-        let new = UartController::new();
-        assert_eq!(default.echo_count(), new.echo_count());
+    /// Enables or disables visible line-ending echo.
+    ///
+    /// # Details
+    /// When enabled, CR echoes as `\\r` and LF as `\\n` followed by
+    /// the real control byte so line structure is visible.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to show line endings
+    #[allow(dead_code)]
+    pub fn set_show_line_endings(&mut self, enabled: bool) {
+        self.settings.show_line_endings = enabled;
     }
 
-    // ==================== Character Processing Tests ====================
+    /// Returns whether line endings are echoed visibly.
+    ///
+    /// # Returns
+    /// * `bool` - True if visible line-ending echo is enabled
+    #[allow(dead_code)]
+    pub fn show_line_endings(&self) -> bool {
+        self.settings.show_line_endings
+    }
 
-    #[test]
-    fn test_process_char_returns_same() {
-        let mut ctrl = UartController::new();
-        assert_eq!(ctrl.process_char(b'A'), b"A");
+    /// Selects the letter case of hex output.
+    ///
+    /// # Details
+    /// Applies to every hex emission, such as the dump and export
+    /// commands. Some tools expect lowercase digits.
+    ///
+    /// # Arguments
+    /// * `upper` - True for `AB`, false for `ab`
+    #[allow(dead_code)]
+    pub fn set_hex_uppercase(&mut self, upper: bool) {
+        self.settings.hex_uppercase = upper;
     }
 
-    #[test]
-    fn test_process_char_increments_count() {
-        let mut ctrl = UartController::new();
-        ctrl.process_char(b'A');
-        ctrl.process_char(b'B');
-        ctrl.process_char(b'C');
-        assert_eq!(ctrl.echo_count(), 3);
+    /// Returns whether hex output uses uppercase digits.
+    ///
+    /// # Returns
+    /// * `bool` - True if letter digits are uppercase
+    #[allow(dead_code)]
+    pub fn hex_uppercase(&self) -> bool {
+        self.settings.hex_uppercase
     }
 
-    #[test]
-    fn test_process_char_special() {
-        let mut ctrl = UartController::new();
-        assert_eq!(ctrl.process_char(b'\n'), b"\n");
-        assert_eq!(ctrl.process_char(b'\r'), b"\r");
+    /// Enables or disables command status markers.
+    ///
+    /// # Details
+    /// For scripting. Each command line is followed by `[OK]`, or by
+    /// `[ERR N]` with the CmdResult exit code. Reports produced later
+    /// by the main loop, such as status, arrive after the marker.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to emit markers
+    #[allow(dead_code)]
+    pub fn set_status_markers(&mut self, enabled: bool) {
+        self.settings.status_markers = enabled;
     }
 
-    #[test]
-    fn test_process_backspace() {
-        let mut ctrl = UartController::new();
-        assert_eq!(ctrl.process_char(0x08), &[0x08, b' ', 0x08]);
+    /// Returns whether command status markers are enabled.
+    ///
+    /// # Returns
+    /// * `bool` - True if markers follow command output
+    #[allow(dead_code)]
+    pub fn status_markers(&self) -> bool {
+        self.settings.status_markers
     }
 
-    #[test]
-    fn test_process_delete() {
-        let mut ctrl = UartController::new();
-        assert_eq!(ctrl.process_char(0x7F), &[0x08, b' ', 0x08]);
+    /// Sets the byte echoed before each echoed character.
+    ///
+    /// # Details
+    /// For framing single bytes, e.g. STX. Applies to typed characters
+    /// echoed in echo mode, not to command or report output.
+    ///
+    /// # Arguments
+    /// * `prefix` - Byte to echo first, or None for no prefix
+    #[allow(dead_code)]
+    pub fn set_char_prefix(&mut self, prefix: Option<u8>) {
+        self.settings.char_prefix = prefix;
     }
 
-    #[test]
-    fn test_process_tab() {
-        let mut ctrl = UartController::new();
-        assert_eq!(ctrl.process_char(b'\t'), b"\t");
+    /// Returns the byte echoed before each echoed character.
+    ///
+    /// # Returns
+    /// * `Option<u8>` - Prefix byte, or None
+    #[allow(dead_code)]
+    pub fn char_prefix(&self) -> Option<u8> {
+        self.settings.char_prefix
     }
 
-    #[test]
-    fn test_process_uppercase_letters() {
-        let mut ctrl = UartController::new();
-        assert_eq!(ctrl.process_char(b'A'), b"A");
-        assert_eq!(ctrl.process_char(b'Z'), b"Z");
-        assert_eq!(ctrl.process_char(b'M'), b"M");
+    /// Sets the byte echoed after each echoed character.
+    ///
+    /// # Details
+    /// Pairs with set_char_prefix(), e.g. ETX.
+    ///
+    /// # Arguments
+    /// * `suffix` - Byte to echo last, or None for no suffix
+    #[allow(dead_code)]
+    pub fn set_char_suffix(&mut self, suffix: Option<u8>) {
+        self.settings.char_suffix = suffix;
     }
 
-    #[test]
-    fn test_process_lowercase_letters() {
-        let mut ctrl = UartController::new();
-        assert_eq!(ctrl.process_char(b'a'), b"a");
-        assert_eq!(ctrl.process_char(b'z'), b"z");
-        assert_eq!(ctrl.process_char(b'm'), b"m");
+    /// Returns the byte echoed after each echoed character.
+    ///
+    /// # Returns
+    /// * `Option<u8>` - Suffix byte, or None
+    #[allow(dead_code)]
+    pub fn char_suffix(&self) -> Option<u8> {
+        self.settings.char_suffix
     }
 
-    #[test]
-    fn test_process_digits() {
-        let mut ctrl = UartController::new();
-        assert_eq!(ctrl.process_char(b'0'), b"0");
-        assert_eq!(ctrl.process_char(b'9'), b"9");
-        assert_eq!(ctrl.process_char(b'5'), b"5");
+    /// Enables or disables sequence-numbered echo.
+    ///
+    /// # Details
+    /// When enabled, each echoed character is followed by a one-byte
+    /// sequence number so the host can detect dropped characters.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to append sequence numbers
+    #[allow(dead_code)]
+    pub fn set_seq_echo(&mut self, enabled: bool) {
+        self.settings.seq_echo = enabled;
     }
 
-    #[test]
-    fn test_process_punctuation() {
-        let mut ctrl = UartController::new();
-        assert_eq!(ctrl.process_char(b'!'), b"!");
-        assert_eq!(ctrl.process_char(b'?'), b"?");
-        assert_eq!(ctrl.process_char(b'.'), b".");
-        assert_eq!(ctrl.process_char(b','), b",");
+    /// Returns whether sequence-numbered echo is enabled.
+    ///
+    /// # Returns
+    /// * `bool` - True if sequence numbers are appended
+    #[allow(dead_code)]
+    pub fn seq_echo(&self) -> bool {
+        self.settings.seq_echo
     }
 
-    #[test]
-    fn test_process_symbols() {
-        let mut ctrl = UartController::new();
-        assert_eq!(ctrl.process_char(b'@'), b"@");
-        assert_eq!(ctrl.process_char(b'#'), b"#");
-        assert_eq!(ctrl.process_char(b'$'), b"$");
-        assert_eq!(ctrl.process_char(b'%'), b"%");
+    /// Enables or disables the ghost typing effect.
+    ///
+    /// # Details
+    /// The controller only generates delays; the loop waits
+    /// next_delay() before processing each character.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to delay echoes
+    #[allow(dead_code)]
+    pub fn set_ghost_typing(&mut self, enabled: bool) {
+        self.ghost_typing = enabled;
     }
 
-    #[test]
-    fn test_process_brackets() {
-        let mut ctrl = UartController::new();
-        assert_eq!(ctrl.process_char(b'['), b"[");
-        assert_eq!(ctrl.process_char(b']'), b"]");
-        assert_eq!(ctrl.process_char(b'('), b"(");
-        assert_eq!(ctrl.process_char(b')'), b")");
-        assert_eq!(ctrl.process_char(b'{'), b"{");
-        assert_eq!(ctrl.process_char(b'}'), b"}");
+    /// Returns whether the ghost typing effect is enabled.
+    ///
+    /// # Returns
+    /// * `bool` - True if echoes are delayed
+    #[allow(dead_code)]
+    pub fn ghost_typing(&self) -> bool {
+        self.ghost_typing
     }
 
-    #[test]
-    fn test_process_unknown_char() {
-        let mut ctrl = UartController::new();
-        assert_eq!(ctrl.process_char(0x01), b"");
-        assert_eq!(ctrl.process_char(0xFF), b"");
+    /// Restarts the ghost typing delays from a seed.
+    ///
+    /// # Arguments
+    /// * `seed` - Generator seed
+    #[allow(dead_code)]
+    pub fn set_ghost_seed(&mut self, seed: u32) {
+        self.ghost = GhostTyper::new(seed);
     }
 
-    #[test]
+    /// Returns the next ghost typing delay.
+    ///
+    /// # Returns
+    /// * `u32` - Delay in milliseconds
+    #[allow(dead_code)]
+    pub fn next_delay(&mut self) -> u32 {
+        self.ghost.next_delay()
+    }
+
+    /// Sets the fixed delay before each character is processed.
+    ///
+    /// # Details
+    /// Like ghost typing, the loop does the waiting. Values above
+    /// MAX_ECHO_DELAY_MS are clamped.
+    ///
+    /// # Arguments
+    /// * `ms` - Delay in milliseconds, 0 for none
+    #[allow(dead_code)]
+    pub fn set_echo_delay(&mut self, ms: u32) {
+        self.echo_delay_ms = ms.min(MAX_ECHO_DELAY_MS);
+    }
+
+    /// Returns the fixed delay before each character is processed.
+    ///
+    /// # Returns
+    /// * `u32` - Delay in milliseconds
+    #[allow(dead_code)]
+    pub fn echo_delay(&self) -> u32 {
+        self.echo_delay_ms
+    }
+
+    /// Enables or disables named control key echo.
+    ///
+    /// # Details
+    /// For teaching keyboard codes. Control keys echo their names, e.g.
+    /// Enter as `<CR>` and backspace as `<BS>`, while still acting on
+    /// the line buffer. ESC shows `<ESC>` before the command prompt.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to echo control key names
+    #[allow(dead_code)]
+    pub fn set_named_controls(&mut self, enabled: bool) {
+        self.settings.named_controls = enabled;
+    }
+
+    /// Returns whether control keys echo as names.
+    ///
+    /// # Returns
+    /// * `bool` - True if control key names are echoed
+    #[allow(dead_code)]
+    pub fn named_controls(&self) -> bool {
+        self.settings.named_controls
+    }
+
+    /// Enables or disables collapsing of repeated spaces.
+    ///
+    /// # Details
+    /// When enabled, a space typed directly after another space is
+    /// neither echoed nor buffered, so runs of spaces become one.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to collapse runs of spaces
+    #[allow(dead_code)]
+    pub fn set_collapse_spaces(&mut self, enabled: bool) {
+        self.settings.collapse_spaces = enabled;
+    }
+
+    /// Returns whether repeated spaces are collapsed.
+    ///
+    /// # Returns
+    /// * `bool` - True if runs of spaces are collapsed
+    #[allow(dead_code)]
+    pub fn collapse_spaces(&self) -> bool {
+        self.settings.collapse_spaces
+    }
+
+    /// Returns the next sequence number to be sent.
+    ///
+    /// # Returns
+    /// * `u8` - Sequence number for the next echoed character
+    #[allow(dead_code)]
+    pub fn seq(&self) -> u8 {
+        self.seq
+    }
+
+    /// Enables or disables automatic line wrapping.
+    ///
+    /// # Details
+    /// When enabled, a printable character that would be echoed past
+    /// the wrap width is preceded by CR LF. The line buffer is unaffected.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to wrap at the configured width
+    #[allow(dead_code)]
+    pub fn set_auto_wrap(&mut self, enabled: bool) {
+        self.settings.auto_wrap = enabled;
+    }
+
+    /// Returns whether automatic line wrapping is enabled.
+    ///
+    /// # Returns
+    /// * `bool` - True if auto-wrap is enabled
+    #[allow(dead_code)]
+    pub fn auto_wrap(&self) -> bool {
+        self.settings.auto_wrap
+    }
+
+    /// Sets the column width used for automatic wrapping.
+    ///
+    /// # Arguments
+    /// * `width` - Number of columns per line, at least 1
+    #[allow(dead_code)]
+    pub fn set_wrap_width(&mut self, width: u16) {
+        self.settings.wrap_width = width.max(1);
+    }
+
+    /// Returns the column width used for automatic wrapping.
+    ///
+    /// # Returns
+    /// * `u16` - Columns per line, RIGHT_MARGIN by default
+    #[allow(dead_code)]
+    pub fn wrap_width(&self) -> u16 {
+        self.settings.wrap_width
+    }
+
+    /// Sets the Caesar cipher shift for echoed letters.
+    ///
+    /// # Details
+    /// Letters are rotated within their case before being echoed and
+    /// buffered. The shift is reduced modulo 26; 0 disables the cipher.
+    ///
+    /// # Arguments
+    /// * `shift` - Number of positions to rotate forward
+    #[allow(dead_code)]
+    pub fn set_caesar(&mut self, shift: u8) {
+        self.settings.caesar_shift = shift % 26;
+    }
+
+    /// Returns the active Caesar cipher shift.
+    ///
+    /// # Returns
+    /// * `u8` - Shift in the range 0-25
+    #[allow(dead_code)]
+    pub fn caesar(&self) -> u8 {
+        self.settings.caesar_shift
+    }
+
+    /// Sets or clears the echo substitution.
+    ///
+    /// # Arguments
+    /// * `substitution` - Byte to replace and its replacement, or None
+    #[allow(dead_code)]
+    pub fn set_substitution(&mut self, substitution: Option<(u8, u8)>) {
+        self.settings.substitution = substitution;
+    }
+
+    /// Returns the echo substitution.
+    ///
+    /// # Returns
+    /// * `Option<(u8, u8)>` - Byte replaced and its replacement, if set
+    #[allow(dead_code)]
+    pub fn substitution(&self) -> Option<(u8, u8)> {
+        self.settings.substitution
+    }
+
+    /// Enables or disables bit reversal of echoed bytes.
+    ///
+    /// # Details
+    /// Letters become non-printable bytes, which echo mode rejects
+    /// unless another mode maps them back.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to reverse bit order
+    #[allow(dead_code)]
+    pub fn set_bit_reverse(&mut self, enabled: bool) {
+        self.settings.bit_reverse = enabled;
+    }
+
+    /// Returns whether echoed bytes are bit-reversed.
+    ///
+    /// # Returns
+    /// * `bool` - True if bit order is reversed
+    #[allow(dead_code)]
+    pub fn bit_reverse(&self) -> bool {
+        self.settings.bit_reverse
+    }
+
+    /// Returns the echo transform chain built from the active modes.
+    ///
+    /// # Details
+    /// Substitution, case, Caesar cipher, then bit reversal, in that
+    /// order, for each echo-mode character.
+    ///
+    /// # Returns
+    /// * `TransformChain` - Chain applied in echo mode
+    #[allow(dead_code)]
+    pub fn transform_chain(&self) -> TransformChain {
+        TransformChain::new(
+            self.settings.substitution,
+            self.settings.case_mode,
+            self.settings.caesar_shift,
+            self.settings.bit_reverse,
+        )
+    }
+
+    /// Enables or disables ASCII value echo.
+    ///
+    /// # Details
+    /// When enabled, each key in echo mode is answered with its decimal
+    /// code and CRLF instead of the character, e.g. 'A' echoes "65\r\n".
+    /// Control characters echo their code too. The line buffer is left
+    /// untouched while the mode is active.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to echo decimal codes
+    #[allow(dead_code)]
+    pub fn set_ascii_value_mode(&mut self, enabled: bool) {
+        self.settings.ascii_value_mode = enabled;
+    }
+
+    /// Returns whether ASCII value echo is enabled.
+    ///
+    /// # Returns
+    /// * `bool` - True if keys echo their decimal code
+    #[allow(dead_code)]
+    pub fn ascii_value_mode(&self) -> bool {
+        self.settings.ascii_value_mode
+    }
+
+    /// Enables or disables hex escapes for non-ASCII bytes.
+    ///
+    /// # Details
+    /// For debugging. Each echo-mode byte from 0x80 up is echoed as the
+    /// four characters `\xNN`, using the hex case setting, instead of
+    /// being rejected; no UTF-8 decoding is attempted. The line buffer
+    /// is left untouched for these bytes.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to echo non-ASCII bytes as escapes
+    #[allow(dead_code)]
+    pub fn set_show_hex_escapes(&mut self, enabled: bool) {
+        self.settings.show_hex_escapes = enabled;
+    }
+
+    /// Returns whether non-ASCII bytes echo as hex escapes.
+    ///
+    /// # Returns
+    /// * `bool` - True if bytes from 0x80 up echo as `\xNN`
+    #[allow(dead_code)]
+    pub fn show_hex_escapes(&self) -> bool {
+        self.settings.show_hex_escapes
+    }
+
+    /// Enables or disables vertical tab and form feed handling.
+    ///
+    /// # Details
+    /// When enabled, VT echoes VT_NEWLINE_SEQ and ends the line like
+    /// Enter, and FF echoes CLEAR_SCREEN_SEQ and homes the tracked
+    /// cursor. When disabled both are dropped as unsupported bytes.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to handle VT and FF
+    #[allow(dead_code)]
+    pub fn set_form_control(&mut self, enabled: bool) {
+        self.settings.form_control = enabled;
+    }
+
+    /// Returns whether vertical tab and form feed are handled.
+    ///
+    /// # Returns
+    /// * `bool` - True if VT and FF are handled
+    #[allow(dead_code)]
+    pub fn form_control(&self) -> bool {
+        self.settings.form_control
+    }
+
+    /// Enables or disables character echo.
+    ///
+    /// # Details
+    /// When disabled, echo-mode characters are still buffered but
+    /// nothing is echoed back.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to echo characters
+    #[allow(dead_code)]
+    pub fn set_echo_enabled(&mut self, enabled: bool) {
+        self.settings.echo_enabled = enabled;
+    }
+
+    /// Returns whether character echo is enabled.
+    ///
+    /// # Returns
+    /// * `bool` - True if characters are echoed
+    #[allow(dead_code)]
+    pub fn echo_enabled(&self) -> bool {
+        self.settings.echo_enabled
+    }
+
+    /// Enables or disables the Ctrl-O echo toggle key.
+    ///
+    /// # Details
+    /// When enabled, CTRL_O in echo mode flips echo on or off and
+    /// prints `(echo off)` or `(echo on)`. The key is not buffered.
+    /// A monitor session is refused with `(permission denied)`.
+    /// When disabled, CTRL_O is an ordinary unsupported byte.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to let Ctrl-O toggle echo
+    #[allow(dead_code)]
+    pub fn set_echo_toggle_key(&mut self, enabled: bool) {
+        self.echo_toggle_key = enabled;
+    }
+
+    /// Returns whether Ctrl-O toggles echo.
+    ///
+    /// # Returns
+    /// * `bool` - True if the toggle key is enabled
+    #[allow(dead_code)]
+    pub fn echo_toggle_key(&self) -> bool {
+        self.echo_toggle_key
+    }
+
+    /// Mutes or unmutes the echo of typed input.
+    ///
+    /// # Details
+    /// Driven by the main loop from mute::is_muted(). Unlike
+    /// set_echo_enabled() this is transient; muted characters are
+    /// still buffered and command output is unaffected.
+    ///
+    /// # Arguments
+    /// * `muted` - True to withhold echo
+    #[allow(dead_code)]
+    pub fn set_muted(&mut self, muted: bool) {
+        self.muted = muted;
+    }
+
+    /// Returns whether echo is currently muted.
+    ///
+    /// # Returns
+    /// * `bool` - True if typed input is not echoed
+    #[allow(dead_code)]
+    pub fn muted(&self) -> bool {
+        self.muted
+    }
+
+    /// Sets the character classes whose echo is suppressed.
+    ///
+    /// # Details
+    /// Suppressed characters are still counted and buffered.
+    ///
+    /// # Arguments
+    /// * `mask` - Classes to suppress
+    #[allow(dead_code)]
+    pub fn set_suppress(&mut self, mask: SuppressMask) {
+        self.settings.suppress = mask;
+    }
+
+    /// Returns the character classes whose echo is suppressed.
+    ///
+    /// # Returns
+    /// * `SuppressMask` - Suppressed classes
+    #[allow(dead_code)]
+    pub fn suppress(&self) -> SuppressMask {
+        self.settings.suppress
+    }
+
+    /// Suppresses or restores the echo of ASCII letters.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to suppress
+    #[allow(dead_code)]
+    pub fn suppress_letters(&mut self, enabled: bool) {
+        self.settings.suppress.set(SuppressMask::LETTERS, enabled);
+    }
+
+    /// Suppresses or restores the echo of ASCII digits.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to suppress
+    #[allow(dead_code)]
+    pub fn suppress_digits(&mut self, enabled: bool) {
+        self.settings.suppress.set(SuppressMask::DIGITS, enabled);
+    }
+
+    /// Suppresses or restores the echo of whitespace.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to suppress
+    #[allow(dead_code)]
+    pub fn suppress_whitespace(&mut self, enabled: bool) {
+        self.settings
+            .suppress
+            .set(SuppressMask::WHITESPACE, enabled);
+    }
+
+    /// Suppresses or restores the echo of ASCII punctuation.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to suppress
+    #[allow(dead_code)]
+    pub fn suppress_punctuation(&mut self, enabled: bool) {
+        self.settings
+            .suppress
+            .set(SuppressMask::PUNCTUATION, enabled);
+    }
+
+    /// Applies a terminal discipline by setting its individual flags.
+    ///
+    /// # Details
+    /// Cooked enables line buffering and backspace editing and echoes
+    /// Enter as CRLF. Raw disables all three so each byte echoes as
+    /// received. The flags can still be changed individually afterwards.
+    ///
+    /// # Arguments
+    /// * `mode` - Terminal discipline to apply
+    #[allow(dead_code)]
+    pub fn set_terminal_mode(&mut self, mode: TerminalMode) {
+        let cooked = mode == TerminalMode::Cooked;
+        self.settings.line_buffering = cooked;
+        self.settings.backspace_handling = cooked;
+        self.settings.newline_mode = if cooked {
+            NewlineMode::CrLf
+        } else {
+            NewlineMode::AsReceived
+        };
+    }
+
+    /// Enables or disables echo-mode line buffering.
+    ///
+    /// # Details
+    /// When disabled, characters are echoed but no line is built or
+    /// committed.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to buffer lines
+    #[allow(dead_code)]
+    pub fn set_line_buffering(&mut self, enabled: bool) {
+        self.settings.line_buffering = enabled;
+    }
+
+    /// Returns whether echo-mode line buffering is enabled.
+    ///
+    /// # Returns
+    /// * `bool` - True if lines are buffered
+    #[allow(dead_code)]
+    pub fn line_buffering(&self) -> bool {
+        self.settings.line_buffering
+    }
+
+    /// Enables or disables backspace and delete editing.
+    ///
+    /// # Details
+    /// When disabled, backspace and delete echo as themselves and do not
+    /// edit the line buffer.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to treat backspace and delete as editing keys
+    #[allow(dead_code)]
+    pub fn set_backspace_handling(&mut self, enabled: bool) {
+        self.settings.backspace_handling = enabled;
+    }
+
+    /// Returns whether backspace and delete edit the line.
+    ///
+    /// # Returns
+    /// * `bool` - True if backspace editing is enabled
+    #[allow(dead_code)]
+    pub fn backspace_handling(&self) -> bool {
+        self.settings.backspace_handling
+    }
+
+    /// Enables or disables telnet option negotiation.
+    ///
+    /// # Details
+    /// For raw telnet clients. When enabled, IAC command sequences are
+    /// parsed instead of echoed, and every option is refused so the
+    /// client settles into line mode.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to handle telnet commands
+    #[allow(dead_code)]
+    pub fn set_telnet(&mut self, enabled: bool) {
+        self.telnet = enabled;
+        self.telnet_parser = TelnetParser::new();
+    }
+
+    /// Returns whether telnet option negotiation is enabled.
+    ///
+    /// # Returns
+    /// * `bool` - True if telnet commands are handled
+    #[allow(dead_code)]
+    pub fn telnet(&self) -> bool {
+        self.telnet
+    }
+
+    /// Sets the character encoding used on the wire.
+    ///
+    /// # Details
+    /// With Encoding::Ebcdic, received bytes are translated from EBCDIC
+    /// before processing and all output is translated back, so a
+    /// mainframe-style peer sees its own characters echoed.
+    ///
+    /// # Arguments
+    /// * `encoding` - Wire encoding
+    #[allow(dead_code)]
+    pub fn set_encoding(&mut self, encoding: Encoding) {
+        self.encoding = encoding;
+    }
+
+    /// Returns the character encoding used on the wire.
+    ///
+    /// # Returns
+    /// * `Encoding` - Wire encoding
+    #[allow(dead_code)]
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    /// Sets the software parity check for 7-bit links.
+    ///
+    /// # Details
+    /// For links whose UART hardware does not check parity. Bit 7 of
+    /// each received byte is verified and then cleared before any
+    /// other processing. Mismatches are counted in parity_errors().
+    ///
+    /// # Arguments
+    /// * `mode` - Expected parity, or ParityMode::None to disable
+    #[allow(dead_code)]
+    pub fn set_parity(&mut self, mode: ParityMode) {
+        self.parity = mode;
+    }
+
+    /// Returns the software parity check mode.
+    ///
+    /// # Returns
+    /// * `ParityMode` - Expected parity
+    #[allow(dead_code)]
+    pub fn parity(&self) -> ParityMode {
+        self.parity
+    }
+
+    /// Chooses whether bytes with bad parity are dropped.
+    ///
+    /// # Details
+    /// When disabled, a bad byte is still counted but processed with
+    /// its parity bit cleared.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to drop bytes that fail the check
+    #[allow(dead_code)]
+    pub fn set_parity_drop(&mut self, enabled: bool) {
+        self.parity_drop = enabled;
+    }
+
+    /// Returns whether bytes with bad parity are dropped.
+    ///
+    /// # Returns
+    /// * `bool` - True if failing bytes are dropped
+    #[allow(dead_code)]
+    pub fn parity_drop(&self) -> bool {
+        self.parity_drop
+    }
+
+    /// Returns the number of parity mismatches seen.
+    ///
+    /// # Returns
+    /// * `u64` - Received bytes that failed the parity check
+    #[allow(dead_code)]
+    pub fn parity_errors(&self) -> u64 {
+        self.parity_errors
+    }
+
+    /// Enables or disables CRC-checked framing.
+    ///
+    /// # Details
+    /// While enabled, input is decoded as `len16, payload, crc16` frames
+    /// instead of typed text; see frame::encode(). Valid payloads are
+    /// echoed followed by ACK and corrupt frames get NAK. Toggling
+    /// discards any partial frame.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to decode CRC frames
+    #[allow(dead_code)]
+    pub fn set_crc_framing(&mut self, enabled: bool) {
+        self.crc_framing = enabled;
+        self.frame = FrameDecoder::with_endianness(self.frame_endianness);
+    }
+
+    /// Returns whether CRC-checked framing is enabled.
+    ///
+    /// # Returns
+    /// * `bool` - True if input is decoded as CRC frames
+    #[allow(dead_code)]
+    pub fn crc_framing(&self) -> bool {
+        self.crc_framing
+    }
+
+    /// Sets the byte order of frame length and CRC fields.
+    ///
+    /// # Details
+    /// Matches peers that send little-endian fields. Changing the
+    /// order discards any partial frame.
+    ///
+    /// # Arguments
+    /// * `order` - Byte order to use
+    #[allow(dead_code)]
+    pub fn set_frame_endianness(&mut self, order: Endianness) {
+        self.frame_endianness = order;
+        self.frame = FrameDecoder::with_endianness(order);
+    }
+
+    /// Returns the byte order of frame length and CRC fields.
+    ///
+    /// # Returns
+    /// * `Endianness` - Current byte order
+    #[allow(dead_code)]
+    pub fn frame_endianness(&self) -> Endianness {
+        self.frame_endianness
+    }
+
+    /// Enables or disables smart punctuation normalization.
+    ///
+    /// # Details
+    /// For text pasted from word processors. Multibyte UTF-8 input is
+    /// decoded and curly quotes, dashes, and no-break spaces are
+    /// replaced by their ASCII equivalents. Other multibyte characters
+    /// are rejected as unsupported.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to normalize smart punctuation
+    #[allow(dead_code)]
+    pub fn set_smart_punctuation(&mut self, enabled: bool) {
+        self.smart_punctuation = enabled;
+        self.utf8 = Utf8Decoder::new();
+    }
+
+    /// Enables or disables hex entry in echo mode.
+    ///
+    /// # Details
+    /// Only hex digits, single spaces between bytes, backspace, and
+    /// Enter are accepted; other keys ring the bell. Enter echoes the
+    /// assembled bytes on their own line.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to accept hex entry
+    #[allow(dead_code)]
+    pub fn set_hex_input(&mut self, enabled: bool) {
+        self.settings.hex_input = enabled;
+        self.hex_entry.clear();
+    }
+
+    /// Returns whether hex entry is enabled.
+    ///
+    /// # Returns
+    /// * `bool` - True if echo mode accepts hex entry
+    #[allow(dead_code)]
+    pub fn hex_input(&self) -> bool {
+        self.settings.hex_input
+    }
+
+    /// Enables or disables run-length decoding of input.
+    ///
+    /// # Details
+    /// Input is read as count byte, value byte pairs and each run is
+    /// expanded and processed as if typed.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to decode run-length input
+    #[allow(dead_code)]
+    pub fn set_rle_input(&mut self, enabled: bool) {
+        self.rle_input = enabled;
+        self.rle = RleDecoder::new();
+    }
+
+    /// Returns whether run-length decoding of input is enabled.
+    ///
+    /// # Returns
+    /// * `bool` - True if input is run-length decoded
+    #[allow(dead_code)]
+    pub fn rle_input(&self) -> bool {
+        self.rle_input
+    }
+
+    /// Returns whether smart punctuation normalization is enabled.
+    ///
+    /// # Returns
+    /// * `bool` - True if smart punctuation is normalized
+    #[allow(dead_code)]
+    pub fn smart_punctuation(&self) -> bool {
+        self.smart_punctuation
+    }
+
+    /// Enables or disables input sanitizing.
+    ///
+    /// # Details
+    /// For pasted text with stray NULs and other C0 controls. When
+    /// enabled, those bytes are dropped silently and counted as noise
+    /// instead of being processed. Editing keys are kept.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to drop stray control bytes
+    #[allow(dead_code)]
+    pub fn set_sanitize_input(&mut self, enabled: bool) {
+        self.sanitize_input = enabled;
+    }
+
+    /// Returns whether input sanitizing is enabled.
+    ///
+    /// # Returns
+    /// * `bool` - True if stray control bytes are dropped
+    #[allow(dead_code)]
+    pub fn sanitize_input(&self) -> bool {
+        self.sanitize_input
+    }
+
+    /// Returns the number of bytes dropped as noise.
+    ///
+    /// # Returns
+    /// * `u64` - Noise byte count
+    #[allow(dead_code)]
+    pub fn noise_count(&self) -> u64 {
+        self.noise_count
+    }
+
+    /// Sets the letter case applied to echoed characters.
+    ///
+    /// # Arguments
+    /// * `mode` - Case mode to apply
+    #[allow(dead_code)]
+    pub fn set_case_mode(&mut self, mode: CaseMode) {
+        self.settings.case_mode = mode;
+    }
+
+    /// Returns the letter case applied to echoed characters.
+    ///
+    /// # Returns
+    /// * `CaseMode` - Active case mode
+    #[allow(dead_code)]
+    pub fn case_mode(&self) -> CaseMode {
+        self.settings.case_mode
+    }
+
+    /// Sets the line ending echoed for Enter.
+    ///
+    /// # Details
+    /// Outside AsReceived, a CRLF pair echoes the configured ending once.
+    ///
+    /// # Arguments
+    /// * `mode` - Newline mode to apply
+    #[allow(dead_code)]
+    pub fn set_newline_mode(&mut self, mode: NewlineMode) {
+        self.settings.newline_mode = mode;
+    }
+
+    /// Returns the line ending echoed for Enter.
+    ///
+    /// # Returns
+    /// * `NewlineMode` - Active newline mode
+    #[allow(dead_code)]
+    pub fn newline_mode(&self) -> NewlineMode {
+        self.settings.newline_mode
+    }
+
+    /// Sets how the bell is signalled when input is rejected.
+    ///
+    /// # Details
+    /// Some terminals ignore an audible BEL; Visible and Both flash the
+    /// screen instead or as well.
+    ///
+    /// # Arguments
+    /// * `mode` - Bell mode to apply
+    #[allow(dead_code)]
+    pub fn set_bell_mode(&mut self, mode: BellMode) {
+        self.settings.bell_mode = mode;
+    }
+
+    /// Returns how the bell is signalled.
+    ///
+    /// # Returns
+    /// * `BellMode` - Active bell mode
+    #[allow(dead_code)]
+    pub fn bell_mode(&self) -> BellMode {
+        self.settings.bell_mode
+    }
+
+    /// Sets whether CRLF commits one echo-mode line or two.
+    ///
+    /// # Details
+    /// Collapse suits hosts that send CRLF for Enter. Separate treats
+    /// CR and LF as independent line ends, for sources that mix them.
+    ///
+    /// # Arguments
+    /// * `mode` - CRLF handling to apply
+    #[allow(dead_code)]
+    pub fn set_crlf_mode(&mut self, mode: CrLfMode) {
+        self.settings.crlf_mode = mode;
+    }
+
+    /// Returns how CRLF ends echo-mode lines.
+    ///
+    /// # Returns
+    /// * `CrLfMode` - Active CRLF handling
+    #[allow(dead_code)]
+    pub fn crlf_mode(&self) -> CrLfMode {
+        self.settings.crlf_mode
+    }
+
+    /// Returns the approximate terminal cursor position.
+    ///
+    /// # Details
+    /// Row advances per newline; column follows echoed characters.
+    /// Both are 1-based to match VT100 reports.
+    ///
+    /// # Returns
+    /// * `(u16, u16)` - Current (row, column)
+    #[allow(dead_code)]
+    pub fn cursor_position(&self) -> (u16, u16) {
+        (self.row, self.col)
+    }
+
+    /// Returns the current input mode.
+    ///
+    /// # Returns
+    /// * `Mode` - Echo or Command
+    #[allow(dead_code)]
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Applies the input mode selected at boot.
+    ///
+    /// # Details
+    /// Mode::Command enables command-only operation: the controller
+    /// enters command mode, shows the prompt, and returns to the prompt
+    /// after each command or ESC instead of dropping back to echo mode.
+    /// Mode::Echo leaves the controller in its normal echo mode. Other
+    /// modes are ignored.
+    ///
+    /// # Arguments
+    /// * `mode` - Mode from initial_mode
+    ///
+    /// # Returns
+    /// * `&[u8]` - Prompt to send, empty for echo mode
+    #[allow(dead_code)]
+    pub fn set_initial_mode(&mut self, mode: Mode) -> &[u8] {
+        self.out_len = 0;
+        match mode {
+            Mode::Command => {
+                self.command_only = true;
+                self.clear_line();
+                self.set_mode(Mode::Command);
+                self.emit_prompt();
+            }
+            Mode::Echo => {
+                self.command_only = false;
+                self.set_mode(Mode::Echo);
+            }
+            Mode::Menu | Mode::Login => {}
+        }
+        &self.out[..self.out_len]
+    }
+
+    /// Returns whether the controller runs command-only.
+    ///
+    /// # Returns
+    /// * `bool` - True if commands return to the prompt
+    #[allow(dead_code)]
+    pub fn command_only(&self) -> bool {
+        self.command_only
+    }
+
+    /// Sets the maximum command line length.
+    ///
+    /// # Details
+    /// Applies in command mode only; echo-mode lines are limited by
+    /// the line buffer capacity alone, which also caps this limit.
+    ///
+    /// # Arguments
+    /// * `len` - Longest command line accepted
+    #[allow(dead_code)]
+    pub fn set_max_cmd_len(&mut self, len: usize) {
+        self.max_cmd_len = len;
+    }
+
+    /// Returns the maximum command line length.
+    ///
+    /// # Returns
+    /// * `usize` - Longest command line accepted
+    #[allow(dead_code)]
+    pub fn max_cmd_len(&self) -> usize {
+        self.max_cmd_len
+    }
+
+    /// Enables or disables case-insensitive command names.
+    ///
+    /// # Details
+    /// Only the dispatcher's comparison copy of the name is folded.
+    /// The echo, the buffered line, and command arguments keep the
+    /// case as typed, independent of the echo case mode.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to fold command name case
+    #[allow(dead_code)]
+    pub fn set_fold_command_case(&mut self, enabled: bool) {
+        self.fold_command_case = enabled;
+    }
+
+    /// Returns whether command names are matched case-insensitively.
+    ///
+    /// # Returns
+    /// * `bool` - True if command name case is folded
+    #[allow(dead_code)]
+    pub fn fold_command_case(&self) -> bool {
+        self.fold_command_case
+    }
+
+    /// Captures the current echo settings.
+    ///
+    /// # Returns
+    /// * `Settings` - Settings that restore() can reapply
+    #[allow(dead_code)]
+    pub fn snapshot(&self) -> Settings {
+        self.settings
+    }
+
+    /// Reapplies previously captured echo settings.
+    ///
+    /// # Details
+    /// Resets the uniq run and any partial hex entry, as their setters
+    /// do.
+    ///
+    /// # Arguments
+    /// * `state` - Settings taken with snapshot()
+    #[allow(dead_code)]
+    pub fn restore(&mut self, state: &Settings) {
+        self.settings = *state;
+        self.set_uniq(state.uniq);
+        self.set_hex_input(state.hex_input);
+    }
+
+    /// Returns the active settings profile.
+    ///
+    /// # Returns
+    /// * `usize` - 0 for profile a, 1 for profile b
+    #[allow(dead_code)]
+    pub fn active_profile(&self) -> usize {
+        self.active_profile
+    }
+
+    /// Returns the bytes typed on the current line.
+    ///
+    /// # Returns
+    /// * `&[u8]` - Line buffer contents not yet committed
+    #[allow(dead_code)]
+    pub fn line(&self) -> &[u8] {
+        &self.line[..self.line_len]
+    }
+
+    /// Sets the case mode of the secondary pipeline.
+    ///
+    /// # Details
+    /// Every buffered character is also stored in a second buffer run
+    /// through this mode, so the logical content can differ from what
+    /// was echoed. CaseMode::AsIs makes it a plain copy.
+    ///
+    /// # Arguments
+    /// * `mode` - Case mode for the secondary copy
+    #[allow(dead_code)]
+    pub fn set_pipeline_case(&mut self, mode: CaseMode) {
+        self.pipeline_case = mode;
+    }
+
+    /// Returns the case mode of the secondary pipeline.
+    ///
+    /// # Returns
+    /// * `CaseMode` - Case mode for the secondary copy
+    #[allow(dead_code)]
+    pub fn pipeline_case(&self) -> CaseMode {
+        self.pipeline_case
+    }
+
+    /// Returns the current line as seen by the secondary pipeline.
+    ///
+    /// # Details
+    /// Tracks line() edit for edit, including backspace.
+    ///
+    /// # Returns
+    /// * `&[u8]` - Transformed copy of the current line
+    #[allow(dead_code)]
+    pub fn transformed_line(&self) -> &[u8] {
+        &self.transformed[..self.line_len]
+    }
+
+    /// Returns and clears a pending reset request.
+    ///
+    /// # Details
+    /// Set by the RESET command after its confirmation is emitted.
+    /// The main loop flushes output and then performs the reset.
+    ///
+    /// # Returns
+    /// * `bool` - True if a reset was requested since the last call
+    #[allow(dead_code)]
+    pub fn take_reset_request(&mut self) -> bool {
+        core::mem::take(&mut self.reset_requested)
+    }
+
+    /// Returns and clears a pending bootloader reboot request.
+    ///
+    /// # Details
+    /// Set by the BOOTSEL command after its confirmation is emitted.
+    /// The main loop flushes output and then reboots into the UF2
+    /// bootloader.
+    ///
+    /// # Returns
+    /// * `bool` - True if a bootsel reboot was requested since the last call
+    #[allow(dead_code)]
+    pub fn take_bootsel_request(&mut self) -> bool {
+        core::mem::take(&mut self.bootsel_requested)
+    }
+
+    /// Returns and clears a pending baud rate change.
+    ///
+    /// # Details
+    /// Set by the baud command after its confirmation is emitted. The
+    /// main loop flushes output at the old rate and then reprograms
+    /// UART0.
+    ///
+    /// # Returns
+    /// * `Option<u32>` - New baud rate, or None if no change was requested
+    #[allow(dead_code)]
+    pub fn take_baud_request(&mut self) -> Option<u32> {
+        self.baud_request.take()
+    }
+
+    /// Enables or disables the destructive command interlock.
+    ///
+    /// # Details
+    /// When enabled, reset, bootsel, and baud print `confirm (y/n)? ` and run
+    /// only if the next key is y. Any other key cancels. A safety
+    /// setting, so it is not part of a snapshot and switching profiles
+    /// leaves it as is.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to require confirmation
+    #[allow(dead_code)]
+    pub fn set_interlock(&mut self, enabled: bool) {
+        self.interlock = enabled;
+    }
+
+    /// Returns whether destructive commands require confirmation.
+    ///
+    /// # Returns
+    /// * `bool` - True if the interlock is enabled
+    #[allow(dead_code)]
+    pub fn interlock(&self) -> bool {
+        self.interlock
+    }
+
+    /// Returns the destructive command awaiting confirmation.
+    ///
+    /// # Returns
+    /// * `Option<Command>` - Command to run on y, or None
+    #[allow(dead_code)]
+    pub fn pending_confirmation(&self) -> Option<Command> {
+        self.pending_confirmation
+    }
+
+    /// Returns and clears the command executed flag.
+    ///
+    /// # Details
+    /// Set whenever a recognized command runs. The main loop uses it
+    /// to start the MUTE_WINDOW_MS echo mute window.
+    ///
+    /// # Returns
+    /// * `bool` - True if a command ran since the last call
+    #[allow(dead_code)]
+    pub fn take_command_executed(&mut self) -> bool {
+        core::mem::take(&mut self.command_executed)
+    }
+
+    /// Returns and clears a pending status request.
+    ///
+    /// # Details
+    /// Set by the status command. The main loop reads the UART flag
+    /// register and passes it to uart_status().
+    ///
+    /// # Returns
+    /// * `bool` - True if status was requested since the last call
+    #[allow(dead_code)]
+    pub fn take_status_request(&mut self) -> bool {
+        core::mem::take(&mut self.status_requested)
+    }
+
+    /// Returns and clears a pending rate request.
+    ///
+    /// # Details
+    /// Set by the rate command. The main loop reads its rate meter and
+    /// passes the result to rate_report().
+    ///
+    /// # Returns
+    /// * `bool` - True if the rate was requested since the last call
+    #[allow(dead_code)]
+    pub fn take_rate_request(&mut self) -> bool {
+        core::mem::take(&mut self.rate_requested)
+    }
+
+    /// Formats the measured throughput for the rate command.
+    ///
+    /// # Arguments
+    /// * `bytes_per_sec` - Current receive rate
+    ///
+    /// # Returns
+    /// * `&[u8]` - Bytes to transmit
+    #[allow(dead_code)]
+    pub fn rate_report(&mut self, bytes_per_sec: u32) -> &[u8] {
+        self.out_len = 0;
+        let mut msg = [0u8; 32];
+        let mut text = FmtBuf::new(&mut msg);
+        let _ = write!(text, "rate: {} B/s\r\n", bytes_per_sec);
+        let len = text.len();
+        self.emit(&msg[..len]);
+        &self.out[..self.out_len]
+    }
+
+    /// Returns and clears a pending free memory request.
+    ///
+    /// # Details
+    /// Set by the mem command. The main loop measures free RAM and
+    /// passes it to mem_report().
+    ///
+    /// # Returns
+    /// * `bool` - True if free memory was requested since the last call
+    #[allow(dead_code)]
+    pub fn take_mem_request(&mut self) -> bool {
+        core::mem::take(&mut self.mem_requested)
+    }
+
+    /// Formats the free RAM measurement for the mem command.
+    ///
+    /// # Arguments
+    /// * `free` - Approximate free bytes
+    ///
+    /// # Returns
+    /// * `&[u8]` - Bytes to transmit
+    #[allow(dead_code)]
+    pub fn mem_report(&mut self, free: u32) -> &[u8] {
+        self.out_len = 0;
+        let mut buf = [0u8; 32];
+        let n = format_mem(free, &mut buf);
+        self.emit(&buf[..n]);
+        self.emit(b"\r\n");
+        &self.out[..self.out_len]
+    }
+
+    /// Returns and clears a pending flash info request.
+    ///
+    /// # Details
+    /// Set by the flashinfo command. The main loop checksums the
+    /// application image in flash and passes the result to
+    /// flash_info_report().
+    ///
+    /// # Returns
+    /// * `bool` - True if flash info was requested since the last call
+    #[allow(dead_code)]
+    pub fn take_flash_info_request(&mut self) -> bool {
+        core::mem::take(&mut self.flash_info_requested)
+    }
+
+    /// Formats the application image size and checksum.
+    ///
+    /// # Details
+    /// Produces `flash: N bytes crc32: 0xXXXXXXXX` and CRLF, with the hex
+    /// case setting, so a host updater can decide whether to reflash.
+    ///
+    /// # Arguments
+    /// * `size` - Application image size in bytes
+    /// * `crc` - CRC-32 of the image
+    ///
+    /// # Returns
+    /// * `&[u8]` - Bytes to transmit
+    #[allow(dead_code)]
+    pub fn flash_info_report(&mut self, size: u32, crc: u32) -> &[u8] {
+        self.out_len = 0;
+        let mut msg = [0u8; 48];
+        let mut text = FmtBuf::new(&mut msg);
+        let _ = match self.settings.hex_uppercase {
+            true => write!(text, "flash: {} bytes crc32: 0x{:08X}\r\n", size, crc),
+            false => write!(text, "flash: {} bytes crc32: 0x{:08x}\r\n", size, crc),
+        };
+        let len = text.len();
+        self.emit(&msg[..len]);
+        &self.out[..self.out_len]
+    }
+
+    /// Formats the main loop iteration rate.
+    ///
+    /// # Arguments
+    /// * `per_sec` - Loop iterations per second
+    ///
+    /// # Returns
+    /// * `&[u8]` - Bytes to transmit
+    #[allow(dead_code)]
+    pub fn loop_report(&mut self, per_sec: u32) -> &[u8] {
+        self.out_len = 0;
+        let mut msg = [0u8; 32];
+        let mut text = FmtBuf::new(&mut msg);
+        let _ = write!(text, "loops: {}/s\r\n", per_sec);
+        let len = text.len();
+        self.emit(&msg[..len]);
+        &self.out[..self.out_len]
+    }
+
+    /// Handles a press of the "print screen" button.
+    ///
+    /// # Details
+    /// Dispatches the stats command as if it were typed, without
+    /// disturbing a line being entered. Does nothing at the login gate.
+    /// A press is charged to the command budget like any other command
+    /// and is refused once the budget is used up.
+    ///
+    /// # Returns
+    /// * `&[u8]` - Bytes to transmit
+    #[allow(dead_code)]
+    pub fn press_button(&mut self) -> &[u8] {
+        self.out_len = 0;
+        if self.mode != Mode::Login {
+            let _ = self.dispatch(b"stats");
+        }
+        &self.out[..self.out_len]
+    }
+
+    /// Returns whether a transcript export is in progress.
+    ///
+    /// # Returns
+    /// * `bool` - True until the last export chunk has been produced
+    #[allow(dead_code)]
+    pub fn export_pending(&self) -> bool {
+        self.export_pos.is_some()
+    }
+
+    /// Produces the next chunk of a transcript export.
+    ///
+    /// # Details
+    /// Hex-encodes up to EXPORT_CHUNK_SIZE transcript bytes as packed
+    /// digit pairs. The final chunk ends with CR LF and
+    /// finishes the export. The main loop calls this whenever the TX
+    /// queue has drained.
+    ///
+    /// # Returns
+    /// * `&[u8]` - Bytes to transmit; empty if no export is in progress
+    #[allow(dead_code)]
+    pub fn export_chunk(&mut self) -> &[u8] {
+        self.out_len = 0;
+        let Some(pos) = self.export_pos else {
+            return &self.out[..0];
+        };
+        let end = (pos + EXPORT_CHUNK_SIZE).min(self.export.len());
+        for offset in pos..end {
+            if let Some(byte) = self.export.get(offset) {
+                self.emit(&byte_to_hex(byte, self.settings.hex_uppercase));
+            }
+        }
+        if end == self.export.len() {
+            self.emit(b"\r\n");
+            self.export_pos = None;
+        } else {
+            self.export_pos = Some(end);
+        }
+        &self.out[..self.out_len]
+    }
+
+    /// Returns whether a test pattern is being streamed.
+    ///
+    /// # Returns
+    /// * `bool` - True until the last pattern chunk has been produced
+    #[allow(dead_code)]
+    pub fn pattern_pending(&self) -> bool {
+        self.pattern_remaining > 0
+    }
+
+    /// Produces the next chunk of the PRBS-7 test pattern.
+    ///
+    /// # Details
+    /// Emits up to PATTERN_CHUNK_SIZE raw pattern bytes. The main loop
+    /// calls this whenever the TX queue has drained.
+    ///
+    /// # Returns
+    /// * `&[u8]` - Bytes to transmit; empty if no pattern is in progress
+    #[allow(dead_code)]
+    pub fn pattern_chunk(&mut self) -> &[u8] {
+        self.out_len = 0;
+        let count = self.pattern_remaining.min(PATTERN_CHUNK_SIZE as u32);
+        let mut chunk = [0u8; PATTERN_CHUNK_SIZE];
+        for byte in &mut chunk[..count as usize] {
+            *byte = self.pattern.next_byte();
+        }
+        self.pattern_remaining -= count;
+        self.emit(&chunk[..count as usize]);
+        &self.out[..self.out_len]
+    }
+
+    /// Formats the UART flag register for the status command.
+    ///
+    /// # Arguments
+    /// * `flags` - Raw UARTFR register value
+    ///
+    /// # Returns
+    /// * `&[u8]` - Bytes to transmit
+    #[allow(dead_code)]
+    pub fn uart_status(&mut self, flags: u32) -> &[u8] {
+        self.out_len = 0;
+        let mut buf = [0u8; 64];
+        let n = format_uart_flags(flags, &mut buf);
+        self.emit(&buf[..n]);
+        self.emit(b"\r\n");
+        &self.out[..self.out_len]
+    }
+
+    /// Enables or disables the per-line timing report.
+    ///
+    /// # Details
+    /// When enabled, each committed echo-mode line is flagged for the
+    /// main loop, which follows it with `[+NNNms]`.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to report line intervals
+    #[allow(dead_code)]
+    pub fn set_line_timing(&mut self, enabled: bool) {
+        self.line_timing = enabled;
+    }
+
+    /// Returns whether the per-line timing report is enabled.
+    ///
+    /// # Returns
+    /// * `bool` - True if line intervals are reported
+    #[allow(dead_code)]
+    pub fn line_timing(&self) -> bool {
+        self.line_timing
+    }
+
+    /// Enables or disables timestamped line echo.
+    ///
+    /// # Details
+    /// For logging. When enabled, each committed echo-mode line is
+    /// flagged for the main loop, which echoes it again prefixed with
+    /// `[NNNNNNNN]` milliseconds.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to timestamp committed lines
+    #[allow(dead_code)]
+    pub fn set_timestamp_lines(&mut self, enabled: bool) {
+        self.settings.timestamp_lines = enabled;
+    }
+
+    /// Returns whether timestamped line echo is enabled.
+    ///
+    /// # Returns
+    /// * `bool` - True if committed lines are timestamped
+    #[allow(dead_code)]
+    pub fn timestamp_lines(&self) -> bool {
+        self.settings.timestamp_lines
+    }
+
+    /// Formats the last committed line with a timestamp prefix.
+    ///
+    /// # Arguments
+    /// * `now` - Current time in milliseconds
+    ///
+    /// # Returns
+    /// * `&[u8]` - Bytes to transmit, e.g. `[00012345] line` and CRLF
+    #[allow(dead_code)]
+    pub fn timestamped_line(&mut self, now: u64) -> &[u8] {
+        self.out_len = 0;
+        let mut msg = [0u8; 16];
+        let n = format_timestamp(now, &mut msg);
+        self.emit(&msg[..n]);
+        let line = self.last_line;
+        self.emit(&line[..self.last_len]);
+        self.emit(b"\r\n");
+        &self.out[..self.out_len]
+    }
+
+    /// Returns and clears the timed line commit flag.
+    ///
+    /// # Returns
+    /// * `bool` - True if a line committed since the last call
+    #[allow(dead_code)]
+    pub fn take_line_committed(&mut self) -> bool {
+        core::mem::take(&mut self.line_committed)
+    }
+
+    /// Returns the time since the previous timed line.
+    ///
+    /// # Arguments
+    /// * `now` - Current time in milliseconds
+    ///
+    /// # Returns
+    /// * `u64` - Milliseconds since the previous line, 0 for the first
+    #[allow(dead_code)]
+    pub fn line_interval_ms(&self, now: u64) -> u64 {
+        match self.last_commit_ms {
+            Some(last) => now.saturating_sub(last),
+            None => 0,
+        }
+    }
+
+    /// Formats the timing report for a committed line.
+    ///
+    /// # Details
+    /// Emits `[+NNNms]` and CRLF, then records `now` as the previous
+    /// commit time.
+    ///
+    /// # Arguments
+    /// * `now` - Current time in milliseconds
+    ///
+    /// # Returns
+    /// * `&[u8]` - Bytes to transmit
+    #[allow(dead_code)]
+    pub fn line_timing_report(&mut self, now: u64) -> &[u8] {
+        self.out_len = 0;
+        let mut msg = [0u8; 32];
+        let mut text = FmtBuf::new(&mut msg);
+        let _ = write!(text, "[+{}ms]\r\n", self.line_interval_ms(now));
+        let len = text.len();
+        self.emit(&msg[..len]);
+        self.last_commit_ms = Some(now);
+        &self.out[..self.out_len]
+    }
+
+    /// Returns the most recently committed echo-mode line.
+    ///
+    /// # Returns
+    /// * `&[u8]` - Last committed line, empty before the first commit
+    #[allow(dead_code)]
+    pub fn last_line(&self) -> &[u8] {
+        &self.last_line[..self.last_len]
+    }
+
+    /// Enables or disables paragraph mode.
+    ///
+    /// # Details
+    /// In paragraph mode Enter inserts a newline into the buffer and
+    /// two consecutive Enters commit the whole multi-line paragraph.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to commit on a blank line only
+    #[allow(dead_code)]
+    pub fn set_paragraph_mode(&mut self, enabled: bool) {
+        self.paragraph_mode = enabled;
+    }
+
+    /// Returns whether paragraph mode is enabled.
+    ///
+    /// # Returns
+    /// * `bool` - True if a blank line is required to commit
+    #[allow(dead_code)]
+    pub fn paragraph_mode(&self) -> bool {
+        self.paragraph_mode
+    }
+
+    /// Services the controller when no byte has arrived for a tick.
+    ///
+    /// # Details
+    /// Resolves an ESC that was not followed by a sequence into an ESC
+    /// keypress. Called by the main loop on its idle timeout.
+    ///
+    /// # Returns
+    /// * `&[u8]` - Bytes to transmit, possibly empty
+    #[allow(dead_code)]
+    pub fn idle(&mut self) -> &[u8] {
+        self.out_len = 0;
+        if self.escape.expire() {
+            self.escape_key();
+        }
+        &self.out[..self.out_len]
+    }
+
+    /// Caps the number of bytes echoed per session.
+    ///
+    /// # Details
+    /// Once the budget is used up the next byte prints "limit reached"
+    /// and further input is dropped until reset(). None removes the cap.
+    ///
+    /// # Arguments
+    /// * `budget` - Maximum bytes per session, or None for unlimited
+    #[allow(dead_code)]
+    pub fn set_byte_budget(&mut self, budget: Option<u64>) {
+        self.byte_budget = budget;
+    }
+
+    /// Returns the session byte budget.
+    ///
+    /// # Returns
+    /// * `Option<u64>` - Maximum bytes per session, or None if unlimited
+    #[allow(dead_code)]
+    pub fn byte_budget(&self) -> Option<u64> {
+        self.byte_budget
+    }
+
+    /// Caps the number of commands executed per session.
+    ///
+    /// # Details
+    /// Once the budget is used up further commands print "command
+    /// limit reached" and are refused until reset(). A replayed line
+    /// counts, but the `again` that replays it does not. None removes
+    /// the cap.
+    ///
+    /// # Arguments
+    /// * `budget` - Maximum commands per session, or None for unlimited
+    #[allow(dead_code)]
+    pub fn set_command_budget(&mut self, budget: Option<u32>) {
+        self.command_budget = budget;
+    }
+
+    /// Returns the session command budget.
+    ///
+    /// # Returns
+    /// * `Option<u32>` - Maximum commands per session, or None if unlimited
+    #[allow(dead_code)]
+    pub fn command_budget(&self) -> Option<u32> {
+        self.command_budget
+    }
+
+    /// Returns the number of commands executed this session.
+    ///
+    /// # Returns
+    /// * `u32` - Commands run since the last reset()
+    #[allow(dead_code)]
+    pub fn commands_run(&self) -> u32 {
+        self.commands_run
+    }
+
+    /// Starts a new session.
+    ///
+    /// # Details
+    /// Clears the session byte and command counts so echoing and
+    /// commands resume after a budget was exhausted. Settings are kept.
+    #[allow(dead_code)]
+    pub fn reset(&mut self) {
+        self.session_bytes = 0;
+        self.budget_exhausted = false;
+        self.commands_run = 0;
+    }
+
+    /// Enables or disables the loopback echo guard.
+    ///
+    /// # Details
+    /// For self-test with TX wired to RX. While enabled, every emitted
+    /// byte is remembered in a RECENT_TX_SIZE window and a received byte
+    /// found there is swallowed instead of echoed again. Disabling
+    /// forgets the window.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to suppress looped-back bytes
+    #[allow(dead_code)]
+    pub fn set_loopback_guard(&mut self, enabled: bool) {
+        self.loopback_guard = enabled;
+        self.recent_tx.clear();
+    }
+
+    /// Returns whether the loopback echo guard is enabled.
+    ///
+    /// # Returns
+    /// * `bool` - True if looped-back bytes are suppressed
+    #[allow(dead_code)]
+    pub fn loopback_guard(&self) -> bool {
+        self.loopback_guard
+    }
+
+    /// Enables or disables the in-band soft reset sequence.
+    ///
+    /// # Details
+    /// For automated test rigs. While enabled, `ESC R` sent twice back
+    /// to back requests a reset like a confirmed RESET command. A byte
+    /// or idle tick between the two disarms it, and login and monitor
+    /// sessions ignore it.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to honor the soft reset sequence
+    #[allow(dead_code)]
+    pub fn set_escape_reset(&mut self, enabled: bool) {
+        self.escape_reset = enabled;
+    }
+
+    /// Returns whether the in-band soft reset sequence is enabled.
+    ///
+    /// # Returns
+    /// * `bool` - True if `ESC R ESC R` requests a reset
+    #[allow(dead_code)]
+    pub fn escape_reset(&self) -> bool {
+        self.escape_reset
+    }
+
+    /// Enables or disables the fault command.
+    ///
+    /// # Details
+    /// Gate for host error-handling tests. Disabling also disarms a
+    /// pending fault.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to allow the fault command
+    #[allow(dead_code)]
+    pub fn set_fault_injection(&mut self, enabled: bool) {
+        self.fault_injection = enabled;
+        self.pending_fault &= enabled;
+    }
+
+    /// Returns whether the fault command is allowed.
+    ///
+    /// # Returns
+    /// * `bool` - True if faults can be armed
+    #[allow(dead_code)]
+    pub fn fault_injection(&self) -> bool {
+        self.fault_injection
+    }
+
+    /// Returns whether a fault is armed for the next response.
+    ///
+    /// # Returns
+    /// * `bool` - True if the next non-empty response will be corrupted
+    #[allow(dead_code)]
+    pub fn fault_pending(&self) -> bool {
+        self.pending_fault
+    }
+
+    /// Enables or disables holding input while command output is sent.
+    ///
+    /// # Details
+    /// When enabled, running a command marks output as pending and
+    /// characters typed meanwhile are queued instead of interleaving.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to hold input during command output
+    #[allow(dead_code)]
+    pub fn set_hold_input(&mut self, enabled: bool) {
+        self.hold_input = enabled;
+    }
+
+    /// Returns whether input is held during command output.
+    ///
+    /// # Returns
+    /// * `bool` - True if input holding is enabled
+    #[allow(dead_code)]
+    pub fn hold_input(&self) -> bool {
+        self.hold_input
+    }
+
+    /// Marks command output as pending or complete.
+    ///
+    /// # Details
+    /// While pending, received characters are queued. Clearing the flag
+    /// here does not replay them; use finish_output() for that.
+    ///
+    /// # Arguments
+    /// * `pending` - True while output is still being transmitted
+    #[allow(dead_code)]
+    pub fn set_output_pending(&mut self, pending: bool) {
+        self.output_pending = pending;
+    }
+
+    /// Returns whether command output is still pending.
+    ///
+    /// # Returns
+    /// * `bool` - True if input is currently being queued
+    #[allow(dead_code)]
+    pub fn output_pending(&self) -> bool {
+        self.output_pending
+    }
+
+    /// Returns the characters queued while output was pending.
+    ///
+    /// # Returns
+    /// * `&[u8]` - Queued input in arrival order
+    #[allow(dead_code)]
+    pub fn queued_input(&self) -> &[u8] {
+        &self.input_buffer[..self.input_len]
+    }
+
+    /// Ends pending output and replays queued input.
+    ///
+    /// # Details
+    /// Called once command output has been transmitted. Queued characters
+    /// are processed in order; if one of them runs another command that
+    /// holds input again, the rest stay queued.
+    ///
+    /// # Returns
+    /// * `&[u8]` - Combined response to the replayed characters
+    #[allow(dead_code)]
+    pub fn finish_output(&mut self) -> &[u8] {
+        self.out_len = 0;
+        self.output_pending = false;
+        let mut replayed = 0;
+        while replayed < self.input_len && !self.output_pending {
+            let ch = self.input_buffer[replayed];
+            replayed += 1;
+            let _ = self.handle(ch);
+        }
+        self.input_buffer.copy_within(replayed..self.input_len, 0);
+        self.input_len -= replayed;
+        &self.out[..self.out_len]
+    }
+
+    /// Accepts one received character.
+    ///
+    /// # Details
+    /// Queues the character while command output is pending,
+    /// otherwise handles it immediately. A decoded run-length run is
+    /// accepted in full even if one of its bytes is rejected.
+    ///
+    /// # Arguments
+    /// * `ch` - The character received
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or the first reason a byte was rejected
+    fn receive(&mut self, ch: u8) -> Result<(), UartError> {
+        if self.loopback_guard && self.recent_tx.take(ch) {
+            return Ok(());
+        }
+        if !self.parity.check(ch) {
+            self.parity_errors += 1;
+            if self.parity_drop {
+                return Err(UartError::ParityError);
+            }
+        }
+        let ch = self.parity.strip(ch);
+        let ch = match self.encoding {
+            Encoding::Ascii => ch,
+            Encoding::Ebcdic => ebcdic::to_ascii(ch),
+        };
+        let ch = match self.telnet_event(ch) {
+            Some(ch) => ch,
+            None => return Ok(()),
+        };
+        if self.crc_framing {
+            return self.frame_char(ch);
+        }
+        if !self.rle_input {
+            return self.accept(ch);
+        }
+        let mut result = Ok(());
+        if self.rle.feed(ch) {
+            let mut chunk = [0u8; RLE_CHUNK_SIZE];
+            loop {
+                let len = self.rle.drain(&mut chunk);
+                if len == 0 {
+                    break;
+                }
+                for &b in &chunk[..len] {
+                    let accepted = self.accept(b);
+                    if result.is_ok() {
+                        result = accepted;
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Accepts a decoded input byte for processing.
+    ///
+    /// # Details
+    /// Applies smart punctuation, sanitizing, and the byte budget, then
+    /// handles the byte or queues it behind pending output. Budget
+    /// exhaustion is logged once per session, with its notice.
+    ///
+    /// # Arguments
+    /// * `ch` - The decoded byte
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or the first error encountered
+    fn accept(&mut self, ch: u8) -> Result<(), UartError> {
+        let ch = if self.smart_punctuation {
+            match self.utf8.feed(ch) {
+                Utf8Event::Ascii(ch) => ch,
+                Utf8Event::Pending => return Ok(()),
+                Utf8Event::Char(code) => match utf8::normalize_punctuation(code) {
+                    Some(ch) => ch,
+                    None => return Err(UartError::UnsupportedByte(ch)),
+                },
+                Utf8Event::Invalid => return Err(UartError::UnsupportedByte(ch)),
+            }
+        } else {
+            ch
+        };
+        if self.sanitize_input && self.is_noise(ch) {
+            self.noise_count += 1;
+            return Ok(());
+        }
+        if self
+            .byte_budget
+            .is_some_and(|budget| self.session_bytes >= budget)
+        {
+            if !self.budget_exhausted {
+                self.budget_exhausted = true;
+                self.emit(b"limit reached\r\n");
+                self.errors.record(UartError::BudgetExhausted, self.now_ms);
+            }
+            return Err(UartError::BudgetExhausted);
+        }
+        self.session_bytes += 1;
+        if !self.output_pending {
+            return self.handle(ch);
+        }
+        if self.input_len == INPUT_BUF_SIZE {
+            return Err(UartError::InputOverflow);
+        }
+        self.input_buffer[self.input_len] = ch;
+        self.input_len += 1;
+        Ok(())
+    }
+
+    /// Runs a byte through telnet negotiation when enabled.
+    ///
+    /// # Details
+    /// Negotiation replies are emitted directly. Command bytes are
+    /// consumed and never echoed as text.
+    ///
+    /// # Arguments
+    /// * `ch` - The byte received
+    ///
+    /// # Returns
+    /// * `Option<u8>` - Data byte to process, or None if consumed
+    fn telnet_event(&mut self, ch: u8) -> Option<u8> {
+        if !self.telnet {
+            return Some(ch);
+        }
+        match self.telnet_parser.feed(ch) {
+            TelnetEvent::NotTelnet => Some(ch),
+            TelnetEvent::Data(data) => Some(data),
+            TelnetEvent::Reply(reply) => {
+                self.emit(&reply);
+                None
+            }
+            TelnetEvent::Pending | TelnetEvent::Consumed => None,
+        }
+    }
+
+    /// Feeds a byte to the CRC frame decoder.
+    ///
+    /// # Details
+    /// A valid frame echoes its payload followed by ACK; a corrupt
+    /// frame echoes only NAK. Partial frames echo nothing.
+    ///
+    /// # Arguments
+    /// * `ch` - The byte received
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or CorruptFrame on a CRC mismatch
+    fn frame_char(&mut self, ch: u8) -> Result<(), UartError> {
+        match self.frame.feed(ch) {
+            FrameEvent::Pending => Ok(()),
+            FrameEvent::Valid => {
+                let frame = self.frame;
+                self.emit(frame.payload());
+                self.emit(&[ACK]);
+                Ok(())
+            }
+            FrameEvent::Corrupt => {
+                self.emit(&[NAK]);
+                Err(UartError::CorruptFrame)
+            }
+        }
+    }
+
+    /// Checks whether a byte is a stray control character.
+    ///
+    /// # Details
+    /// C0 controls are noise except backspace, tab, CR, LF, ESC, and,
+    /// with form control enabled, VT and FF, or with the toggle key
+    /// enabled, Ctrl-O.
+    ///
+    /// # Arguments
+    /// * `ch` - The character received
+    ///
+    /// # Returns
+    /// * `bool` - True if the byte should be dropped as noise
+    fn is_noise(&self, ch: u8) -> bool {
+        match ch {
+            BACKSPACE | b'\t' | b'\r' | b'\n' | ESCAPE => false,
+            VERTICAL_TAB | FORM_FEED => !self.settings.form_control,
+            CTRL_O => !self.echo_toggle_key,
+            _ => ch < 0x20,
+        }
+    }
+
+    /// Runs one received character through the controller.
+    ///
+    /// # Details
+    /// Counts the character and routes it through the escape parser
+    /// before the mode-specific handler, appending to the response.
+    ///
+    /// # Arguments
+    /// * `ch` - The character received
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or the reason the byte was rejected
+    fn handle(&mut self, ch: u8) -> Result<(), UartError> {
+        self.echo_count += 1;
+        if let Some(cmd) = self.pending_confirmation {
+            if ch == b'\n' && self.skip_lf {
+                self.skip_lf = false;
+                return Ok(());
+            }
+            self.pending_confirmation = None;
+            return self.confirm(cmd, ch);
+        }
+        match self.escape.feed(ch) {
+            EscapeEvent::NotEscape => {}
+            EscapeEvent::LoneEscape => self.escape_key(),
+            EscapeEvent::CursorPositionRequest => {
+                self.report_cursor();
+                return Ok(());
+            }
+            EscapeEvent::SoftReset if self.soft_reset_allowed() => {
+                self.clear_line();
+                self.set_mode(Mode::Echo);
+                self.emit(b"\r\n");
+                self.cmd_reset();
+                return Ok(());
+            }
+            EscapeEvent::SoftReset => self.escape_key(),
+            EscapeEvent::Pending | EscapeEvent::Unrecognized => return Ok(()),
+        }
+        if ch == b'\n' && self.skip_lf {
+            self.skip_lf = false;
+            return Ok(());
+        }
+        self.skip_lf = false;
+        if ch == CTRL_O && self.echo_toggle_key && self.mode == Mode::Echo {
+            if self.privilege == Privilege::Monitor {
+                self.emit(b"(permission denied)");
+                return Err(UartError::PermissionDenied);
+            }
+            self.settings.echo_enabled = !self.settings.echo_enabled;
+            self.emit(match self.settings.echo_enabled {
+                true => b"(echo on)",
+                false => b"(echo off)",
+            });
+            return Ok(());
+        }
+        let result = match self.mode {
+            _ if ch == b'\r' || ch == b'\n' => self.on_enter(ch),
+            Mode::Echo => self.echo_char(ch),
+            Mode::Command => self.command_char(ch),
+            Mode::Menu => self.menu_char(ch),
+            Mode::Login => self.login_char(ch),
+        };
+        self.prev_cr = ch == b'\r';
+        result
+    }
+
+    /// Handles Enter according to the input mode.
+    ///
+    /// # Details
+    /// In echo mode Enter commits the line and echoes the newline
+    /// mode's line ending. With line buffering off, as in the raw
+    /// terminal discipline, there is no line to commit and the CR or LF
+    /// is echoed literally. In command mode Enter is not echoed; the
+    /// line is dispatched and its output starts on a fresh line. The
+    /// menu and login gate handle Enter themselves.
+    ///
+    /// # Arguments
+    /// * `ch` - CR or LF as received
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Result of the mode's Enter handling
+    fn on_enter(&mut self, ch: u8) -> Result<(), UartError> {
+        match self.mode {
+            Mode::Echo if !self.settings.line_buffering && !self.settings.hex_input => {
+                self.transcript.push(ch);
+                self.prev_space = false;
+                if self.settings.echo_enabled && !self.muted {
+                    self.emit(&[ch]);
+                }
+                Ok(())
+            }
+            Mode::Echo => self.echo_char(ch),
+            Mode::Command => {
+                self.skip_lf = ch == b'\r';
+                self.run_command()
+            }
+            Mode::Menu => self.menu_char(ch),
+            Mode::Login => self.login_char(ch),
+        }
+    }
+
+    /// Handles a character in command mode.
+    ///
+    /// # Details
+    /// Printable characters build the command line and Enter runs it.
+    /// Characters past the command length limit ring the bell.
+    ///
+    /// # Arguments
+    /// * `ch` - The character received
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or the reason the byte was rejected
+    fn command_char(&mut self, ch: u8) -> Result<(), UartError> {
+        match ch {
+            BACKSPACE | DELETE => {
+                if self.line_len > 0 {
+                    self.line_len -= 1;
+                    self.emit(&BACKSPACE_SEQ);
+                }
+                Ok(())
+            }
+            0x20..=0x7E if self.line_len >= self.max_cmd_len => {
+                self.ring_bell();
+                Err(UartError::LineOverflow)
+            }
+            0x20..=0x7E => {
+                self.push_line(ch)?;
+                self.emit(&[ch]);
+                Ok(())
+            }
+            _ => Err(UartError::UnsupportedByte(ch)),
+        }
+    }
+
+    /// Handles a character at the login gate.
+    ///
+    /// # Details
+    /// Password characters are buffered without echo and Enter checks
+    /// them. Success greets the new session and enters echo mode; the
+    /// monitor password starts a read-only session.
+    ///
+    /// # Arguments
+    /// * `ch` - The character received
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or InvalidArgument on a wrong password
+    fn login_char(&mut self, ch: u8) -> Result<(), UartError> {
+        match ch {
+            BACKSPACE | DELETE => {
+                self.line_len = self.line_len.saturating_sub(1);
+                Ok(())
+            }
+            b'\r' | b'\n' => {
+                self.skip_lf = ch == b'\r';
+                let entered = Some(&self.line[..self.line_len]);
+                let privilege = if self.password == entered {
+                    Privilege::Admin
+                } else if self.monitor_password == entered {
+                    Privilege::Monitor
+                } else {
+                    self.clear_line();
+                    self.emit(b"login incorrect\r\n");
+                    return Err(UartError::InvalidArgument);
+                };
+                self.line_len = 0;
+                self.privilege = privilege;
+                self.session_count = self.session_count.saturating_add(1);
+                self.saved_len = 0;
+                self.last_len = 0;
+                self.pending_newline = false;
+                self.set_mode(Mode::Echo);
+                self.emit_greeting();
+                Ok(())
+            }
+            0x20..=0x7E => self.push_line(ch),
+            _ => Err(UartError::UnsupportedByte(ch)),
+        }
+    }
+
+    /// Writes the greeting as `greeting, session #N` and CRLF.
+    fn emit_greeting(&mut self) {
+        let greeting = self.greeting;
+        self.emit(greeting);
+        let mut msg = [0u8; 24];
+        let mut text = FmtBuf::new(&mut msg);
+        let _ = write!(text, ", session #{}\r\n", self.session_count);
+        let len = text.len();
+        self.emit(&msg[..len]);
+    }
+
+    /// Appends a character to the line buffer.
+    ///
+    /// # Details
+    /// Rings the bell instead when the buffer is full.
+    ///
+    /// # Arguments
+    /// * `ch` - The character to buffer
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or LineOverflow if full
+    fn push_line(&mut self, ch: u8) -> Result<(), UartError> {
+        self.pending_newline = false;
+        if self.line_len == LINE_CAP {
+            self.ring_bell();
+            let line = self.line;
+            self.callbacks.fire(Event::Overflow, &line);
+            return Err(UartError::LineOverflow);
+        }
+        let prev = match self.line_len {
+            0 => b' ',
+            n => self.line[n - 1],
+        };
+        self.transformed[self.line_len] = transform::apply_case(ch, self.pipeline_case, prev);
+        self.line[self.line_len] = ch;
+        self.line_len += 1;
+        Ok(())
+    }
+
+    /// Empties the line buffer without committing it.
+    ///
+    /// # Details
+    /// Also forgets a buffered paragraph newline, which would otherwise
+    /// be stripped from the next line.
+    fn clear_line(&mut self) {
+        self.line_len = 0;
+        self.pending_newline = false;
+    }
+
+    /// Returns whether the soft reset sequence may reset the device.
+    ///
+    /// # Returns
+    /// * `bool` - True if enabled outside login and monitor sessions
+    fn soft_reset_allowed(&self) -> bool {
+        self.escape_reset && self.mode != Mode::Login && self.privilege == Privilege::Admin
+    }
+
+    /// Acts on an ESC keypress.
+    ///
+    /// # Details
+    /// In echo mode, saves the partial line for `dump`, clears the line
+    /// buffer, and shows the command prompt on a fresh line. In command
+    /// mode, discards the partial command and returns to echo mode. In
+    /// the settings menu, closes the menu.
+    fn escape_key(&mut self) {
+        if self.mode == Mode::Menu {
+            self.menu_action(MenuAction::Exit);
+        } else if self.mode == Mode::Command && self.command_only {
+            self.clear_line();
+            self.emit(b"\r\n");
+            self.emit_prompt();
+        } else if self.mode == Mode::Command {
+            self.clear_line();
+            self.set_mode(Mode::Echo);
+            self.emit(b"\r\n");
+        } else if self.mode == Mode::Echo {
+            if self.settings.named_controls {
+                self.emit(b"<ESC>");
+            }
+            self.saved_line = self.line;
+            self.saved_len = self.line_len;
+            self.clear_line();
+            self.set_mode(Mode::Command);
+            self.emit(b"\r\n");
+            self.emit_prompt();
+        }
+    }
+
+    /// Emits the command prompt.
+    fn emit_prompt(&mut self) {
+        let mut prompt = [0u8; PROMPT_BUF_SIZE];
+        let n = self.prompt(&mut prompt);
+        self.emit(&prompt[..n]);
+    }
+
+    /// Returns to the command prompt in command-only operation.
+    ///
+    /// # Details
+    /// Does nothing unless command-only is set and the controller is
+    /// back in echo mode, so commands that open the menu keep it open.
+    fn resume_command_only(&mut self) {
+        if self.command_only && self.mode == Mode::Echo {
+            self.set_mode(Mode::Command);
+            self.emit_prompt();
+        }
+    }
+
+    /// Runs the buffered command line and returns to echo mode.
+    ///
+    /// # Details
+    /// An empty line runs nothing. Every other line except `again`
+    /// itself is remembered for replay. In command-only operation the
+    /// prompt is shown again instead of returning to echo mode.
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or UnknownCommand
+    fn run_command(&mut self) -> Result<(), UartError> {
+        let line = self.line;
+        let len = self.line_len;
+        self.clear_line();
+        self.set_mode(Mode::Echo);
+        self.emit(b"\r\n");
+        if command::trim(&line[..len]).is_empty() {
+            self.resume_command_only();
+            return Ok(());
+        }
+        self.output_pending = self.hold_input;
+        if command::parse_folded(&line[..len], self.fold_command_case) != Some(Command::Again) {
+            self.last_cmd = line;
+            self.last_cmd_len = len;
+        }
+        let result = self.dispatch(&line[..len]);
+        if self.settings.status_markers {
+            let mut msg = [0u8; 16];
+            let n = result.marker(&mut msg);
+            self.emit(&msg[..n]);
+        }
+        self.resume_command_only();
+        result.into_result()
+    }
+
+    /// Parses and executes a command line.
+    ///
+    /// # Details
+    /// Unknown commands print a message.
+    ///
+    /// # Arguments
+    /// * `line` - Command line bytes
+    ///
+    /// # Returns
+    /// * `CmdResult` - Outcome of the command
+    fn dispatch(&mut self, line: &[u8]) -> CmdResult {
+        match command::parse_folded(line, self.fold_command_case) {
+            Some(cmd) if cmd != Command::Again && self.command_budget_used() => {
+                self.emit(b"command limit reached\r\n");
+                CmdResult::Error(UartError::BudgetExhausted)
+            }
+            Some(cmd) => {
+                if cmd != Command::Again {
+                    self.commands_run = self.commands_run.saturating_add(1);
+                }
+                self.callbacks
+                    .fire(Event::CommandRecognized, command::trim(line));
+                self.command_executed = true;
+                CmdResult::from(self.execute(cmd, command::split(line).1))
+            }
+            None => {
+                self.emit(b"unknown command\r\n");
+                CmdResult::NotFound
+            }
+        }
+    }
+
+    /// Switches the input mode.
+    ///
+    /// # Details
+    /// Fires the mode-changed callback when the mode actually changes.
+    ///
+    /// # Arguments
+    /// * `mode` - New input mode
+    fn set_mode(&mut self, mode: Mode) {
+        if self.mode != mode {
+            self.mode = mode;
+            self.callbacks.fire(Event::ModeChanged, mode.label());
+        }
+    }
+
+    /// Checks whether the session command budget is used up.
+    ///
+    /// # Returns
+    /// * `bool` - True if no further commands may run this session
+    fn command_budget_used(&self) -> bool {
+        self.command_budget
+            .is_some_and(|budget| self.commands_run >= budget)
+    }
+
+    /// Executes a recognized command.
+    ///
+    /// # Details
+    /// With the interlock enabled, a destructive command is held and a
+    /// confirmation prompt emitted instead; see confirm(). A baud rate
+    /// is validated and staged first, so a bad rate never prompts.
+    ///
+    /// # Arguments
+    /// * `cmd` - Command to run
+    /// * `args` - Argument text following the command name
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, InvalidArgument, or PermissionDenied
+    fn execute(&mut self, cmd: Command, args: &[u8]) -> Result<(), UartError> {
+        if self.privilege == Privilege::Monitor && cmd.is_privileged() {
+            self.emit(b"permission denied\r\n");
+            return Err(UartError::PermissionDenied);
+        }
+        if cmd == Command::Baud {
+            self.staged_baud = self.parse_baud(args)?;
+        }
+        if self.interlock && cmd.is_destructive() {
+            self.emit(b"confirm (y/n)? ");
+            self.pending_confirmation = Some(cmd);
+            return Ok(());
+        }
+        self.perform(cmd, args)
+    }
+
+    /// Answers a pending confirmation prompt.
+    ///
+    /// # Details
+    /// The key is echoed. y or Y runs the held command; any other key
+    /// cancels it.
+    ///
+    /// # Arguments
+    /// * `cmd` - Command awaiting confirmation
+    /// * `ch` - The key received
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Result of the command, or Ok if cancelled
+    fn confirm(&mut self, cmd: Command, ch: u8) -> Result<(), UartError> {
+        if ch.is_ascii_graphic() {
+            self.emit(&[ch]);
+        }
+        self.emit(b"\r\n");
+        if ch.eq_ignore_ascii_case(&b'y') {
+            return self.perform(cmd, &[]);
+        }
+        self.emit(b"cancelled\r\n");
+        Ok(())
+    }
+
+    /// Runs a command whose access checks have passed.
+    ///
+    /// # Arguments
+    /// * `cmd` - Command to run
+    /// * `args` - Argument text following the command name
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or InvalidArgument
+    fn perform(&mut self, cmd: Command, args: &[u8]) -> Result<(), UartError> {
+        match cmd {
+            Command::Dump => self.cmd_dump(),
+            Command::Reset => self.cmd_reset(),
+            Command::Bootsel => self.cmd_bootsel(),
+            Command::Baud => self.cmd_baud(),
+            Command::Delay => return self.cmd_delay(args),
+            Command::Status => self.status_requested = true,
+            Command::Rate => self.rate_requested = true,
+            Command::Mem => self.mem_requested = true,
+            Command::FlashInfo => self.flash_info_requested = true,
+            Command::Errors => self.cmd_errors(),
+            Command::Stats => self.cmd_stats(),
+            Command::Logo => self.cmd_logo(),
+            Command::Clear => return self.cmd_clear(args),
+            Command::Pattern => return self.cmd_pattern(args),
+            Command::Again => return self.cmd_again(),
+            Command::Export => self.cmd_export(),
+            Command::Profile => return self.cmd_profile(args),
+            Command::IndentUp => self.cmd_indent(self.settings.indent.saturating_add(1)),
+            Command::IndentDown => self.cmd_indent(self.settings.indent.saturating_sub(1)),
+            Command::Menu => self.cmd_menu(),
+            Command::Quality => self.cmd_quality(),
+            Command::Fault => return self.cmd_fault(),
+            Command::Case => return self.cmd_case(args),
+            Command::Sweep => return self.cmd_sweep(args),
+            Command::Name => return self.cmd_name(args),
+            Command::Calc => return self.cmd_calc(args),
+            Command::Find => return self.cmd_find(args),
+            Command::Base64 => return self.cmd_base64(args),
+        }
+        Ok(())
+    }
+
+    /// Prints the pre-command line buffer as space-separated hex.
+    ///
+    /// # Details
+    /// LINE_CAP_FITS guarantees a full line's dump fits the response.
+    fn cmd_dump(&mut self) {
+        let saved = self.saved_line;
+        let mut buf = [0u8; ECHO_BUF_SIZE];
+        let n = hex_dump(
+            &saved[..self.saved_len],
+            self.settings.hex_uppercase,
+            &mut buf,
+        );
+        self.emit(&buf[..n]);
+        self.emit(b"\r\n");
+    }
+
+    /// Opens the settings menu.
+    ///
+    /// # Details
+    /// Lists the numbered options with their current values and
+    /// switches to menu mode.
+    fn cmd_menu(&mut self) {
+        self.set_mode(Mode::Menu);
+        self.emit(b"1) ");
+        self.emit_setting(MenuAction::ToggleEcho);
+        self.emit(b"2) ");
+        self.emit_setting(MenuAction::CycleCase);
+        self.emit(b"3) ");
+        self.emit_setting(MenuAction::CycleNewline);
+        self.emit(b"q) quit\r\n");
+    }
+
+    /// Handles a keypress in the settings menu.
+    ///
+    /// # Details
+    /// Numbered keys change a setting and echo its new value; `q`
+    /// closes the menu. Other keys ring the bell.
+    ///
+    /// # Arguments
+    /// * `ch` - The character received
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or InvalidArgument for other keys
+    fn menu_char(&mut self, ch: u8) -> Result<(), UartError> {
+        match menu::select(ch) {
+            Some(action) => {
+                self.menu_action(action);
+                Ok(())
+            }
+            None => {
+                self.ring_bell();
+                Err(UartError::InvalidArgument)
+            }
+        }
+    }
+
+    /// Applies a settings menu action.
+    ///
+    /// # Arguments
+    /// * `action` - Selected action
+    fn menu_action(&mut self, action: MenuAction) {
+        match action {
+            MenuAction::ToggleEcho => self.settings.echo_enabled = !self.settings.echo_enabled,
+            MenuAction::CycleCase => self.settings.case_mode = self.settings.case_mode.next(),
+            MenuAction::CycleNewline => {
+                self.settings.newline_mode = self.settings.newline_mode.next()
+            }
+            MenuAction::Exit => {
+                self.set_mode(Mode::Echo);
+                self.emit(b"menu closed\r\n");
+                return;
+            }
+        }
+        self.emit_setting(action);
+    }
+
+    /// Writes a menu setting as `name: value` and CRLF.
+    ///
+    /// # Arguments
+    /// * `action` - Action whose setting is shown
+    fn emit_setting(&mut self, action: MenuAction) {
+        match action {
+            MenuAction::ToggleEcho => {
+                self.emit(b"echo: ");
+                self.emit(if self.settings.echo_enabled {
+                    b"on"
+                } else {
+                    b"off"
+                });
+            }
+            MenuAction::CycleCase => {
+                self.emit(b"case: ");
+                self.emit(self.settings.case_mode.label());
+            }
+            MenuAction::CycleNewline => {
+                self.emit(b"newline: ");
+                self.emit(self.settings.newline_mode.label());
+            }
+            MenuAction::Exit => {}
+        }
+        self.emit(b"\r\n");
+    }
+
+    /// Reports the RX overrun rate per 1000 processed characters.
+    fn cmd_quality(&mut self) {
+        let rate = overrun_rate(self.rx_overruns, self.echo_count);
+        let mut msg = [0u8; 40];
+        let mut text = FmtBuf::new(&mut msg);
+        let _ = write!(text, "overrun rate: {} per mille\r\n", rate);
+        let len = text.len();
+        self.emit(&msg[..len]);
+    }
+
+    /// Sets the echo case mode and confirms the new value.
+    ///
+    /// # Arguments
+    /// * `args` - Mode name: upper, lower, asis, or title
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or InvalidArgument if unknown
+    fn cmd_case(&mut self, args: &[u8]) -> Result<(), UartError> {
+        match CaseMode::parse(args) {
+            Some(mode) => {
+                self.settings.case_mode = mode;
+                self.emit_setting(MenuAction::CycleCase);
+                Ok(())
+            }
+            None => {
+                self.emit(b"unknown case\r\n");
+                Err(UartError::InvalidArgument)
+            }
+        }
+    }
+
+    /// Prints every printable ASCII character on one line.
+    ///
+    /// # Details
+    /// Lines end with the newline mode's Enter echo, or CRLF when
+    /// newlines echo as received.
+    ///
+    /// # Arguments
+    /// * `args` - Optional repeat count, 1 to SWEEP_MAX_REPEAT
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or InvalidArgument for a bad count
+    fn cmd_sweep(&mut self, args: &[u8]) -> Result<(), UartError> {
+        let count = match args {
+            b"" => Some(1),
+            _ => core::str::from_utf8(args)
+                .ok()
+                .and_then(|text| text.parse::<usize>().ok()),
+        };
+        let count = match count {
+            Some(n @ 1..=SWEEP_MAX_REPEAT) => n,
+            _ => {
+                self.emit(b"invalid count\r\n");
+                return Err(UartError::InvalidArgument);
+            }
+        };
+        let newline = match self.settings.newline_mode {
+            NewlineMode::AsReceived => b"\r\n",
+            mode => mode.echo(b'\r'),
+        };
+        let mut buf = [0u8; 97];
+        let len = sweep(newline, &mut buf);
+        for _ in 0..count {
+            self.emit(&buf[..len]);
+        }
+        Ok(())
+    }
+
+    /// Sets the device name, or shows it when no argument is given.
+    ///
+    /// # Arguments
+    /// * `args` - New name, or empty to query
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or InvalidArgument if the name is rejected
+    fn cmd_name(&mut self, args: &[u8]) -> Result<(), UartError> {
+        if !args.is_empty() && self.set_name(args).is_err() {
+            self.emit(b"invalid name\r\n");
+            return Err(UartError::InvalidArgument);
+        }
+        let name = self.name;
+        self.emit(b"name: ");
+        self.emit(&name[..self.name_len]);
+        self.emit(b"\r\n");
+        Ok(())
+    }
+
+    /// Evaluates an integer expression and echoes the result.
+    ///
+    /// # Arguments
+    /// * `args` - Expression, e.g. `2+3*4`
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or InvalidArgument if evaluation failed
+    fn cmd_calc(&mut self, args: &[u8]) -> Result<(), UartError> {
+        match calc::evaluate(args) {
+            Ok(value) => {
+                let mut msg = [0u8; 24];
+                let mut text = FmtBuf::new(&mut msg);
+                let _ = write!(text, "{}\r\n", value);
+                let len = text.len();
+                self.emit(&msg[..len]);
+                Ok(())
+            }
+            Err(err) => {
+                self.emit(match err {
+                    CalcError::Overflow => b"overflow\r\n",
+                    CalcError::Empty | CalcError::Malformed => b"invalid expression\r\n",
+                });
+                Err(UartError::InvalidArgument)
+            }
+        }
+    }
+
+    /// Switches between settings profiles a and b.
+    ///
+    /// # Details
+    /// The current settings are saved into the active profile before
+    /// the other is restored. A profile never used before starts from
+    /// the default settings.
+    ///
+    /// # Arguments
+    /// * `args` - `a` or `b`
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or InvalidArgument if unknown
+    fn cmd_profile(&mut self, args: &[u8]) -> Result<(), UartError> {
+        let index = match args {
+            b"a" | b"A" => 0,
+            b"b" | b"B" => 1,
+            _ => {
+                self.emit(b"invalid profile\r\n");
+                return Err(UartError::InvalidArgument);
+            }
+        };
+        self.profiles[self.active_profile] = Some(self.snapshot());
+        let state = self.profiles[index].unwrap_or_default();
+        self.restore(&state);
+        self.active_profile = index;
+        self.emit(b"profile: ");
+        self.emit(&args[..1]);
+        self.emit(b"\r\n");
+        Ok(())
+    }
+
+    /// Echoes and re-runs the previous command line.
+    ///
+    /// # Details
+    /// Does nothing if no command has run yet. `again` is never
+    /// remembered, so a replay cannot recurse.
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Result of the replayed command
+    fn cmd_again(&mut self) -> Result<(), UartError> {
+        if self.last_cmd_len == 0 {
+            return Ok(());
+        }
+        let line = self.last_cmd;
+        let line = &line[..self.last_cmd_len];
+        self.emit(line);
+        self.emit(b"\r\n");
+        self.dispatch(line).into_result()
+    }
+
+    /// Reports the receive, echo, and overrun counters.
+    fn cmd_stats(&mut self) {
+        let mut msg = [0u8; 80];
+        let mut text = FmtBuf::new(&mut msg);
+        let _ = write!(
+            text,
+            "rx: {} echoed: {} overruns: {}\r\n",
+            self.rx_bytes, self.echo_count, self.rx_overruns
+        );
+        let len = text.len();
+        self.emit(&msg[..len]);
+    }
+
+    /// Zeroes one statistic by name.
+    ///
+    /// # Details
+    /// `errors` clears the overrun and parity counts and the error log,
+    /// `echo` the echo count, `bytes` the rx/tx byte counts, and `noise`
+    /// the sanitized byte count. Other counters are left untouched.
+    ///
+    /// # Arguments
+    /// * `args` - Counter name
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or InvalidArgument if unknown
+    fn cmd_clear(&mut self, args: &[u8]) -> Result<(), UartError> {
+        match args {
+            b"errors" => self.clear_errors(),
+            b"echo" => self.clear_echo(),
+            b"bytes" => self.clear_bytes(),
+            b"noise" => self.clear_noise(),
+            _ => {
+                self.emit(b"unknown counter\r\n");
+                return Err(UartError::InvalidArgument);
+            }
+        }
+        self.emit(b"cleared ");
+        self.emit(args);
+        self.emit(b"\r\n");
+        Ok(())
+    }
+
+    /// Prints the ASCII-art logo.
+    ///
+    /// # Details
+    /// Each `\n` in LOGO is replaced with the Enter echo of the current
+    /// newline mode, so the logo ends its lines like typed text does.
+    fn cmd_logo(&mut self) {
+        let newline = self.settings.newline_mode.echo(b'\n');
+        for (i, line) in LOGO.split(|&b| b == b'\n').enumerate() {
+            if i > 0 {
+                self.emit(newline);
+            }
+            self.emit(line);
+        }
+    }
+
+    /// Lists the error log, oldest first.
+    ///
+    /// # Details
+    /// Each entry is shown as `[NNNNNNNN] name` and CRLF, or
+    /// `no errors` if none has been recorded.
+    fn cmd_errors(&mut self) {
+        if self.errors.is_empty() {
+            self.emit(b"no errors\r\n");
+            return;
+        }
+        let mut index = 0;
+        while let Some((err, ms)) = self.errors.get(index) {
+            let mut msg = [0u8; 16];
+            let n = format_timestamp(ms, &mut msg);
+            self.emit(&msg[..n]);
+            self.emit(err.name().as_bytes());
+            self.emit(b"\r\n");
+            index += 1;
+        }
+    }
+
+    /// Applies a new indent and reports it.
+    ///
+    /// # Arguments
+    /// * `indent` - Requested indent in spaces
+    fn cmd_indent(&mut self, indent: u8) {
+        self.set_indent(indent);
+        let mut msg = [0u8; 16];
+        let mut text = FmtBuf::new(&mut msg);
+        let _ = write!(text, "indent: {}\r\n", self.settings.indent);
+        let len = text.len();
+        self.emit(&msg[..len]);
+    }
+
+    /// Sets the echo delay, or shows it when no argument is given.
+    ///
+    /// # Arguments
+    /// * `args` - Decimal milliseconds from 0 to MAX_ECHO_DELAY_MS, or empty
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or InvalidArgument if out of range
+    fn cmd_delay(&mut self, args: &[u8]) -> Result<(), UartError> {
+        if !args.is_empty() {
+            let ms = core::str::from_utf8(args)
+                .ok()
+                .and_then(|text| text.parse::<u32>().ok());
+            match ms {
+                Some(ms @ 0..=MAX_ECHO_DELAY_MS) => self.set_echo_delay(ms),
+                _ => {
+                    self.emit(b"invalid delay\r\n");
+                    return Err(UartError::InvalidArgument);
+                }
+            }
+        }
+        let mut msg = [0u8; 24];
+        let mut text = FmtBuf::new(&mut msg);
+        let _ = write!(text, "delay: {} ms\r\n", self.echo_delay_ms);
+        let len = text.len();
+        self.emit(&msg[..len]);
+        Ok(())
+    }
+
+    /// Starts streaming the PRBS-7 test pattern.
+    ///
+    /// # Details
+    /// The generator restarts from its seed so every run produces the
+    /// same bytes. The main loop streams them with pattern_chunk().
+    ///
+    /// # Arguments
+    /// * `args` - Decimal byte count from 1 to PATTERN_MAX_BYTES
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or InvalidArgument if out of range
+    fn cmd_pattern(&mut self, args: &[u8]) -> Result<(), UartError> {
+        let count = core::str::from_utf8(args)
+            .ok()
+            .and_then(|text| text.parse::<u32>().ok());
+        match count {
+            Some(n @ 1..=PATTERN_MAX_BYTES) => {
+                self.pattern = Prbs7::new();
+                self.pattern_remaining = n;
+                Ok(())
+            }
+            _ => {
+                self.emit(b"invalid count\r\n");
+                Err(UartError::InvalidArgument)
+            }
+        }
+    }
+
+    /// Starts exporting the typed transcript.
+    ///
+    /// # Details
+    /// Snapshots the transcript so typing during the export does not
+    /// shift it. The main loop streams it with export_chunk().
+    fn cmd_export(&mut self) {
+        self.export = self.transcript;
+        self.export_pos = Some(0);
+    }
+
+    /// Searches the typed transcript for a byte pattern.
+    ///
+    /// # Details
+    /// Reports the offset from the oldest retained byte of the first
+    /// match, or `not found`.
+    ///
+    /// # Arguments
+    /// * `args` - Pattern as hex bytes, e.g. `41 42`
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or InvalidArgument if the pattern is malformed
+    fn cmd_find(&mut self, args: &[u8]) -> Result<(), UartError> {
+        let mut pattern = [0u8; LINE_CAP];
+        let n = match parse_hex(args, &mut pattern) {
+            Some(n) if n > 0 => n,
+            _ => {
+                self.emit(b"invalid pattern\r\n");
+                return Err(UartError::InvalidArgument);
+            }
+        };
+        match self.transcript.find(&pattern[..n]) {
+            Some(offset) => {
+                let mut msg = [0u8; 32];
+                let mut text = FmtBuf::new(&mut msg);
+                let _ = write!(text, "found at {}\r\n", offset);
+                let len = text.len();
+                self.emit(&msg[..len]);
+            }
+            None => self.emit(b"not found\r\n"),
+        }
+        Ok(())
+    }
+
+    /// Arms a one-shot malformed response.
+    ///
+    /// # Details
+    /// Refused unless fault injection was enabled with
+    /// set_fault_injection(), so a stray `fault` line cannot corrupt
+    /// output.
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or InvalidArgument if not enabled
+    fn cmd_fault(&mut self) -> Result<(), UartError> {
+        if !self.fault_injection {
+            self.emit(b"fault injection disabled\r\n");
+            return Err(UartError::InvalidArgument);
+        }
+        self.emit(b"fault armed\r\n");
+        self.pending_fault = true;
+        Ok(())
+    }
+
+    /// Corrupts the current response if a fault was armed before it.
+    ///
+    /// # Details
+    /// Flips the low bit of the last response byte and disarms the
+    /// fault. Empty responses leave the fault armed.
+    ///
+    /// # Arguments
+    /// * `armed` - Whether a fault was pending before this character
+    fn inject_fault(&mut self, armed: bool) {
+        if armed && self.out_len > 0 {
+            self.out[self.out_len - 1] ^= 0x01;
+            self.pending_fault = false;
+        }
+    }
+
+    /// Confirms and requests a software reset.
+    fn cmd_reset(&mut self) {
+        self.emit(b"resetting...\r\n");
+        self.reset_requested = true;
+    }
+
+    /// Confirms and requests a reboot into the UF2 bootloader.
+    fn cmd_bootsel(&mut self) {
+        self.emit(b"rebooting to bootloader...\r\n");
+        self.bootsel_requested = true;
+    }
+
+    /// Validates a baud rate argument.
+    ///
+    /// # Arguments
+    /// * `args` - Decimal rate, one of BAUD_RATES
+    ///
+    /// # Returns
+    /// * `Result<u32, UartError>` - The rate, or InvalidArgument
+    fn parse_baud(&mut self, args: &[u8]) -> Result<u32, UartError> {
+        let rate = core::str::from_utf8(args)
+            .ok()
+            .and_then(|text| text.parse::<u32>().ok());
+        match rate {
+            Some(rate) if BAUD_RATES.contains(&rate) => Ok(rate),
+            _ => {
+                self.emit(b"invalid baud\r\n");
+                Err(UartError::InvalidArgument)
+            }
+        }
+    }
+
+    /// Confirms and requests a switch to the staged baud rate.
+    fn cmd_baud(&mut self) {
+        let mut msg = [0u8; 24];
+        let mut text = FmtBuf::new(&mut msg);
+        let _ = write!(text, "baud: {}\r\n", self.staged_baud);
+        let len = text.len();
+        self.emit(&msg[..len]);
+        self.baud_request = Some(self.staged_baud);
+    }
+
+    /// Decodes base64 text and reports the decoded byte count.
+    ///
+    /// # Details
+    /// The arguments come from the line buffer, so their decoded bytes
+    /// always fit a LINE_CAP scratch buffer.
+    ///
+    /// # Arguments
+    /// * `args` - Base64 text
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or InvalidArgument if malformed
+    fn cmd_base64(&mut self, args: &[u8]) -> Result<(), UartError> {
+        let mut buf = [0u8; LINE_CAP];
+        match base64::decode(args, &mut buf) {
+            Ok(n) => {
+                let mut msg = [0u8; 24];
+                let mut text = FmtBuf::new(&mut msg);
+                let _ = write!(text, "decoded {} bytes\r\n", n);
+                let len = text.len();
+                self.emit(&msg[..len]);
+                Ok(())
+            }
+            Err(_) => {
+                self.emit(b"invalid base64\r\n");
+                Err(UartError::InvalidArgument)
+            }
+        }
+    }
+
+    /// Appends bytes to the response buffer.
+    ///
+    /// # Details
+    /// Updates the tracked cursor for each byte and, with the loopback
+    /// guard on, remembers it as recently transmitted. With EBCDIC
+    /// encoding the bytes are translated as they are appended.
+    /// Bytes beyond ECHO_BUF_SIZE are dropped; LINE_CAP_FITS keeps every
+    /// response within it.
+    ///
+    /// # Arguments
+    /// * `bytes` - Bytes to append
+    fn emit(&mut self, bytes: &[u8]) {
+        let end = (self.out_len + bytes.len()).min(ECHO_BUF_SIZE);
+        let n = end - self.out_len;
+        for (i, &b) in bytes[..n].iter().enumerate() {
+            self.track_cursor(b);
+            let wire = match self.encoding {
+                Encoding::Ascii => b,
+                Encoding::Ebcdic => ebcdic::from_ascii(b),
+            };
+            self.out[self.out_len + i] = wire;
+            if self.loopback_guard {
+                self.recent_tx.push(wire);
+            }
+        }
+        self.out_len = end;
+    }
+
+    /// Signals the bell according to the bell mode.
+    fn ring_bell(&mut self) {
+        if self.settings.bell_mode != BellMode::Visible {
+            self.emit(&[BELL]);
+        }
+        if self.settings.bell_mode != BellMode::Audible {
+            self.emit(&VISIBLE_BELL_SEQ);
+        }
+    }
+
+    /// Writes a VT100 cursor position report into the response buffer.
+    ///
+    /// # Details
+    /// Formats `ESC[row;colR` from the tracked position.
+    fn report_cursor(&mut self) {
+        let mut msg = [0u8; 16];
+        let mut text = FmtBuf::new(&mut msg);
+        let _ = write!(text, "\x1b[{};{}R", self.row, self.col);
+        let len = text.len();
+        self.emit(&msg[..len]);
+    }
+
+    /// Updates the tracked cursor for an emitted byte.
+    ///
+    /// # Details
+    /// Printable characters advance the column, CR returns to column 1,
+    /// LF advances the row, tab moves to the next 8-column stop, and
+    /// backspace moves left without passing column 1.
+    ///
+    /// # Arguments
+    /// * `ch` - The byte that was emitted
+    fn track_cursor(&mut self, ch: u8) {
+        match ch {
+            b'\r' => self.col = 1,
+            b'\n' => self.row = self.row.saturating_add(1),
+            b'\t' => self.col = ((self.col - 1) / 8 + 1) * 8 + 1,
+            BACKSPACE | DELETE => self.col = self.col.saturating_sub(1).max(1),
+            0x20..=0x7E => self.col = self.col.saturating_add(1),
+            _ => {}
+        }
+    }
+
+    /// Enables or disables duplicate line suppression.
+    ///
+    /// # Details
+    /// Works like the `uniq` tool on echo-mode lines: typed characters
+    /// are not echoed, each committed line is echoed once, and a run
+    /// of identical lines is closed with `(repeated N times)` when a
+    /// different line is committed. Toggling starts a new run.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to collapse repeated lines
+    #[allow(dead_code)]
+    pub fn set_uniq(&mut self, enabled: bool) {
+        self.settings.uniq = enabled;
+        self.repeat_count = 0;
+    }
+
+    /// Returns whether duplicate line suppression is enabled.
+    ///
+    /// # Returns
+    /// * `bool` - True if repeated lines are collapsed
+    #[allow(dead_code)]
+    pub fn uniq(&self) -> bool {
+        self.settings.uniq
+    }
+
+    /// Enables or disables numbered line echo.
+    ///
+    /// # Details
+    /// For listing-style output. Each committed echo-mode line is
+    /// echoed again after Enter with an incrementing line number.
+    /// Enabling restarts numbering at 1.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to echo numbered lines
+    #[allow(dead_code)]
+    pub fn set_line_numbers(&mut self, enabled: bool) {
+        self.settings.line_numbers = enabled;
+        self.line_no = 0;
+    }
+
+    /// Returns whether numbered line echo is enabled.
+    ///
+    /// # Returns
+    /// * `bool` - True if committed lines are numbered
+    #[allow(dead_code)]
+    pub fn line_numbers(&self) -> bool {
+        self.settings.line_numbers
+    }
+
+    /// Sets the digit width line numbers are right-aligned to.
+    ///
+    /// # Details
+    /// Clamped to 1..=10, enough for any u32.
+    ///
+    /// # Arguments
+    /// * `width` - Minimum number of characters for the number
+    #[allow(dead_code)]
+    pub fn set_line_number_width(&mut self, width: usize) {
+        self.settings.line_number_width = width.clamp(1, 10);
+    }
+
+    /// Returns the line number width.
+    ///
+    /// # Returns
+    /// * `usize` - Digit width
+    #[allow(dead_code)]
+    pub fn line_number_width(&self) -> usize {
+        self.settings.line_number_width
+    }
+
+    /// Sets the indent echoed before each committed line.
+    ///
+    /// # Details
+    /// With a nonzero indent, each committed echo-mode line is echoed
+    /// again after Enter, prefixed with this many spaces. Clamped to
+    /// MAX_INDENT.
+    ///
+    /// # Arguments
+    /// * `indent` - Number of leading spaces
+    #[allow(dead_code)]
+    pub fn set_indent(&mut self, indent: u8) {
+        self.settings.indent = indent.min(MAX_INDENT);
+    }
+
+    /// Returns the committed line indent.
+    ///
+    /// # Returns
+    /// * `u8` - Number of leading spaces
+    #[allow(dead_code)]
+    pub fn indent(&self) -> u8 {
+        self.settings.indent
+    }
+
+    /// Enables or disables reversed line echo.
+    ///
+    /// # Details
+    /// For demos. Each committed echo-mode line is echoed again with
+    /// its bytes in reverse order, e.g. `olleh` and CRLF after `hello`.
+    /// Bytes are reversed individually, so this suits ASCII input;
+    /// multi-byte UTF-8 characters would be split.
+    ///
+    /// # Arguments
+    /// * `enabled` - True to echo committed lines reversed
+    #[allow(dead_code)]
+    pub fn set_reverse_lines(&mut self, enabled: bool) {
+        self.settings.reverse_lines = enabled;
+    }
+
+    /// Returns whether committed lines are echoed reversed.
+    ///
+    /// # Returns
+    /// * `bool` - True if reversed line echo is enabled
+    #[allow(dead_code)]
+    pub fn reverse_lines(&self) -> bool {
+        self.settings.reverse_lines
+    }
+
+    /// Sets the device name shown in the banner and prompt.
+    ///
+    /// # Details
+    /// Names are at most DEVICE_NAME_SIZE printable ASCII characters.
+    /// An empty name clears it. A rejected name leaves the old one.
+    ///
+    /// # Arguments
+    /// * `name` - New device name
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or InvalidArgument if too long or unprintable
+    #[allow(dead_code)]
+    pub fn set_name(&mut self, name: &[u8]) -> Result<(), UartError> {
+        if name.len() > DEVICE_NAME_SIZE || !name.iter().all(|b| (0x20..=0x7E).contains(b)) {
+            return Err(UartError::InvalidArgument);
+        }
+        self.name[..name.len()].copy_from_slice(name);
+        self.name_len = name.len();
+        Ok(())
+    }
+
+    /// Returns the device name.
+    ///
+    /// # Returns
+    /// * `&[u8]` - Device name, empty if unset
+    #[allow(dead_code)]
+    pub fn name(&self) -> &[u8] {
+        &self.name[..self.name_len]
+    }
+
+    /// Selects the parts shown in the command prompt.
+    ///
+    /// # Details
+    /// Defaults to the device name alone, giving `name> `.
+    ///
+    /// # Arguments
+    /// * `parts` - Parts to show
+    #[allow(dead_code)]
+    pub fn set_prompt_parts(&mut self, parts: PromptParts) {
+        self.prompt_parts = parts;
+    }
+
+    /// Returns the parts shown in the command prompt.
+    ///
+    /// # Returns
+    /// * `PromptParts` - Selected parts
+    #[allow(dead_code)]
+    pub fn prompt_parts(&self) -> PromptParts {
+        self.prompt_parts
+    }
+
+    /// Builds the command prompt from the current state.
+    ///
+    /// # Arguments
+    /// * `out` - Destination buffer, PROMPT_BUF_SIZE bytes is always enough
+    ///
+    /// # Returns
+    /// * `usize` - Bytes written to `out`
+    #[allow(dead_code)]
+    pub fn prompt(&self, out: &mut [u8]) -> usize {
+        build_prompt(
+            self.prompt_parts,
+            self.name(),
+            self.echo_count,
+            self.mode.label(),
+            out,
+        )
+    }
+
+    /// Formats the startup banner.
+    ///
+    /// # Details
+    /// Produces BANNER, then ` - name` if a device name is set, then
+    /// CRLF.
+    ///
+    /// # Returns
+    /// * `&[u8]` - Bytes to transmit
+    #[allow(dead_code)]
+    pub fn banner(&mut self) -> &[u8] {
+        self.out_len = 0;
+        self.emit(BANNER);
+        if self.name_len > 0 {
+            let name = self.name;
+            self.emit(b" - ");
+            self.emit(&name[..self.name_len]);
+        }
+        self.emit(b"\r\n");
+        &self.out[..self.out_len]
+    }
+
+    /// Sets or clears the login password.
+    ///
+    /// # Details
+    /// Setting a password locks the controller until it is entered.
+    /// Clearing it returns a locked controller to echo mode.
+    ///
+    /// # Arguments
+    /// * `password` - Required password, or None to disable the gate
+    #[allow(dead_code)]
+    pub fn set_password(&mut self, password: Option<&'static [u8]>) {
+        self.password = password;
+        if password.is_some() {
+            self.lock();
+        } else {
+            self.privilege = Privilege::Admin;
+            if self.mode == Mode::Login {
+                self.set_mode(Mode::Echo);
+            }
+        }
+    }
+
+    /// Sets or clears the read-only monitor password.
+    ///
+    /// # Details
+    /// Accepted at the login gate alongside the main password and
+    /// starts a Privilege::Monitor session. Has no effect unless a main
+    /// password is set with set_password().
+    ///
+    /// # Arguments
+    /// * `password` - Monitor password, or None to disable it
+    #[allow(dead_code)]
+    pub fn set_monitor_password(&mut self, password: Option<&'static [u8]>) {
+        self.monitor_password = password;
+    }
+
+    /// Returns the access level of the current session.
+    ///
+    /// # Returns
+    /// * `Privilege` - Admin or Monitor
+    #[allow(dead_code)]
+    pub fn privilege(&self) -> Privilege {
+        self.privilege
+    }
+
+    /// Ends the session and returns to the login gate.
+    ///
+    /// # Details
+    /// Does nothing when no password is set.
+    #[allow(dead_code)]
+    pub fn lock(&mut self) {
+        if self.password.is_some() {
+            self.clear_line();
+            self.set_mode(Mode::Login);
+        }
+    }
+
+    /// Sets the text greeting each new session.
+    ///
+    /// # Arguments
+    /// * `greeting` - Welcome text, followed by ", session #N"
+    #[allow(dead_code)]
+    pub fn set_greeting(&mut self, greeting: &'static [u8]) {
+        self.greeting = greeting;
+    }
+
+    /// Returns the number of successful logins.
+    ///
+    /// # Returns
+    /// * `u32` - Session count
+    #[allow(dead_code)]
+    pub fn session_count(&self) -> u32 {
+        self.session_count
+    }
+
+    /// Records an RX overrun reported by the UART driver.
+    ///
+    /// # Details
+    /// Also added to the error log at the current time.
+    #[allow(dead_code)]
+    pub fn record_overrun(&mut self) {
+        self.rx_overruns += 1;
+        self.errors.record(UartError::RxOverrun, self.now_ms);
+    }
+
+    /// Records output the main loop dropped because a TX queue was full.
+    ///
+    /// # Details
+    /// Added to the error log as TxQueueFull at the current time.
+    #[allow(dead_code)]
+    pub fn record_tx_drop(&mut self) {
+        self.errors.record(UartError::TxQueueFull, self.now_ms);
+    }
+
+    /// Sets the current time used to timestamp logged errors.
+    ///
+    /// # Details
+    /// Called by the main loop before each character is processed.
+    ///
+    /// # Arguments
+    /// * `now_ms` - Milliseconds since boot
+    #[allow(dead_code)]
+    pub fn set_time(&mut self, now_ms: u64) {
+        self.now_ms = now_ms;
+    }
+
+    /// Returns the log of recent errors.
+    ///
+    /// # Returns
+    /// * `&ErrorLog<ERROR_LOG_SIZE>` - Errors, oldest first
+    #[allow(dead_code)]
+    pub fn errors(&self) -> &ErrorLog<ERROR_LOG_SIZE> {
+        &self.errors
+    }
+
+    /// Records output bytes whose transmission has completed.
+    ///
+    /// # Details
+    /// Called by the main loop after a UART write has flushed, so the
+    /// count only covers bytes that actually left the device.
+    ///
+    /// # Arguments
+    /// * `bytes` - Number of bytes flushed
+    #[allow(dead_code)]
+    pub fn record_flushed(&mut self, bytes: usize) {
+        self.flushed_count = self.flushed_count.saturating_add(bytes as u64);
+    }
+
+    /// Returns the number of output bytes confirmed transmitted.
+    ///
+    /// # Details
+    /// Unlike echo_count(), which counts received characters as they
+    /// are processed, this only advances after a flush completes.
+    ///
+    /// # Returns
+    /// * `u64` - Flushed byte count
+    #[allow(dead_code)]
+    pub fn flushed_count(&self) -> u64 {
+        self.flushed_count
+    }
+
+    /// Returns the number of recorded RX overruns.
+    ///
+    /// # Returns
+    /// * `u64` - Overrun count
+    #[allow(dead_code)]
+    pub fn overruns(&self) -> u64 {
+        self.rx_overruns
+    }
+
+    /// Zeroes the error counters.
+    ///
+    /// # Details
+    /// Clears the overrun and parity error counts and empties the error
+    /// log. Traffic and echo counts are kept.
+    #[allow(dead_code)]
+    pub fn clear_errors(&mut self) {
+        self.rx_overruns = 0;
+        self.parity_errors = 0;
+        self.errors.clear();
+    }
+
+    /// Zeroes the echoed character count.
+    #[allow(dead_code)]
+    pub fn clear_echo(&mut self) {
+        self.echo_count = 0;
+    }
+
+    /// Zeroes the received and transmitted byte counts.
+    #[allow(dead_code)]
+    pub fn clear_bytes(&mut self) {
+        self.rx_bytes = 0;
+        self.tx_bytes = 0;
+    }
+
+    /// Zeroes the count of bytes dropped by input sanitizing.
+    #[allow(dead_code)]
+    pub fn clear_noise(&mut self) {
+        self.noise_count = 0;
+    }
+
+    /// Registers or clears the callback for a controller event.
+    ///
+    /// # Details
+    /// Callbacks run synchronously inside process_char(). Event data is
+    /// described on Event.
+    ///
+    /// # Arguments
+    /// * `event` - Event to hook
+    /// * `callback` - Function to call, or None to clear
+    #[allow(dead_code)]
+    pub fn set_callback(&mut self, event: Event, callback: Option<Callback>) {
+        self.callbacks.set(event, callback);
+    }
+
+    /// Returns total echo count.
+    ///
+    /// # Returns
+    /// * `u64` - Number of characters echoed
+    #[allow(dead_code)]
+    pub fn echo_count(&self) -> u64 {
+        self.echo_count
+    }
+
+    /// Returns the number of bytes received.
+    ///
+    /// # Returns
+    /// * `u64` - Bytes passed to process_char() and its variants
+    #[allow(dead_code)]
+    pub fn rx_bytes(&self) -> u64 {
+        self.rx_bytes
+    }
+
+    /// Returns the number of response bytes produced.
+    ///
+    /// # Details
+    /// Differs from rx_bytes() whenever one byte echoes several, as a
+    /// backspace erase or a CR expanded to CRLF does.
+    ///
+    /// # Returns
+    /// * `u64` - Bytes returned for transmission
+    #[allow(dead_code)]
+    pub fn tx_bytes(&self) -> u64 {
+        self.tx_bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{CLEAR_SCREEN_SEQ, LINE_NUMBER_WIDTH, RIGHT_MARGIN, VT_NEWLINE_SEQ};
+    use std::sync::Mutex;
+
+    // ==================== UartController Construction Tests ====================
+
+    #[test]
+    fn test_new_controller() {
+        let ctrl = UartController::new();
+        assert_eq!(ctrl.echo_count(), 0);
+    }
+
+    #[test]
+    fn test_default_equals_new() {
+        let default = UartController::default();
+        let new = UartController::new();
+        assert_eq!(default.echo_count(), new.echo_count());
+    }
+
+    // ==================== Character Processing Tests ====================
+
+    #[test]
+    fn test_process_char_returns_same() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.process_char(b'A'), b"A");
+    }
+
+    #[test]
+    fn test_process_char_increments_count() {
+        let mut ctrl = UartController::new();
+        ctrl.process_char(b'A');
+        ctrl.process_char(b'B');
+        ctrl.process_char(b'C');
+        assert_eq!(ctrl.echo_count(), 3);
+    }
+
+    #[test]
+    fn test_process_char_special() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.process_char(b'\n'), b"\n");
+        assert_eq!(ctrl.process_char(b'\r'), b"\r");
+    }
+
+    #[test]
+    fn test_process_backspace() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.process_char(0x08), &[0x08, b' ', 0x08]);
+    }
+
+    #[test]
+    fn test_process_delete() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.process_char(0x7F), &[0x08, b' ', 0x08]);
+    }
+
+    #[test]
+    fn test_process_tab() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.process_char(b'\t'), b"\t");
+    }
+
+    #[test]
+    fn test_process_uppercase_letters() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.process_char(b'A'), b"A");
+        assert_eq!(ctrl.process_char(b'Z'), b"Z");
+        assert_eq!(ctrl.process_char(b'M'), b"M");
+    }
+
+    #[test]
+    fn test_process_lowercase_letters() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.process_char(b'a'), b"a");
+        assert_eq!(ctrl.process_char(b'z'), b"z");
+        assert_eq!(ctrl.process_char(b'm'), b"m");
+    }
+
+    #[test]
+    fn test_process_digits() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.process_char(b'0'), b"0");
+        assert_eq!(ctrl.process_char(b'9'), b"9");
+        assert_eq!(ctrl.process_char(b'5'), b"5");
+    }
+
+    #[test]
+    fn test_process_punctuation() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.process_char(b'!'), b"!");
+        assert_eq!(ctrl.process_char(b'?'), b"?");
+        assert_eq!(ctrl.process_char(b'.'), b".");
+        assert_eq!(ctrl.process_char(b','), b",");
+    }
+
+    #[test]
+    fn test_process_symbols() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.process_char(b'@'), b"@");
+        assert_eq!(ctrl.process_char(b'#'), b"#");
+        assert_eq!(ctrl.process_char(b'$'), b"$");
+        assert_eq!(ctrl.process_char(b'%'), b"%");
+    }
+
+    #[test]
+    fn test_process_brackets() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.process_char(b'['), b"[");
+        assert_eq!(ctrl.process_char(b']'), b"]");
+        assert_eq!(ctrl.process_char(b'('), b"(");
+        assert_eq!(ctrl.process_char(b')'), b")");
+        assert_eq!(ctrl.process_char(b'{'), b"{");
+        assert_eq!(ctrl.process_char(b'}'), b"}");
+    }
+
+    #[test]
+    fn test_process_unknown_char() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.process_char(0x01), b"");
+        assert_eq!(ctrl.process_char(0xFF), b"");
+    }
+
+    #[test]
     fn test_process_space() {
         let mut ctrl = UartController::new();
-        assert_eq!(ctrl.process_char(b' '), b" ");
+        assert_eq!(ctrl.process_char(b' '), b" ");
+    }
+
+    #[test]
+    fn test_backspace_increments_count() {
+        let mut ctrl = UartController::new();
+        ctrl.process_char(0x08);
+        assert_eq!(ctrl.echo_count(), 1);
+    }
+
+    #[test]
+    fn test_multiple_backspaces() {
+        let mut ctrl = UartController::new();
+        ctrl.process_char(0x08);
+        ctrl.process_char(0x7F);
+        ctrl.process_char(0x08);
+        assert_eq!(ctrl.echo_count(), 3);
+    }
+
+    #[test]
+    fn test_mixed_chars_and_backspace() {
+        let mut ctrl = UartController::new();
+        ctrl.process_char(b'A');
+        ctrl.process_char(b'B');
+        ctrl.process_char(0x08);
+        ctrl.process_char(b'C');
+        assert_eq!(ctrl.echo_count(), 4);
+    }
+
+    // ==================== Fallible API Tests ====================
+
+    #[test]
+    fn test_try_process_char_ok() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.try_process_char(b'A'), Ok(&b"A"[..]));
+    }
+
+    #[test]
+    fn test_try_process_char_unsupported_byte() {
+        let mut ctrl = UartController::new();
+        assert_eq!(
+            ctrl.try_process_char(0x01),
+            Err((UartError::UnsupportedByte(0x01), &b""[..]))
+        );
+        assert_eq!(ctrl.echo_count(), 1);
+    }
+
+    #[test]
+    fn test_try_process_writes_echo() {
+        let mut ctrl = UartController::new();
+        let mut out = [0u8; ECHO_BUF_SIZE];
+        assert_eq!(ctrl.try_process(0x08, &mut out), Ok(3));
+        assert_eq!(&out[..3], &BACKSPACE_SEQ);
+    }
+
+    #[test]
+    fn test_try_process_writes_response_on_error() {
+        let mut ctrl = UartController::<2>::with_capacity();
+        let mut out = [0u8; ECHO_BUF_SIZE];
+        feed(&mut ctrl, b"ab");
+        assert_eq!(
+            ctrl.try_process(b'c', &mut out),
+            Err((UartError::LineOverflow, 1))
+        );
+        assert_eq!(out[0], BELL);
+        assert_eq!(ctrl.tx_bytes(), 3);
+    }
+
+    #[test]
+    fn test_try_process_buffer_too_small() {
+        let mut ctrl = UartController::new();
+        let mut out = [0u8; ECHO_BUF_SIZE - 1];
+        assert_eq!(
+            ctrl.try_process(0x7F, &mut out),
+            Err((UartError::BufferTooSmall, 0))
+        );
+        assert_eq!(ctrl.echo_count(), 0);
+        assert_eq!(ctrl.rx_bytes(), 0);
+        assert!(ctrl.errors().is_empty());
+    }
+
+    #[test]
+    fn test_try_process_buffer_too_small_fires_no_callback() {
+        static HITS: Mutex<u32> = Mutex::new(0);
+        fn hit(_: &[u8]) {
+            *HITS.lock().unwrap() += 1;
+        }
+        let mut ctrl = UartController::new();
+        ctrl.set_callback(Event::LineCommitted, Some(hit));
+        let mut out = [0u8; 4];
+        feed(&mut ctrl, b"hi");
+        assert_eq!(
+            ctrl.try_process(b'\r', &mut out),
+            Err((UartError::BufferTooSmall, 0))
+        );
+        assert_eq!(*HITS.lock().unwrap(), 0);
+        assert_eq!(ctrl.line(), b"hi");
+    }
+
+    #[test]
+    fn test_try_process_unsupported_byte() {
+        let mut ctrl = UartController::new();
+        let mut out = [0u8; ECHO_BUF_SIZE];
+        assert_eq!(
+            ctrl.try_process(0xFF, &mut out),
+            Err((UartError::UnsupportedByte(0xFF), 0))
+        );
+    }
+
+    // ==================== Preview Tests ====================
+
+    #[test]
+    fn test_preview_matches_process_char() {
+        let mut ctrl = UartController::new();
+        ctrl.set_case_mode(CaseMode::Upper);
+        ctrl.set_caesar(1);
+        let mut out = [0u8; ECHO_BUF_SIZE];
+        for &ch in b"ab\x08c\r" {
+            let n = ctrl.preview(ch, &mut out);
+            assert_eq!(&out[..n], ctrl.process_char(ch));
+        }
+    }
+
+    #[test]
+    fn test_preview_leaves_state_unchanged() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"ab");
+        let before = ctrl;
+        let mut out = [0u8; 8];
+        assert_eq!(ctrl.preview(b'c', &mut out), 1);
+        assert_eq!(out[0], b'c');
+        assert_eq!(ctrl.echo_count(), 2);
+        assert_eq!(ctrl.line(), b"ab");
+        assert_eq!(ctrl, before);
+    }
+
+    #[test]
+    fn test_preview_framed_hex_escape_keeps_sequence() {
+        let mut ctrl = UartController::new();
+        ctrl.set_char_prefix(Some(b'<'));
+        ctrl.set_char_suffix(Some(b'>'));
+        ctrl.set_show_hex_escapes(true);
+        ctrl.set_seq_echo(true);
+        let mut out = [0u8; 16];
+        for &ch in &[0xE9, b'a', b' '] {
+            let n = ctrl.preview(ch, &mut out);
+            assert_eq!(ctrl.preview(ch, &mut out), n);
+            assert_eq!(&out[..n], ctrl.process_char(ch));
+        }
+    }
+
+    #[test]
+    fn test_preview_truncates_to_buffer() {
+        let ctrl = UartController::new();
+        let mut out = [0u8; 2];
+        assert_eq!(ctrl.preview(0x08, &mut out), 2);
+        assert_eq!(out, BACKSPACE_SEQ[..2]);
+    }
+
+    // ==================== Line Ending Display Tests ====================
+
+    #[test]
+    fn test_show_line_endings_default_off() {
+        let ctrl = UartController::new();
+        assert!(!ctrl.show_line_endings());
+    }
+
+    #[test]
+    fn test_show_line_endings_cr() {
+        let mut ctrl = UartController::new();
+        ctrl.set_show_line_endings(true);
+        assert_eq!(ctrl.process_char(b'\r'), b"\\r\r");
+    }
+
+    #[test]
+    fn test_show_line_endings_lf() {
+        let mut ctrl = UartController::new();
+        ctrl.set_show_line_endings(true);
+        assert_eq!(ctrl.process_char(b'\n'), b"\\n\n");
+    }
+
+    #[test]
+    fn test_show_line_endings_other_chars_unchanged() {
+        let mut ctrl = UartController::new();
+        ctrl.set_show_line_endings(true);
+        assert_eq!(ctrl.process_char(b'A'), b"A");
+        assert_eq!(ctrl.process_char(0x08), &BACKSPACE_SEQ);
+    }
+
+    #[test]
+    fn test_show_line_endings_off_unchanged() {
+        let mut ctrl = UartController::new();
+        ctrl.set_show_line_endings(true);
+        ctrl.set_show_line_endings(false);
+        assert_eq!(ctrl.process_char(b'\r'), b"\r");
+        assert_eq!(ctrl.process_char(b'\n'), b"\n");
+    }
+
+    #[test]
+    fn test_try_process_visible_cr_size() {
+        let mut ctrl = UartController::new();
+        ctrl.set_show_line_endings(true);
+        let mut out = [0u8; 2];
+        assert_eq!(
+            ctrl.try_process(b'\r', &mut out),
+            Err((UartError::BufferTooSmall, 0))
+        );
+    }
+
+    // ==================== Cursor Position Report Tests ====================
+
+    /// Feeds a byte sequence and returns the response to the last byte.
+    fn feed<const N: usize>(ctrl: &mut UartController<N>, bytes: &[u8]) -> Vec<u8> {
+        let mut last = Vec::new();
+        for &b in bytes {
+            last = ctrl.process_char(b).to_vec();
+        }
+        last
+    }
+
+    #[test]
+    fn test_cursor_starts_at_origin() {
+        let ctrl = UartController::new();
+        assert_eq!(ctrl.cursor_position(), (1, 1));
+    }
+
+    #[test]
+    fn test_cursor_report_at_origin() {
+        let mut ctrl = UartController::new();
+        assert_eq!(feed(&mut ctrl, b"\x1b[6n"), b"\x1b[1;1R");
+    }
+
+    #[test]
+    fn test_cursor_report_tracks_columns_and_rows() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"hello\r\nab");
+        assert_eq!(ctrl.cursor_position(), (2, 3));
+        assert_eq!(feed(&mut ctrl, b"\x1b[6n"), b"\x1b[2;3R");
+    }
+
+    #[test]
+    fn test_cursor_report_after_backspace() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"abc\x08");
+        assert_eq!(feed(&mut ctrl, b"\x1b[6n"), b"\x1b[1;3R");
+    }
+
+    #[test]
+    fn test_cursor_query_bytes_not_echoed() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.process_char(0x1B), b"");
+        assert_eq!(ctrl.process_char(b'['), b"");
+        assert_eq!(ctrl.process_char(b'6'), b"");
+        assert_eq!(ctrl.cursor_position(), (1, 1));
+    }
+
+    #[test]
+    fn test_unrecognized_sequence_swallowed() {
+        let mut ctrl = UartController::new();
+        assert_eq!(feed(&mut ctrl, b"\x1b[A"), b"");
+        assert_eq!(ctrl.process_char(b'x'), b"x");
+    }
+
+    #[test]
+    fn test_try_process_cursor_report() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1b[6");
+        let mut out = [0u8; ECHO_BUF_SIZE];
+        assert_eq!(ctrl.try_process(b'n', &mut out), Ok(6));
+        assert_eq!(&out[..6], b"\x1b[1;1R");
+    }
+
+    #[test]
+    fn test_try_process_too_small_leaves_state() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1b[6");
+        let before = ctrl;
+        let mut out = [0u8; 2];
+        assert_eq!(
+            ctrl.try_process(b'n', &mut out),
+            Err((UartError::BufferTooSmall, 0))
+        );
+        assert_eq!(ctrl, before);
+    }
+
+    // ==================== Named Controls Tests ====================
+
+    #[test]
+    fn test_named_controls_default_off() {
+        let mut ctrl = UartController::new();
+        assert!(!ctrl.named_controls());
+        assert_eq!(ctrl.process_char(0x09), b"\t");
+    }
+
+    #[test]
+    fn test_named_controls_cr_echoes_name() {
+        let mut ctrl = UartController::new();
+        ctrl.set_named_controls(true);
+        feed(&mut ctrl, b"hi");
+        assert_eq!(ctrl.process_char(0x0D), b"<CR>");
+        assert_eq!(ctrl.line(), b"");
+    }
+
+    #[test]
+    fn test_named_controls_esc_echoes_name() {
+        let mut ctrl = UartController::new();
+        ctrl.set_named_controls(true);
+        assert_eq!(ctrl.process_char(0x1B), b"");
+        assert_eq!(ctrl.idle(), b"<ESC>\r\n> ");
+    }
+
+    #[test]
+    fn test_named_controls_tab_and_backspace() {
+        let mut ctrl = UartController::new();
+        ctrl.set_named_controls(true);
+        assert_eq!(ctrl.process_char(0x09), b"<TAB>");
+        feed(&mut ctrl, b"x");
+        assert_eq!(ctrl.process_char(0x08), b"<BS>");
+        assert_eq!(ctrl.line(), b"\t");
+    }
+
+    #[test]
+    fn test_named_controls_printable_unchanged() {
+        let mut ctrl = UartController::new();
+        ctrl.set_named_controls(true);
+        assert_eq!(ctrl.process_char(b'a'), b"a");
+        assert_eq!(ctrl.process_char(b' '), b" ");
+    }
+
+    // ==================== Collapse Spaces Tests ====================
+
+    #[test]
+    fn test_collapse_spaces_default_off() {
+        let mut ctrl = UartController::new();
+        assert!(!ctrl.collapse_spaces());
+        feed(&mut ctrl, b"a  b");
+        assert_eq!(ctrl.line(), b"a  b");
+    }
+
+    #[test]
+    fn test_collapse_spaces_run_becomes_one() {
+        let mut ctrl = UartController::new();
+        ctrl.set_collapse_spaces(true);
+        let mut out = Vec::new();
+        for &b in b"a    b" {
+            out.extend_from_slice(ctrl.process_char(b));
+        }
+        assert_eq!(out, b"a b");
+        assert_eq!(ctrl.line(), b"a b");
+    }
+
+    #[test]
+    fn test_collapse_spaces_single_space_kept() {
+        let mut ctrl = UartController::new();
+        ctrl.set_collapse_spaces(true);
+        feed(&mut ctrl, b"a b c");
+        assert_eq!(ctrl.line(), b"a b c");
+    }
+
+    #[test]
+    fn test_collapse_spaces_after_backspace() {
+        let mut ctrl = UartController::new();
+        ctrl.set_collapse_spaces(true);
+        feed(&mut ctrl, b"a x\x08 b");
+        assert_eq!(ctrl.line(), b"a b");
+    }
+
+    // ==================== Sequence Echo Tests ====================
+
+    #[test]
+    fn test_seq_echo_default_off() {
+        let mut ctrl = UartController::new();
+        assert!(!ctrl.seq_echo());
+        assert_eq!(ctrl.process_char(b'a'), b"a");
+    }
+
+    #[test]
+    fn test_seq_echo_appends_counter() {
+        let mut ctrl = UartController::new();
+        ctrl.set_seq_echo(true);
+        assert_eq!(ctrl.process_char(b'a'), &[b'a', 0]);
+        assert_eq!(ctrl.process_char(b'b'), &[b'b', 1]);
+        assert_eq!(ctrl.process_char(b'c'), &[b'c', 2]);
+    }
+
+    #[test]
+    fn test_seq_echo_wraps() {
+        let mut ctrl = UartController::new();
+        ctrl.set_seq_echo(true);
+        for _ in 0..255 {
+            ctrl.process_char(b'\r');
+        }
+        assert_eq!(ctrl.seq(), 255);
+        assert_eq!(ctrl.process_char(b'x'), &[b'x', 255]);
+        assert_eq!(ctrl.process_char(b'y'), &[b'y', 0]);
+    }
+
+    #[test]
+    fn test_seq_echo_skips_unsupported() {
+        let mut ctrl = UartController::new();
+        ctrl.set_seq_echo(true);
+        assert_eq!(ctrl.process_char(0x01), b"");
+        assert_eq!(ctrl.seq(), 0);
+    }
+
+    // ==================== Echo Toggle Key Tests ====================
+
+    #[test]
+    fn test_echo_toggle_key_default_on() {
+        let ctrl = UartController::new();
+        assert!(ctrl.echo_toggle_key());
+    }
+
+    #[test]
+    fn test_ctrl_o_toggles_echo() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.process_char(CTRL_O), b"(echo off)");
+        assert!(!ctrl.echo_enabled());
+        assert_eq!(ctrl.process_char(b'a'), b"");
+        assert_eq!(ctrl.process_char(CTRL_O), b"(echo on)");
+        assert!(ctrl.echo_enabled());
+        assert_eq!(ctrl.process_char(b'b'), b"b");
+        assert_eq!(ctrl.line(), b"ab");
+    }
+
+    #[test]
+    fn test_ctrl_o_ignored_when_disabled() {
+        let mut ctrl = UartController::new();
+        ctrl.set_echo_toggle_key(false);
+        assert_eq!(
+            ctrl.try_process_char(CTRL_O),
+            Err((UartError::UnsupportedByte(CTRL_O), &b""[..]))
+        );
+        assert!(ctrl.echo_enabled());
+    }
+
+    #[test]
+    fn test_ctrl_o_denied_to_monitor_session() {
+        let mut ctrl = UartController::new();
+        ctrl.set_password(Some(b"admin"));
+        ctrl.set_monitor_password(Some(b"view"));
+        feed(&mut ctrl, b"view\r");
+        assert_eq!(
+            ctrl.try_process_char(CTRL_O),
+            Err((UartError::PermissionDenied, &b"(permission denied)"[..]))
+        );
+        assert!(ctrl.echo_enabled());
+        assert_eq!(ctrl.process_char(b'a'), b"a");
+    }
+
+    #[test]
+    fn test_ctrl_o_survives_sanitizing() {
+        let mut ctrl = UartController::new();
+        ctrl.set_sanitize_input(true);
+        assert_eq!(ctrl.process_char(CTRL_O), b"(echo off)");
+    }
+
+    // ==================== Character Framing Tests ====================
+
+    #[test]
+    fn test_char_framing_default_off() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.char_prefix(), None);
+        assert_eq!(ctrl.char_suffix(), None);
+        assert_eq!(ctrl.process_char(b'A'), b"A");
+    }
+
+    #[test]
+    fn test_char_framing_stx_etx() {
+        let mut ctrl = UartController::new();
+        ctrl.set_char_prefix(Some(0x02));
+        ctrl.set_char_suffix(Some(0x03));
+        assert_eq!(ctrl.process_char(b'A'), &[0x02, 0x41, 0x03]);
+    }
+
+    #[test]
+    fn test_char_framing_prefix_only() {
+        let mut ctrl = UartController::new();
+        ctrl.set_char_prefix(Some(b'<'));
+        assert_eq!(ctrl.process_char(b'A'), b"<A");
+    }
+
+    #[test]
+    fn test_char_framing_wraps_multi_byte_echo() {
+        let mut ctrl = UartController::new();
+        ctrl.process_char(b'A');
+        ctrl.set_char_prefix(Some(0x02));
+        ctrl.set_char_suffix(Some(0x03));
+        let mut expected = vec![0x02];
+        expected.extend_from_slice(&BACKSPACE_SEQ);
+        expected.push(0x03);
+        assert_eq!(ctrl.process_char(BACKSPACE), expected);
+    }
+
+    #[test]
+    fn test_char_framing_skips_silent_echo() {
+        let mut ctrl = UartController::new();
+        ctrl.set_char_prefix(Some(0x02));
+        ctrl.set_char_suffix(Some(0x03));
+        ctrl.set_echo_enabled(false);
+        assert_eq!(ctrl.process_char(b'A'), b"");
+    }
+
+    // ==================== Auto Wrap Tests ====================
+
+    #[test]
+    fn test_auto_wrap_default() {
+        let ctrl = UartController::new();
+        assert!(!ctrl.auto_wrap());
+        assert_eq!(ctrl.wrap_width(), RIGHT_MARGIN);
+    }
+
+    #[test]
+    fn test_auto_wrap_before_overflowing_char() {
+        let mut ctrl = UartController::new();
+        ctrl.set_auto_wrap(true);
+        ctrl.set_wrap_width(5);
+        assert_eq!(feed(&mut ctrl, b"abcde"), b"e");
+        assert_eq!(ctrl.cursor_position(), (1, 6));
+        assert_eq!(ctrl.process_char(b'f'), b"\r\nf");
+        assert_eq!(ctrl.cursor_position(), (2, 2));
+    }
+
+    #[test]
+    fn test_auto_wrap_at_right_margin() {
+        let mut ctrl = UartController::new();
+        ctrl.set_auto_wrap(true);
+        for _ in 0..RIGHT_MARGIN {
+            assert_eq!(ctrl.process_char(b'x'), b"x");
+        }
+        assert_eq!(ctrl.process_char(b'y'), b"\r\ny");
+    }
+
+    #[test]
+    fn test_auto_wrap_keeps_line_buffer() {
+        let mut ctrl = UartController::new();
+        ctrl.set_auto_wrap(true);
+        ctrl.set_wrap_width(2);
+        feed(&mut ctrl, b"abc");
+        assert_eq!(ctrl.line(), b"abc");
+    }
+
+    #[test]
+    fn test_auto_wrap_disabled_no_wrap() {
+        let mut ctrl = UartController::new();
+        ctrl.set_wrap_width(2);
+        assert_eq!(feed(&mut ctrl, b"abc"), b"c");
+    }
+
+    // ==================== Caesar Cipher Tests ====================
+
+    #[test]
+    fn test_caesar_default_identity() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.caesar(), 0);
+        assert_eq!(ctrl.process_char(b'a'), b"a");
+    }
+
+    #[test]
+    fn test_caesar_shift_three_echo() {
+        let mut ctrl = UartController::new();
+        ctrl.set_caesar(3);
+        assert_eq!(ctrl.process_char(b'a'), b"d");
+        assert_eq!(ctrl.process_char(b'z'), b"c");
+        assert_eq!(ctrl.process_char(b'X'), b"A");
+        assert_eq!(ctrl.process_char(b'!'), b"!");
+    }
+
+    #[test]
+    fn test_caesar_shift_reduced_modulo() {
+        let mut ctrl = UartController::new();
+        ctrl.set_caesar(29);
+        assert_eq!(ctrl.caesar(), 3);
+    }
+
+    #[test]
+    fn test_caesar_applies_to_buffer() {
+        let mut ctrl = UartController::new();
+        ctrl.set_caesar(1);
+        feed(&mut ctrl, b"HAL");
+        assert_eq!(ctrl.line(), b"IBM");
+    }
+
+    // ==================== Transform Chain Tests ====================
+
+    #[test]
+    fn test_transform_chain_upper_rot13() {
+        let mut ctrl = UartController::new();
+        ctrl.set_case_mode(CaseMode::Upper);
+        ctrl.set_caesar(13);
+        assert_eq!(
+            ctrl.transform_chain(),
+            TransformChain::new(None, CaseMode::Upper, 13, false)
+        );
+        assert_eq!(ctrl.process_char(b'a'), b"N");
+    }
+
+    #[test]
+    fn test_substitution_runs_before_cipher() {
+        let mut ctrl = UartController::new();
+        ctrl.set_substitution(Some((b'a', b'b')));
+        ctrl.set_caesar(1);
+        assert_eq!(ctrl.process_char(b'a'), b"c");
+        assert_eq!(ctrl.process_char(b'x'), b"y");
+    }
+
+    #[test]
+    fn test_bit_reverse_runs_last() {
+        let mut ctrl = UartController::new();
+        ctrl.set_bit_reverse(true);
+        ctrl.set_substitution(Some((b'a', 0x82)));
+        assert_eq!(ctrl.process_char(b'a'), b"A");
+    }
+
+    // ==================== Hex Escape Tests ====================
+
+    #[test]
+    fn test_hex_escapes_default_off() {
+        let mut ctrl = UartController::new();
+        assert!(!ctrl.show_hex_escapes());
+        assert_eq!(
+            ctrl.try_process_char(0xC3),
+            Err((UartError::UnsupportedByte(0xC3), &b""[..]))
+        );
+    }
+
+    #[test]
+    fn test_hex_escape_for_high_byte() {
+        let mut ctrl = UartController::new();
+        ctrl.set_show_hex_escapes(true);
+        assert_eq!(ctrl.process_char(0xC3), b"\\xC3");
+        assert_eq!(ctrl.process_char(0x80), b"\\x80");
+        assert_eq!(ctrl.process_char(0xFF), b"\\xFF");
+        assert_eq!(ctrl.line(), b"");
+    }
+
+    #[test]
+    fn test_hex_escape_lowercase() {
+        let mut ctrl = UartController::new();
+        ctrl.set_show_hex_escapes(true);
+        ctrl.set_hex_uppercase(false);
+        assert_eq!(ctrl.process_char(0xC3), b"\\xc3");
+    }
+
+    #[test]
+    fn test_hex_escapes_leave_ascii_unaffected() {
+        let mut ctrl = UartController::new();
+        ctrl.set_show_hex_escapes(true);
+        assert_eq!(ctrl.process_char(b'A'), b"A");
+        assert_eq!(ctrl.process_char(b'~'), b"~");
+        assert_eq!(ctrl.process_char(b'\\'), b"\\");
+        assert_eq!(ctrl.line(), b"A~\\");
+    }
+
+    #[test]
+    fn test_hex_escapes_silent_with_echo_off() {
+        let mut ctrl = UartController::new();
+        ctrl.set_show_hex_escapes(true);
+        ctrl.set_echo_enabled(false);
+        assert_eq!(ctrl.process_char(0xC3), b"");
+    }
+
+    // ==================== ASCII Value Mode Tests ====================
+
+    #[test]
+    fn test_ascii_value_mode_default_off() {
+        let ctrl = UartController::new();
+        assert!(!ctrl.ascii_value_mode());
+    }
+
+    #[test]
+    fn test_ascii_value_mode_letter() {
+        let mut ctrl = UartController::new();
+        ctrl.set_ascii_value_mode(true);
+        assert_eq!(ctrl.process_char(b'A'), b"65\r\n");
+    }
+
+    #[test]
+    fn test_ascii_value_mode_space() {
+        let mut ctrl = UartController::new();
+        ctrl.set_ascii_value_mode(true);
+        assert_eq!(ctrl.process_char(b' '), b"32\r\n");
+    }
+
+    #[test]
+    fn test_ascii_value_mode_enter() {
+        let mut ctrl = UartController::new();
+        ctrl.set_ascii_value_mode(true);
+        assert_eq!(ctrl.process_char(0x0D), b"13\r\n");
+    }
+
+    #[test]
+    fn test_ascii_value_mode_unsupported_byte() {
+        let mut ctrl = UartController::new();
+        ctrl.set_ascii_value_mode(true);
+        assert_eq!(ctrl.try_process_char(0xFF), Ok(&b"255\r\n"[..]));
+    }
+
+    // ==================== Form Control Tests ====================
+
+    #[test]
+    fn test_form_control_default_off() {
+        let ctrl = UartController::new();
+        assert!(!ctrl.form_control());
+    }
+
+    #[test]
+    fn test_form_control_disabled_drops_vt_and_ff() {
+        let mut ctrl = UartController::new();
+        assert_eq!(
+            ctrl.try_process_char(VERTICAL_TAB),
+            Err((UartError::UnsupportedByte(VERTICAL_TAB), &b""[..]))
+        );
+        assert_eq!(
+            ctrl.try_process_char(FORM_FEED),
+            Err((UartError::UnsupportedByte(FORM_FEED), &b""[..]))
+        );
+    }
+
+    #[test]
+    fn test_form_control_vt_emits_newline() {
+        let mut ctrl = UartController::new();
+        ctrl.set_form_control(true);
+        feed(&mut ctrl, b"ab");
+        assert_eq!(ctrl.process_char(VERTICAL_TAB), &VT_NEWLINE_SEQ);
+        assert_eq!(ctrl.last_line(), b"ab");
+    }
+
+    #[test]
+    fn test_form_control_ff_clears_screen() {
+        let mut ctrl = UartController::new();
+        ctrl.set_form_control(true);
+        feed(&mut ctrl, b"ab");
+        assert_eq!(ctrl.process_char(FORM_FEED), &CLEAR_SCREEN_SEQ);
+        assert_eq!(ctrl.cursor_position(), (1, 1));
+        assert_eq!(ctrl.line(), b"ab");
+    }
+
+    // ==================== Echo Suppression Tests ====================
+
+    #[test]
+    fn test_suppress_default_none() {
+        let ctrl = UartController::new();
+        assert_eq!(ctrl.suppress(), SuppressMask::NONE);
+    }
+
+    #[test]
+    fn test_suppress_digits_counts_but_silent() {
+        let mut ctrl = UartController::new();
+        ctrl.suppress_digits(true);
+        assert_eq!(ctrl.process_char(b'5'), b"");
+        assert_eq!(ctrl.echo_count(), 1);
+        assert_eq!(ctrl.process_char(b'a'), b"a");
+        assert_eq!(ctrl.line(), b"5a");
+    }
+
+    #[test]
+    fn test_suppress_restore() {
+        let mut ctrl = UartController::new();
+        ctrl.set_suppress(SuppressMask::LETTERS | SuppressMask::PUNCTUATION);
+        assert_eq!(ctrl.process_char(b'a'), b"");
+        assert_eq!(ctrl.process_char(b'!'), b"");
+        ctrl.suppress_letters(false);
+        assert_eq!(ctrl.process_char(b'a'), b"a");
+    }
+
+    #[test]
+    fn test_suppress_whitespace() {
+        let mut ctrl = UartController::new();
+        ctrl.suppress_whitespace(true);
+        assert_eq!(ctrl.process_char(b' '), b"");
+        ctrl.suppress_punctuation(true);
+        assert_eq!(ctrl.process_char(b'.'), b"");
+    }
+
+    // ==================== Echo Mute Tests ====================
+
+    #[test]
+    fn test_muted_withholds_echo_but_buffers() {
+        let mut ctrl = UartController::new();
+        ctrl.set_muted(true);
+        assert!(ctrl.muted());
+        assert_eq!(ctrl.process_char(b'a'), b"");
+        assert_eq!(ctrl.line(), b"a");
+        ctrl.set_muted(false);
+        assert_eq!(ctrl.process_char(b'b'), b"b");
+    }
+
+    #[test]
+    fn test_muted_keeps_command_output() {
+        let mut ctrl = UartController::new();
+        ctrl.set_muted(true);
+        assert_eq!(feed(&mut ctrl, b"\x1bcase upper\r"), b"\r\ncase: upper\r\n");
+    }
+
+    #[test]
+    fn test_command_executed_flag() {
+        let mut ctrl = UartController::new();
+        assert!(!ctrl.take_command_executed());
+        feed(&mut ctrl, b"\x1bbogus\r");
+        assert!(!ctrl.take_command_executed());
+        feed(&mut ctrl, b"\x1bquality\r");
+        assert!(ctrl.take_command_executed());
+        assert!(!ctrl.take_command_executed());
+    }
+
+    // ==================== Enter Handling Tests ====================
+
+    #[test]
+    fn test_enter_in_echo_mode_echoes_newline() {
+        let mut ctrl = UartController::new();
+        ctrl.set_newline_mode(NewlineMode::CrLf);
+        assert_eq!(feed(&mut ctrl, b"hi\r"), b"\r\n");
+        assert_eq!(ctrl.last_line(), b"hi");
+        assert_eq!(ctrl.mode(), Mode::Echo);
+    }
+
+    #[test]
+    fn test_enter_in_command_mode_dispatches_without_echo() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bstats");
+        let out = ctrl.process_char(b'\r').to_vec();
+        assert_eq!(out, b"\r\nrx: 7 echoed: 7 overruns: 0\r\n");
+        assert!(!out.starts_with(b"\r\r"));
+        assert_eq!(ctrl.mode(), Mode::Echo);
+        assert_eq!(ctrl.process_char(b'\n'), b"");
+    }
+
+    #[test]
+    fn test_enter_in_raw_mode_is_literal() {
+        let mut ctrl = UartController::new();
+        ctrl.set_terminal_mode(TerminalMode::Raw);
+        ctrl.set_newline_mode(NewlineMode::CrLf);
+        feed(&mut ctrl, b"ab");
+        assert_eq!(ctrl.process_char(b'\r'), b"\r");
+        assert_eq!(ctrl.process_char(b'\n'), b"\n");
+        assert_eq!(ctrl.line(), b"");
+        assert_eq!(ctrl.last_line(), b"");
+    }
+
+    #[test]
+    fn test_enter_in_raw_mode_silent_with_echo_off() {
+        let mut ctrl = UartController::new();
+        ctrl.set_terminal_mode(TerminalMode::Raw);
+        ctrl.set_echo_enabled(false);
+        assert_eq!(ctrl.process_char(b'\r'), b"");
+    }
+
+    // ==================== Terminal Mode Tests ====================
+
+    #[test]
+    fn test_terminal_mode_raw_disables_cooking() {
+        let mut ctrl = UartController::new();
+        ctrl.set_terminal_mode(TerminalMode::Raw);
+        assert!(!ctrl.line_buffering());
+        assert!(!ctrl.backspace_handling());
+        assert_eq!(ctrl.newline_mode(), NewlineMode::AsReceived);
+        assert_eq!(ctrl.process_char(b'a'), b"a");
+        assert_eq!(ctrl.process_char(BACKSPACE), &[BACKSPACE]);
+        assert_eq!(ctrl.process_char(b'\r'), b"\r");
+        assert_eq!(ctrl.line(), b"");
+        assert_eq!(ctrl.last_line(), b"");
+    }
+
+    #[test]
+    fn test_terminal_mode_cooked_enables_flags() {
+        let mut ctrl = UartController::new();
+        ctrl.set_terminal_mode(TerminalMode::Raw);
+        ctrl.set_terminal_mode(TerminalMode::Cooked);
+        assert!(ctrl.line_buffering());
+        assert!(ctrl.backspace_handling());
+        assert_eq!(ctrl.newline_mode(), NewlineMode::CrLf);
+        feed(&mut ctrl, b"ab\x7f");
+        assert_eq!(ctrl.process_char(b'\r'), b"\r\n");
+        assert_eq!(ctrl.last_line(), b"a");
+    }
+
+    #[test]
+    fn test_backspace_handling_off_keeps_line() {
+        let mut ctrl = UartController::new();
+        ctrl.set_backspace_handling(false);
+        feed(&mut ctrl, b"ab");
+        assert_eq!(ctrl.process_char(DELETE), &[DELETE]);
+        assert_eq!(ctrl.line(), b"ab");
+    }
+
+    // ==================== Telnet Negotiation Tests ====================
+
+    #[test]
+    fn test_telnet_default_off() {
+        let mut ctrl = UartController::new();
+        assert!(!ctrl.telnet());
+        assert_eq!(
+            ctrl.try_process_char(0xFF),
+            Err((UartError::UnsupportedByte(0xFF), &b""[..]))
+        );
+    }
+
+    #[test]
+    fn test_telnet_do_echo_refused() {
+        let mut ctrl = UartController::new();
+        ctrl.set_telnet(true);
+        assert_eq!(ctrl.process_char(0xFF), b"");
+        assert_eq!(ctrl.process_char(0xFD), b"");
+        assert_eq!(ctrl.process_char(0x01), &[0xFF, 0xFC, 0x01]);
+        assert_eq!(ctrl.line(), b"");
+        assert_eq!(ctrl.echo_count(), 0);
+    }
+
+    #[test]
+    fn test_telnet_data_after_negotiation() {
+        let mut ctrl = UartController::new();
+        ctrl.set_telnet(true);
+        feed(&mut ctrl, &[0xFF, 0xFB, 0x03]);
+        assert_eq!(ctrl.process_char(b'a'), b"a");
+        assert_eq!(ctrl.line(), b"a");
+    }
+
+    // ==================== Encoding Tests ====================
+
+    #[test]
+    fn test_encoding_default_ascii() {
+        let ctrl = UartController::new();
+        assert_eq!(ctrl.encoding(), Encoding::Ascii);
+    }
+
+    #[test]
+    fn test_ebcdic_echo_round_trip() {
+        let mut ctrl = UartController::new();
+        ctrl.set_encoding(Encoding::Ebcdic);
+        assert_eq!(ctrl.process_char(0xC1), &[0xC1]);
+        assert_eq!(ctrl.process_char(0x81), &[0x81]);
+        assert_eq!(ctrl.line(), b"Aa");
+        assert_eq!(ctrl.cursor_position(), (1, 3));
+    }
+
+    #[test]
+    fn test_ebcdic_command_output_translated() {
+        let mut ctrl = UartController::new();
+        ctrl.set_encoding(Encoding::Ebcdic);
+        let input: Vec<u8> = b"\x1bcase upper\r"
+            .iter()
+            .map(|&b| ebcdic::from_ascii(b))
+            .collect();
+        let out = feed(&mut ctrl, &input);
+        let text: Vec<u8> = out.iter().map(|&b| ebcdic::to_ascii(b)).collect();
+        assert_eq!(text, b"\r\ncase: upper\r\n");
+    }
+
+    // ==================== Parity Check Tests ====================
+
+    #[test]
+    fn test_parity_default_none() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.parity(), ParityMode::None);
+        assert!(ctrl.parity_drop());
+        assert_eq!(ctrl.parity_errors(), 0);
+        assert_eq!(ctrl.process_char(b'C'), b"C");
+    }
+
+    #[test]
+    fn test_even_parity_good_byte_passes() {
+        let mut ctrl = UartController::new();
+        ctrl.set_parity(ParityMode::Even);
+        assert_eq!(ctrl.process_char(b'C' | 0x80), b"C");
+        assert_eq!(ctrl.process_char(b'A'), b"A");
+        assert_eq!(ctrl.parity_errors(), 0);
+        assert_eq!(ctrl.line(), b"CA");
+    }
+
+    #[test]
+    fn test_even_parity_bad_byte_counted_and_dropped() {
+        let mut ctrl = UartController::new();
+        ctrl.set_parity(ParityMode::Even);
+        assert_eq!(
+            ctrl.try_process_char(b'C'),
+            Err((UartError::ParityError, &b""[..]))
+        );
+        assert_eq!(ctrl.process_char(b'A' | 0x80), b"");
+        assert_eq!(ctrl.parity_errors(), 2);
+        assert_eq!(ctrl.line(), b"");
+    }
+
+    #[test]
+    fn test_parity_bad_byte_kept_without_drop() {
+        let mut ctrl = UartController::new();
+        ctrl.set_parity(ParityMode::Odd);
+        ctrl.set_parity_drop(false);
+        assert_eq!(ctrl.process_char(b'A' | 0x80), b"A");
+        assert_eq!(ctrl.process_char(b'A'), b"A");
+        assert_eq!(ctrl.parity_errors(), 1);
+    }
+
+    // ==================== CRC Framing Tests ====================
+
+    #[test]
+    fn test_crc_framing_default_off() {
+        let ctrl = UartController::new();
+        assert!(!ctrl.crc_framing());
+    }
+
+    #[test]
+    fn test_crc_frame_accepted() {
+        let mut ctrl = UartController::new();
+        ctrl.set_crc_framing(true);
+        let mut frame = [0u8; 16];
+        let n = crate::frame::encode(b"hi", Endianness::Big, &mut frame).unwrap();
+        assert_eq!(feed(&mut ctrl, &frame[..n - 1]), b"");
+        assert_eq!(ctrl.process_char(frame[n - 1]), b"hi\x06");
+        assert_eq!(ctrl.line(), b"");
+    }
+
+    #[test]
+    fn test_crc_frame_flipped_bit_rejected() {
+        let mut ctrl = UartController::new();
+        ctrl.set_crc_framing(true);
+        let mut frame = [0u8; 16];
+        let n = crate::frame::encode(b"hi", Endianness::Big, &mut frame).unwrap();
+        frame[2] ^= 0x01;
+        feed(&mut ctrl, &frame[..n - 1]);
+        assert_eq!(
+            ctrl.try_process_char(frame[n - 1]),
+            Err((UartError::CorruptFrame, &[NAK][..]))
+        );
+    }
+
+    #[test]
+    fn test_crc_frame_little_endian() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.frame_endianness(), Endianness::Big);
+        ctrl.set_frame_endianness(Endianness::Little);
+        ctrl.set_crc_framing(true);
+        let mut frame = [0u8; 16];
+        let n = crate::frame::encode(b"hi", Endianness::Little, &mut frame).unwrap();
+        feed(&mut ctrl, &frame[..n - 1]);
+        assert_eq!(ctrl.process_char(frame[n - 1]), b"hi\x06");
+    }
+
+    #[test]
+    fn test_crc_frame_endianness_mismatch_rejected() {
+        let mut ctrl = UartController::new();
+        ctrl.set_crc_framing(true);
+        let mut frame = [0u8; 16];
+        crate::frame::encode(b"hi", Endianness::Little, &mut frame).unwrap();
+        assert!(ctrl.try_process_char(frame[0]).is_ok());
+        assert_eq!(
+            ctrl.try_process_char(frame[1]),
+            Err((UartError::CorruptFrame, &[NAK][..]))
+        );
+    }
+
+    // ==================== Smart Punctuation Tests ====================
+
+    #[test]
+    fn test_smart_punctuation_default_off() {
+        let mut ctrl = UartController::new();
+        assert!(!ctrl.smart_punctuation());
+        assert_eq!(
+            ctrl.try_process_char(0xE2),
+            Err((UartError::UnsupportedByte(0xE2), &b""[..]))
+        );
+    }
+
+    #[test]
+    fn test_curly_quotes_echo_straight() {
+        let mut ctrl = UartController::new();
+        ctrl.set_smart_punctuation(true);
+        let mut out = Vec::new();
+        for &b in "\u{201C}hi\u{201D} it\u{2019}s".as_bytes() {
+            out.extend_from_slice(ctrl.process_char(b));
+        }
+        assert_eq!(out, b"\"hi\" it's");
+        assert_eq!(ctrl.line(), b"\"hi\" it's");
+    }
+
+    #[test]
+    fn test_em_dash_echoes_hyphen() {
+        let mut ctrl = UartController::new();
+        ctrl.set_smart_punctuation(true);
+        assert_eq!(feed(&mut ctrl, "\u{2014}".as_bytes()), b"-");
+    }
+
+    #[test]
+    fn test_other_multibyte_rejected() {
+        let mut ctrl = UartController::new();
+        ctrl.set_smart_punctuation(true);
+        assert_eq!(ctrl.try_process_char(0xC3), Ok(&b""[..]));
+        assert_eq!(
+            ctrl.try_process_char(0xA9),
+            Err((UartError::UnsupportedByte(0xA9), &b""[..]))
+        );
+    }
+
+    // ==================== Hex Entry Tests ====================
+
+    #[test]
+    fn test_hex_input_default_off() {
+        let ctrl = UartController::new();
+        assert!(!ctrl.hex_input());
+    }
+
+    #[test]
+    fn test_hex_input_spaced_echoes_bytes() {
+        let mut ctrl = UartController::new();
+        ctrl.set_hex_input(true);
+        assert_eq!(feed(&mut ctrl, b"41 4"), b"4");
+        assert_eq!(feed(&mut ctrl, b"2\r"), b"\r\nAB\r\n");
+    }
+
+    #[test]
+    fn test_hex_input_packed_echoes_bytes() {
+        let mut ctrl = UartController::new();
+        ctrl.set_hex_input(true);
+        assert_eq!(feed(&mut ctrl, b"4142\r"), b"\r\nAB\r\n");
+        assert_eq!(feed(&mut ctrl, b"\n"), b"");
+    }
+
+    #[test]
+    fn test_hex_input_rejects_non_hex_with_bell() {
+        let mut ctrl = UartController::new();
+        ctrl.set_hex_input(true);
+        assert_eq!(
+            ctrl.try_process_char(b'z'),
+            Err((UartError::UnsupportedByte(b'z'), &[BELL][..]))
+        );
+        assert_eq!(ctrl.process_char(b'x'), &[BELL]);
+    }
+
+    #[test]
+    fn test_hex_input_enter_with_half_byte_rings_bell() {
+        let mut ctrl = UartController::new();
+        ctrl.set_hex_input(true);
+        assert_eq!(feed(&mut ctrl, b"414\r"), &[BELL]);
+        assert_eq!(feed(&mut ctrl, b"2\r"), b"\r\nAB\r\n");
+    }
+
+    #[test]
+    fn test_hex_input_backspace_erases_digit() {
+        let mut ctrl = UartController::new();
+        ctrl.set_hex_input(true);
+        assert_eq!(feed(&mut ctrl, b"4\x08"), &BACKSPACE_SEQ);
+        assert_eq!(feed(&mut ctrl, b"\x08"), b"");
+        assert_eq!(feed(&mut ctrl, b"41\r"), b"\r\nA\r\n");
+    }
+
+    // ==================== Run-Length Input Tests ====================
+
+    #[test]
+    fn test_rle_input_default_off() {
+        let ctrl = UartController::new();
+        assert!(!ctrl.rle_input());
+    }
+
+    #[test]
+    fn test_rle_input_echoes_expanded() {
+        let mut ctrl = UartController::new();
+        ctrl.set_rle_input(true);
+        assert_eq!(feed(&mut ctrl, b"\x03A"), b"AAA");
+        assert_eq!(feed(&mut ctrl, b"\x02B"), b"BB");
+        assert_eq!(ctrl.line(), b"AAABB");
+    }
+
+    #[test]
+    fn test_rle_input_zero_count_echoes_nothing() {
+        let mut ctrl = UartController::new();
+        ctrl.set_rle_input(true);
+        assert_eq!(feed(&mut ctrl, b"\x00A"), b"");
+        assert_eq!(ctrl.line(), b"");
+    }
+
+    #[test]
+    fn test_rle_input_run_longer_than_chunk() {
+        let mut ctrl = UartController::new();
+        ctrl.set_rle_input(true);
+        feed(&mut ctrl, b"\x28x");
+        assert_eq!(ctrl.line(), &[b'x'; 40][..]);
+    }
+
+    #[test]
+    fn test_rle_input_run_continues_past_error() {
+        let mut ctrl = UartController::<4>::with_capacity();
+        ctrl.set_rle_input(true);
+        assert_eq!(feed(&mut ctrl, b"\x06A"), b"AAAA\x07\x07");
+        assert_eq!(ctrl.line(), b"AAAA");
+    }
+
+    #[test]
+    fn test_rle_input_budget_runs_out_mid_run() {
+        let mut ctrl = UartController::new();
+        ctrl.set_rle_input(true);
+        ctrl.set_byte_budget(Some(3));
+        assert_eq!(feed(&mut ctrl, b"\x05A"), b"AAAlimit reached\r\n");
+        assert_eq!(ctrl.line(), b"AAA");
+        ctrl.reset();
+        assert_eq!(feed(&mut ctrl, b"\x01B"), b"B");
+        assert_eq!(ctrl.line(), b"AAAB");
+    }
+
+    // ==================== Input Sanitizing Tests ====================
+
+    #[test]
+    fn test_sanitize_default_off() {
+        let mut ctrl = UartController::new();
+        assert!(!ctrl.sanitize_input());
+        assert_eq!(
+            ctrl.try_process_char(0x00),
+            Err((UartError::UnsupportedByte(0x00), &b""[..]))
+        );
+    }
+
+    #[test]
+    fn test_sanitize_drops_controls_in_paste() {
+        let mut ctrl = UartController::new();
+        ctrl.set_sanitize_input(true);
+        let echoed: Vec<u8> = b"a\x00b\x01c"
+            .iter()
+            .flat_map(|&b| ctrl.try_process_char(b).unwrap().to_vec())
+            .collect();
+        assert_eq!(echoed, b"abc");
+        assert_eq!(ctrl.line(), b"abc");
+        assert_eq!(ctrl.noise_count(), 2);
+        assert_eq!(ctrl.echo_count(), 3);
+    }
+
+    #[test]
+    fn test_sanitize_keeps_editing_keys() {
+        let mut ctrl = UartController::new();
+        ctrl.set_sanitize_input(true);
+        feed(&mut ctrl, b"ab\x08\tc\r");
+        assert_eq!(ctrl.last_line(), b"a\tc");
+        assert_eq!(ctrl.noise_count(), 0);
+    }
+
+    // ==================== Line Buffer Tests ====================
+
+    #[test]
+    fn test_chars_buffered_until_enter() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"ab");
+        assert_eq!(ctrl.line(), b"ab");
+        feed(&mut ctrl, b"\r\n");
+        assert_eq!(ctrl.line(), b"");
+    }
+
+    #[test]
+    fn test_backspace_removes_buffered_char() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"abc\x7f");
+        assert_eq!(ctrl.line(), b"ab");
+    }
+
+    #[test]
+    fn test_line_overflow_rings_bell() {
+        let mut ctrl = UartController::new();
+        for _ in 0..LINE_BUF_SIZE {
+            ctrl.process_char(b'x');
+        }
+        assert_eq!(
+            ctrl.try_process_char(b'y'),
+            Err((UartError::LineOverflow, &[BELL][..]))
+        );
+        assert_eq!(ctrl.process_char(b'y'), &[BELL]);
+        assert_eq!(ctrl.line().len(), LINE_BUF_SIZE);
+    }
+
+    // ==================== Ghost Typing Tests ====================
+
+    #[test]
+    fn test_ghost_typing_default_off() {
+        let ctrl = UartController::new();
+        assert!(!ctrl.ghost_typing());
+    }
+
+    #[test]
+    fn test_ghost_delays_reproducible_from_seed() {
+        let mut a = UartController::new();
+        let mut b = UartController::new();
+        a.set_ghost_seed(99);
+        b.set_ghost_seed(99);
+        let first: Vec<u32> = (0..8).map(|_| a.next_delay()).collect();
+        let second: Vec<u32> = (0..8).map(|_| b.next_delay()).collect();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_ghost_reseed_restarts_sequence() {
+        let mut ctrl = UartController::new();
+        let first: Vec<u32> = (0..4).map(|_| ctrl.next_delay()).collect();
+        ctrl.set_ghost_seed(GHOST_SEED);
+        let again: Vec<u32> = (0..4).map(|_| ctrl.next_delay()).collect();
+        assert_eq!(first, again);
+    }
+
+    // ==================== CRLF Mode Tests ====================
+
+    #[test]
+    fn test_crlf_mode_default_collapse() {
+        let ctrl = UartController::new();
+        assert_eq!(ctrl.crlf_mode(), CrLfMode::Collapse);
+    }
+
+    #[test]
+    fn test_crlf_collapse_commits_once() {
+        let mut ctrl = UartController::new();
+        ctrl.set_line_numbers(true);
+        assert_eq!(feed(&mut ctrl, b"a\r"), b"\r  1: a\r\n");
+        assert_eq!(ctrl.process_char(b'\n'), b"\n");
+        assert_eq!(feed(&mut ctrl, b"b\r"), b"\r  2: b\r\n");
+    }
+
+    #[test]
+    fn test_crlf_separate_commits_twice() {
+        let mut ctrl = UartController::new();
+        ctrl.set_crlf_mode(CrLfMode::Separate);
+        ctrl.set_line_numbers(true);
+        assert_eq!(feed(&mut ctrl, b"a\r"), b"\r  1: a\r\n");
+        assert_eq!(ctrl.process_char(b'\n'), b"\n  2: \r\n");
+        assert_eq!(ctrl.last_line(), b"");
+    }
+
+    #[test]
+    fn test_crlf_separate_lone_endings() {
+        let mut ctrl = UartController::new();
+        ctrl.set_crlf_mode(CrLfMode::Separate);
+        ctrl.set_line_numbers(true);
+        assert_eq!(feed(&mut ctrl, b"x\n"), b"\n  1: x\r\n");
+        assert_eq!(feed(&mut ctrl, b"y\r"), b"\r  2: y\r\n");
+    }
+
+    // ==================== Bell Mode Tests ====================
+
+    /// Overflows a small line so the next byte rings the bell.
+    fn ring(mode: BellMode) -> Vec<u8> {
+        let mut ctrl = UartController::<2>::with_capacity();
+        ctrl.set_bell_mode(mode);
+        feed(&mut ctrl, b"ab");
+        ctrl.process_char(b'c').to_vec()
+    }
+
+    #[test]
+    fn test_bell_mode_default_audible() {
+        let ctrl = UartController::new();
+        assert_eq!(ctrl.bell_mode(), BellMode::Audible);
+    }
+
+    #[test]
+    fn test_bell_mode_audible() {
+        assert_eq!(ring(BellMode::Audible), [BELL]);
+    }
+
+    #[test]
+    fn test_bell_mode_visible() {
+        assert_eq!(ring(BellMode::Visible), VISIBLE_BELL_SEQ);
+    }
+
+    #[test]
+    fn test_bell_mode_both() {
+        let mut both = vec![BELL];
+        both.extend_from_slice(&VISIBLE_BELL_SEQ);
+        assert_eq!(ring(BellMode::Both), both);
+    }
+
+    #[test]
+    fn test_bell_mode_applies_to_hex_entry() {
+        let mut ctrl = UartController::new();
+        ctrl.set_bell_mode(BellMode::Visible);
+        ctrl.set_hex_input(true);
+        assert_eq!(ctrl.process_char(b'x'), VISIBLE_BELL_SEQ);
+    }
+
+    #[test]
+    fn test_default_controller_capacity() {
+        let mut ctrl = DefaultController::new();
+        feed(&mut ctrl, &[b'x'; LINE_BUF_SIZE]);
+        assert_eq!(
+            ctrl.try_process_char(b'y'),
+            Err((UartError::LineOverflow, &[BELL][..]))
+        );
+    }
+
+    #[test]
+    fn test_small_capacity_overflow() {
+        let mut ctrl = UartController::<8>::with_capacity();
+        feed(&mut ctrl, b"12345678");
+        assert_eq!(ctrl.line(), b"12345678");
+        assert_eq!(
+            ctrl.try_process_char(b'9'),
+            Err((UartError::LineOverflow, &[BELL][..]))
+        );
+        assert_eq!(ctrl.process_char(b'9'), &[BELL]);
+        assert_eq!(ctrl.line(), b"12345678");
+    }
+
+    #[test]
+    fn test_large_capacity_overflow() {
+        let mut ctrl = UartController::<149>::with_capacity();
+        feed(&mut ctrl, &[b'x'; 130]);
+        assert_eq!(ctrl.line().len(), 130);
+        feed(&mut ctrl, &[b'x'; 19]);
+        assert_eq!(
+            ctrl.try_process_char(b'y'),
+            Err((UartError::LineOverflow, &[BELL][..]))
+        );
+        assert_eq!(ctrl.line().len(), 149);
+    }
+
+    #[test]
+    fn test_largest_capacity_full_dump() {
+        let mut ctrl = UartController::<149>::with_capacity();
+        feed(&mut ctrl, &[b'A'; 149]);
+        let out = feed(&mut ctrl, b"\x1bdump\r");
+        assert_eq!(out.len(), 2 + 149 * 3 - 1 + 2);
+        assert!(out[2..out.len() - 2].chunks(3).all(|c| &c[..2] == b"41"));
+        assert!(out.ends_with(b"41\r\n"));
+    }
+
+    #[test]
+    fn test_large_capacity_b64() {
+        let mut ctrl = UartController::<149>::with_capacity();
+        ctrl.set_max_cmd_len(149);
+        let mut line = b"\x1bb64 ".to_vec();
+        line.extend_from_slice(&[b'A'; 140]);
+        line.extend_from_slice(b"Zg==\r");
+        assert_eq!(feed(&mut ctrl, &line), b"\r\ndecoded 106 bytes\r\n");
+    }
+
+    #[test]
+    fn test_b64_padding_before_end_rejected() {
+        let mut ctrl = UartController::<149>::with_capacity();
+        ctrl.set_max_cmd_len(149);
+        let mut line = b"\x1bb64 ".to_vec();
+        line.extend_from_slice(&[b'A'; 124]);
+        line.extend_from_slice(b"Zg==Zm9v\r");
+        assert_eq!(feed(&mut ctrl, &line), b"\r\ninvalid base64\r\n");
+    }
+
+    #[test]
+    fn test_commit_saves_last_line() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"hi\r\n");
+        assert_eq!(ctrl.last_line(), b"hi");
+    }
+
+    // ==================== Command Length Tests ====================
+
+    #[test]
+    fn test_max_cmd_len_default() {
+        let ctrl = UartController::new();
+        assert_eq!(ctrl.max_cmd_len(), MAX_CMD_LEN);
+    }
+
+    #[test]
+    fn test_command_mode_limit_rings_bell() {
+        let mut ctrl = UartController::new();
+        ctrl.set_max_cmd_len(4);
+        feed(&mut ctrl, b"\x1bcalc");
+        assert_eq!(ctrl.line(), b"calc");
+        assert_eq!(
+            ctrl.try_process_char(b' '),
+            Err((UartError::LineOverflow, &[BELL][..]))
+        );
+        assert_eq!(ctrl.process_char(b'1'), &[BELL]);
+        assert_eq!(ctrl.line(), b"calc");
+    }
+
+    #[test]
+    fn test_command_limit_independent_of_line_capacity() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1b");
+        feed(&mut ctrl, &[b'a'; MAX_CMD_LEN + 10]);
+        assert_eq!(ctrl.line().len(), MAX_CMD_LEN);
+    }
+
+    #[test]
+    fn test_echo_mode_uses_line_limit() {
+        let mut ctrl = UartController::new();
+        ctrl.set_max_cmd_len(4);
+        feed(&mut ctrl, &[b'a'; LINE_BUF_SIZE + 10]);
+        assert_eq!(ctrl.line().len(), LINE_BUF_SIZE);
+    }
+
+    // ==================== Secondary Pipeline Tests ====================
+
+    #[test]
+    fn test_pipeline_default_copies_line() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.pipeline_case(), CaseMode::AsIs);
+        feed(&mut ctrl, b"aB");
+        assert_eq!(ctrl.transformed_line(), b"aB");
+    }
+
+    #[test]
+    fn test_pipeline_uppercase_decoupled_from_echo() {
+        let mut ctrl = UartController::new();
+        ctrl.set_pipeline_case(CaseMode::Upper);
+        assert_eq!(ctrl.process_char(b'a'), b"a");
+        assert_eq!(ctrl.process_char(b'B'), b"B");
+        assert_eq!(ctrl.line(), b"aB");
+        assert_eq!(ctrl.transformed_line(), b"AB");
+    }
+
+    #[test]
+    fn test_pipeline_follows_backspace() {
+        let mut ctrl = UartController::new();
+        ctrl.set_pipeline_case(CaseMode::Upper);
+        feed(&mut ctrl, b"ab\x08c");
+        assert_eq!(ctrl.transformed_line(), b"AC");
+    }
+
+    // ==================== Line Timing Tests ====================
+
+    #[test]
+    fn test_line_timing_default_off() {
+        let mut ctrl = UartController::new();
+        assert!(!ctrl.line_timing());
+        feed(&mut ctrl, b"a\r");
+        assert!(!ctrl.take_line_committed());
+    }
+
+    #[test]
+    fn test_line_interval_first_line_zero() {
+        let ctrl = UartController::new();
+        assert_eq!(ctrl.line_interval_ms(1234), 0);
+    }
+
+    #[test]
+    fn test_line_interval_since_previous() {
+        let mut ctrl = UartController::new();
+        ctrl.line_timing_report(1000);
+        assert_eq!(ctrl.line_interval_ms(1250), 250);
+    }
+
+    #[test]
+    fn test_line_interval_clock_behind_is_zero() {
+        let mut ctrl = UartController::new();
+        ctrl.line_timing_report(1000);
+        assert_eq!(ctrl.line_interval_ms(900), 0);
+    }
+
+    #[test]
+    fn test_line_timing_flags_commit() {
+        let mut ctrl = UartController::new();
+        ctrl.set_line_timing(true);
+        feed(&mut ctrl, b"ab");
+        assert!(!ctrl.take_line_committed());
+        feed(&mut ctrl, b"\r");
+        assert!(ctrl.take_line_committed());
+        assert!(!ctrl.take_line_committed());
+    }
+
+    #[test]
+    fn test_line_timing_report_format() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.line_timing_report(500), b"[+0ms]\r\n");
+        assert_eq!(ctrl.line_timing_report(742), b"[+242ms]\r\n");
+    }
+
+    // ==================== Timestamp Line Tests ====================
+
+    #[test]
+    fn test_timestamp_lines_default_off() {
+        let mut ctrl = UartController::new();
+        assert!(!ctrl.timestamp_lines());
+        feed(&mut ctrl, b"a\r");
+        assert!(!ctrl.take_line_committed());
+    }
+
+    #[test]
+    fn test_timestamped_line_format() {
+        let mut ctrl = UartController::new();
+        ctrl.set_timestamp_lines(true);
+        feed(&mut ctrl, b"line\r");
+        assert!(ctrl.take_line_committed());
+        assert_eq!(ctrl.timestamped_line(12345), b"[00012345] line\r\n");
+    }
+
+    // ==================== Event Callback Tests ====================
+
+    static COMMITTED: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+    static MODES: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+    static COMMANDS: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+    static OVERFLOWS: Mutex<Vec<usize>> = Mutex::new(Vec::new());
+
+    fn on_commit(line: &[u8]) {
+        COMMITTED.lock().unwrap().extend_from_slice(line);
+    }
+
+    fn on_mode(name: &[u8]) {
+        let mut modes = MODES.lock().unwrap();
+        modes.extend_from_slice(name);
+        modes.push(b',');
+    }
+
+    fn on_command(line: &[u8]) {
+        COMMANDS.lock().unwrap().extend_from_slice(line);
+    }
+
+    fn on_overflow(line: &[u8]) {
+        OVERFLOWS.lock().unwrap().push(line.len());
+    }
+
+    #[test]
+    fn test_callback_line_committed() {
+        let mut ctrl = UartController::new();
+        ctrl.set_callback(Event::LineCommitted, Some(on_commit));
+        feed(&mut ctrl, b"hello");
+        assert!(COMMITTED.lock().unwrap().is_empty());
+        feed(&mut ctrl, b"\r");
+        assert_eq!(COMMITTED.lock().unwrap().as_slice(), b"hello");
+    }
+
+    #[test]
+    fn test_callback_mode_and_command() {
+        let mut ctrl = UartController::new();
+        ctrl.set_callback(Event::ModeChanged, Some(on_mode));
+        ctrl.set_callback(Event::CommandRecognized, Some(on_command));
+        feed(&mut ctrl, b"\x1b dump \r");
+        assert_eq!(MODES.lock().unwrap().as_slice(), b"command,echo,");
+        assert_eq!(COMMANDS.lock().unwrap().as_slice(), b"dump");
+    }
+
+    #[test]
+    fn test_callback_overflow() {
+        let mut ctrl = UartController::<4>::with_capacity();
+        ctrl.set_callback(Event::Overflow, Some(on_overflow));
+        feed(&mut ctrl, b"abcde");
+        assert_eq!(OVERFLOWS.lock().unwrap().as_slice(), &[4]);
+    }
+
+    #[test]
+    fn test_callback_cleared() {
+        let mut ctrl = UartController::new();
+        ctrl.set_callback(Event::LineCommitted, Some(on_commit));
+        ctrl.set_callback(Event::LineCommitted, None);
+        assert_eq!(ctrl, UartController::new());
+    }
+
+    // ==================== Uniq Tests ====================
+
+    #[test]
+    fn test_uniq_default_off() {
+        let ctrl = UartController::new();
+        assert!(!ctrl.uniq());
+    }
+
+    #[test]
+    fn test_uniq_collapses_repeats() {
+        let mut ctrl = UartController::new();
+        ctrl.set_uniq(true);
+        let mut out = Vec::new();
+        for &b in b"a\ra\ra\rb\r" {
+            out.extend_from_slice(ctrl.process_char(b));
+        }
+        assert_eq!(out, b"a (repeated 3 times)\r\nb");
+    }
+
+    #[test]
+    fn test_uniq_distinct_lines_not_annotated() {
+        let mut ctrl = UartController::new();
+        ctrl.set_uniq(true);
+        let mut out = Vec::new();
+        for &b in b"ab\rcd\rab\r" {
+            out.extend_from_slice(ctrl.process_char(b));
+        }
+        assert_eq!(out, b"ab\r\ncd\r\nab");
+    }
+
+    // ==================== Line Number Tests ====================
+
+    #[test]
+    fn test_line_numbers_default_off() {
+        let mut ctrl = UartController::new();
+        assert!(!ctrl.line_numbers());
+        assert_eq!(ctrl.line_number_width(), LINE_NUMBER_WIDTH);
+        assert_eq!(feed(&mut ctrl, b"a\r"), b"\r");
+    }
+
+    #[test]
+    fn test_line_numbers_increment_with_padding() {
+        let mut ctrl = UartController::new();
+        ctrl.set_line_numbers(true);
+        assert_eq!(feed(&mut ctrl, b"hello\r"), b"\r  1: hello\r\n");
+        assert_eq!(feed(&mut ctrl, b"b\r"), b"\r  2: b\r\n");
+        assert_eq!(feed(&mut ctrl, b"c\r"), b"\r  3: c\r\n");
+    }
+
+    // ==================== Reverse Lines Tests ====================
+
+    #[test]
+    fn test_reverse_lines_default_off() {
+        let mut ctrl = UartController::new();
+        assert!(!ctrl.reverse_lines());
+        assert_eq!(feed(&mut ctrl, b"hello\r"), b"\r");
+    }
+
+    #[test]
+    fn test_reverse_lines_echoes_reversed() {
+        let mut ctrl = UartController::new();
+        ctrl.set_reverse_lines(true);
+        assert_eq!(feed(&mut ctrl, b"hello\r"), b"\rolleh\r\n");
+        assert_eq!(ctrl.last_line(), b"hello");
+    }
+
+    #[test]
+    fn test_reverse_lines_empty_line() {
+        let mut ctrl = UartController::new();
+        ctrl.set_reverse_lines(true);
+        assert_eq!(ctrl.process_char(b'\r'), b"\r\r\n");
+    }
+
+    #[test]
+    fn test_reverse_lines_with_line_numbers() {
+        let mut ctrl = UartController::new();
+        ctrl.set_reverse_lines(true);
+        ctrl.set_line_numbers(true);
+        assert_eq!(feed(&mut ctrl, b"abc\r"), b"\r  1: cba\r\n");
+    }
+
+    // ==================== Indent Tests ====================
+
+    #[test]
+    fn test_indent_default_zero() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.indent(), 0);
+        assert_eq!(feed(&mut ctrl, b"a\r"), b"\r");
+    }
+
+    #[test]
+    fn test_indent_four_prefixes_four_spaces() {
+        let mut ctrl = UartController::new();
+        ctrl.set_indent(4);
+        assert_eq!(feed(&mut ctrl, b"hi\r"), b"\r    hi\r\n");
+    }
+
+    #[test]
+    fn test_indent_with_line_numbers() {
+        let mut ctrl = UartController::new();
+        ctrl.set_indent(2);
+        ctrl.set_line_numbers(true);
+        assert_eq!(feed(&mut ctrl, b"x\r"), b"\r    1: x\r\n");
+    }
+
+    #[test]
+    fn test_indent_commands_adjust() {
+        let mut ctrl = UartController::new();
+        assert_eq!(feed(&mut ctrl, b"\x1bindent+\r"), b"\r\nindent: 1\r\n");
+        feed(&mut ctrl, b"\x1bindent+\r");
+        assert_eq!(ctrl.indent(), 2);
+        assert_eq!(feed(&mut ctrl, b"\x1bindent-\r"), b"\r\nindent: 1\r\n");
+    }
+
+    #[test]
+    fn test_indent_down_clamps_at_zero() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bindent-\r");
+        assert_eq!(feed(&mut ctrl, b"\x1bindent-\r"), b"\r\nindent: 0\r\n");
+        assert_eq!(ctrl.indent(), 0);
+    }
+
+    #[test]
+    fn test_indent_clamps_at_max() {
+        let mut ctrl = UartController::new();
+        ctrl.set_indent(u8::MAX);
+        assert_eq!(ctrl.indent(), MAX_INDENT);
+    }
+
+    #[test]
+    fn test_line_number_width_configurable() {
+        let mut ctrl = UartController::new();
+        ctrl.set_line_numbers(true);
+        ctrl.set_line_number_width(5);
+        assert_eq!(feed(&mut ctrl, b"x\r"), b"\r    1: x\r\n");
+        ctrl.set_line_number_width(0);
+        assert_eq!(ctrl.line_number_width(), 1);
+    }
+
+    #[test]
+    fn test_line_numbers_crlf_numbers_once() {
+        let mut ctrl = UartController::new();
+        ctrl.set_line_numbers(true);
+        feed(&mut ctrl, b"a\r");
+        assert_eq!(ctrl.process_char(b'\n'), b"\n");
+        assert_eq!(feed(&mut ctrl, b"b\r"), b"\r  2: b\r\n");
+    }
+
+    // ==================== Login Gate Tests ====================
+
+    #[test]
+    fn test_no_password_starts_in_echo() {
+        let ctrl = UartController::new();
+        assert_eq!(ctrl.mode(), Mode::Echo);
+        assert_eq!(ctrl.session_count(), 0);
+    }
+
+    #[test]
+    fn test_login_hides_password() {
+        let mut ctrl = UartController::new();
+        ctrl.set_password(Some(b"pw"));
+        assert_eq!(ctrl.mode(), Mode::Login);
+        assert_eq!(ctrl.process_char(b'p'), b"");
+        assert_eq!(ctrl.process_char(b'w'), b"");
+    }
+
+    #[test]
+    fn test_login_wrong_password() {
+        let mut ctrl = UartController::new();
+        ctrl.set_password(Some(b"pw"));
+        feed(&mut ctrl, b"px");
+        assert_eq!(
+            ctrl.try_process_char(b'\r'),
+            Err((UartError::InvalidArgument, &b"login incorrect\r\n"[..]))
+        );
+        assert_eq!(ctrl.mode(), Mode::Login);
+        assert_eq!(ctrl.session_count(), 0);
+    }
+
+    #[test]
+    fn test_login_greets_with_session_count() {
+        let mut ctrl = UartController::new();
+        ctrl.set_password(Some(b"pw"));
+        assert_eq!(feed(&mut ctrl, b"pw\r"), b"Welcome, session #1\r\n");
+        assert_eq!(ctrl.mode(), Mode::Echo);
+        feed(&mut ctrl, b"abc");
+        ctrl.lock();
+        assert_eq!(ctrl.line(), b"");
+        assert_eq!(feed(&mut ctrl, b"pw\r"), b"Welcome, session #2\r\n");
+        assert_eq!(ctrl.session_count(), 2);
+    }
+
+    #[test]
+    fn test_login_custom_greeting() {
+        let mut ctrl = UartController::new();
+        ctrl.set_greeting(b"Hello");
+        ctrl.set_password(Some(b"pw"));
+        assert_eq!(feed(&mut ctrl, b"pw\r"), b"Hello, session #1\r\n");
+        assert_eq!(ctrl.process_char(b'\n'), b"");
+    }
+
+    #[test]
+    fn test_clearing_password_unlocks() {
+        let mut ctrl = UartController::new();
+        ctrl.set_password(Some(b"pw"));
+        ctrl.set_password(None);
+        assert_eq!(ctrl.mode(), Mode::Echo);
+    }
+
+    // ==================== Privilege Tests ====================
+
+    #[test]
+    fn test_default_privilege_admin() {
+        let ctrl = UartController::new();
+        assert_eq!(ctrl.privilege(), Privilege::Admin);
+    }
+
+    #[test]
+    fn test_monitor_login_denies_privileged_command() {
+        let mut ctrl = UartController::new();
+        ctrl.set_password(Some(b"admin"));
+        ctrl.set_monitor_password(Some(b"view"));
+        feed(&mut ctrl, b"view\r");
+        assert_eq!(ctrl.privilege(), Privilege::Monitor);
+        assert_eq!(
+            feed(&mut ctrl, b"\x1bRESET\r"),
+            b"\r\npermission denied\r\n"
+        );
+        assert!(!ctrl.take_reset_request());
+        feed(&mut ctrl, b"\x1bcase upper");
+        assert_eq!(
+            ctrl.try_process_char(b'\r'),
+            Err((
+                UartError::PermissionDenied,
+                &b"\r\npermission denied\r\n"[..]
+            ))
+        );
+    }
+
+    #[test]
+    fn test_monitor_login_allows_read_only_command() {
+        let mut ctrl = UartController::new();
+        ctrl.set_password(Some(b"admin"));
+        ctrl.set_monitor_password(Some(b"view"));
+        feed(&mut ctrl, b"view\r");
+        assert_eq!(feed(&mut ctrl, b"\x1bstatus\r"), b"\r\n");
+        assert!(ctrl.take_status_request());
+    }
+
+    #[test]
+    fn test_admin_login_allows_privileged_command() {
+        let mut ctrl = UartController::new();
+        ctrl.set_password(Some(b"admin"));
+        ctrl.set_monitor_password(Some(b"view"));
+        feed(&mut ctrl, b"admin\r");
+        assert_eq!(ctrl.privilege(), Privilege::Admin);
+        feed(&mut ctrl, b"\x1bRESET\r");
+        assert!(ctrl.take_reset_request());
+    }
+
+    // ==================== Paragraph Mode Tests ====================
+
+    #[test]
+    fn test_paragraph_mode_default_off() {
+        let ctrl = UartController::new();
+        assert!(!ctrl.paragraph_mode());
+    }
+
+    #[test]
+    fn test_paragraph_single_enter_buffers_newline() {
+        let mut ctrl = UartController::new();
+        ctrl.set_paragraph_mode(true);
+        feed(&mut ctrl, b"one\r");
+        assert_eq!(ctrl.line(), b"one\n");
+        assert_eq!(ctrl.last_line(), b"");
+    }
+
+    #[test]
+    fn test_paragraph_escape_clears_pending_newline() {
+        let mut ctrl = UartController::new();
+        ctrl.set_paragraph_mode(true);
+        feed(&mut ctrl, b"a\r\x1b\x1b\r");
+        assert_eq!(ctrl.mode(), Mode::Echo);
+        assert_eq!(ctrl.line(), b"");
+        ctrl.process_char(b'\r');
+        assert_eq!(ctrl.line(), b"\n");
+        ctrl.process_char(b'\r');
+        assert_eq!(ctrl.line(), b"");
+        assert_eq!(ctrl.last_line(), b"");
+    }
+
+    #[test]
+    fn test_paragraph_double_enter_commits() {
+        let mut ctrl = UartController::new();
+        ctrl.set_paragraph_mode(true);
+        feed(&mut ctrl, b"one\rtwo\r\r");
+        assert_eq!(ctrl.last_line(), b"one\ntwo");
+        assert_eq!(ctrl.line(), b"");
+    }
+
+    #[test]
+    fn test_paragraph_crlf_counts_as_one_enter() {
+        let mut ctrl = UartController::new();
+        ctrl.set_paragraph_mode(true);
+        feed(&mut ctrl, b"one\r\ntwo\r\n\r\n");
+        assert_eq!(ctrl.last_line(), b"one\ntwo");
+    }
+
+    #[test]
+    fn test_paragraph_backspace_resets_blank_line() {
+        let mut ctrl = UartController::new();
+        ctrl.set_paragraph_mode(true);
+        feed(&mut ctrl, b"ab\r\x08\r");
+        assert_eq!(ctrl.line(), b"ab\n");
+        assert_eq!(ctrl.last_line(), b"");
+    }
+
+    // ==================== Command Mode Tests ====================
+
+    #[test]
+    fn test_starts_in_echo_mode() {
+        let ctrl = UartController::new();
+        assert_eq!(ctrl.mode(), Mode::Echo);
+    }
+
+    #[test]
+    fn test_initial_mode_pin_high_is_echo() {
+        assert_eq!(initial_mode(true), Mode::Echo);
+    }
+
+    #[test]
+    fn test_initial_mode_pin_low_is_command() {
+        assert_eq!(initial_mode(false), Mode::Command);
+    }
+
+    #[test]
+    fn test_set_initial_mode_echo_emits_nothing() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.set_initial_mode(initial_mode(true)), b"");
+        assert_eq!(ctrl.mode(), Mode::Echo);
+        assert!(!ctrl.command_only());
+    }
+
+    #[test]
+    fn test_set_initial_mode_command_shows_prompt() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.set_initial_mode(initial_mode(false)), b"> ");
+        assert_eq!(ctrl.mode(), Mode::Command);
+        assert!(ctrl.command_only());
+    }
+
+    #[test]
+    fn test_command_only_returns_to_prompt() {
+        let mut ctrl = UartController::new();
+        ctrl.set_initial_mode(Mode::Command);
+        let out = feed(&mut ctrl, b"version\r").to_vec();
+        assert!(out.ends_with(b"\r\n> "));
+        assert_eq!(ctrl.mode(), Mode::Command);
+    }
+
+    #[test]
+    fn test_command_only_empty_line_returns_to_prompt() {
+        let mut ctrl = UartController::new();
+        ctrl.set_initial_mode(Mode::Command);
+        assert_eq!(ctrl.process_char(b'\r'), b"\r\n> ");
+        assert_eq!(ctrl.mode(), Mode::Command);
+    }
+
+    #[test]
+    fn test_command_only_escape_stays_in_command_mode() {
+        let mut ctrl = UartController::new();
+        ctrl.set_initial_mode(Mode::Command);
+        feed(&mut ctrl, b"ver");
+        ctrl.process_char(0x1B);
+        assert_eq!(ctrl.idle(), b"\r\n> ");
+        assert_eq!(ctrl.mode(), Mode::Command);
+        assert_eq!(ctrl.line(), b"");
+    }
+
+    #[test]
+    fn test_lone_escape_enters_command_mode_on_idle() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.process_char(0x1B), b"");
+        assert_eq!(ctrl.idle(), b"\r\n> ");
+        assert_eq!(ctrl.mode(), Mode::Command);
+    }
+
+    #[test]
+    fn test_escape_then_char_enters_command_mode() {
+        let mut ctrl = UartController::new();
+        ctrl.process_char(0x1B);
+        assert_eq!(ctrl.process_char(b'd'), b"\r\n> d");
+        assert_eq!(ctrl.mode(), Mode::Command);
+        assert_eq!(ctrl.line(), b"d");
+    }
+
+    #[test]
+    fn test_idle_without_escape_is_empty() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.idle(), b"");
+        assert_eq!(ctrl.mode(), Mode::Echo);
+    }
+
+    #[test]
+    fn test_dump_prints_pre_command_line() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"AB\x1b");
+        ctrl.idle();
+        assert_eq!(feed(&mut ctrl, b"dump\r"), b"\r\n41 42\r\n");
+        assert_eq!(ctrl.mode(), Mode::Echo);
+    }
+
+    #[test]
+    fn test_dump_hex_case() {
+        let mut ctrl = UartController::new();
+        assert!(ctrl.hex_uppercase());
+        feed(&mut ctrl, b"J\x1b");
+        ctrl.idle();
+        assert_eq!(feed(&mut ctrl, b"dump\r"), b"\r\n4A\r\n");
+        ctrl.set_hex_uppercase(false);
+        feed(&mut ctrl, b"J\x1b");
+        ctrl.idle();
+        assert_eq!(feed(&mut ctrl, b"dump\r"), b"\r\n4a\r\n");
+    }
+
+    #[test]
+    fn test_full_line_dump_reaches_tx_queue() {
+        use crate::config::TX_QUEUE_SIZE;
+        use crate::txqueue::TxQueue;
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, &[b'A'; LINE_BUF_SIZE]);
+        feed(&mut ctrl, b"\x1b");
+        ctrl.idle();
+        let out = feed(&mut ctrl, b"dump\r");
+        assert_eq!(out.len(), 2 + LINE_BUF_SIZE * 3 - 1 + 2);
+        let mut queue: TxQueue<TX_QUEUE_SIZE> = TxQueue::new();
+        queue.push(&[b'x'; ECHO_BUF_SIZE]).unwrap();
+        queue.push(&out).unwrap();
+        assert_eq!(queue.len(), ECHO_BUF_SIZE + out.len());
+    }
+
+    #[test]
+    fn test_dump_empty_line() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"AB\r\x1bdump");
+        assert_eq!(ctrl.process_char(b'\r'), b"\r\n\r\n");
+    }
+
+    #[test]
+    fn test_lf_after_command_cr_dropped() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bdump\r");
+        assert_eq!(ctrl.process_char(b'\n'), b"");
+        assert_eq!(ctrl.process_char(b'\n'), b"\n");
+    }
+
+    #[test]
+    fn test_unknown_command_surfaces_error() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bbogus");
+        assert_eq!(
+            ctrl.try_process_char(b'\r'),
+            Err((UartError::UnknownCommand, &b"\r\nunknown command\r\n"[..]))
+        );
+        assert_eq!(ctrl.mode(), Mode::Echo);
+    }
+
+    #[test]
+    fn test_empty_command_returns_to_echo() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1b");
+        ctrl.idle();
+        assert_eq!(ctrl.try_process_char(b'\r'), Ok(&b"\r\n"[..]));
+        assert_eq!(ctrl.mode(), Mode::Echo);
+    }
+
+    #[test]
+    fn test_command_backspace_stops_at_prompt() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bd");
+        assert_eq!(ctrl.process_char(0x08), &BACKSPACE_SEQ);
+        assert_eq!(ctrl.process_char(0x08), b"");
+    }
+
+    #[test]
+    fn test_escape_cancels_command() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bdum\x1b");
+        assert_eq!(ctrl.mode(), Mode::Command);
+        assert_eq!(ctrl.idle(), b"\r\n");
+        assert_eq!(ctrl.mode(), Mode::Echo);
+        assert_eq!(ctrl.line(), b"");
+    }
+
+    #[test]
+    fn test_escape_cancel_runs_nothing() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bRESET\x1b");
+        ctrl.idle();
+        assert_eq!(ctrl.process_char(b'\r'), b"\r");
+        assert!(!ctrl.take_reset_request());
+    }
+
+    #[test]
+    fn test_escape_cancel_then_key_echoes() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bdum");
+        assert_eq!(feed(&mut ctrl, b"\x1bx"), b"\r\nx");
+        assert_eq!(ctrl.line(), b"x");
+    }
+
+    // ==================== Settings Menu Tests ====================
+
+    #[test]
+    fn test_menu_command_lists_options() {
+        let mut ctrl = UartController::new();
+        let out = feed(&mut ctrl, b"\x1bmenu\r");
+        assert_eq!(
+            out,
+            b"\r\n1) echo: on\r\n2) case: as-is\r\n3) newline: as-received\r\nq) quit\r\n"
+        );
+        assert_eq!(ctrl.mode(), Mode::Menu);
+    }
+
+    #[test]
+    fn test_menu_toggle_echo() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bmenu\r");
+        assert_eq!(ctrl.process_char(b'1'), b"echo: off\r\n");
+        assert!(!ctrl.echo_enabled());
+        assert_eq!(ctrl.process_char(b'q'), b"menu closed\r\n");
+        assert_eq!(ctrl.mode(), Mode::Echo);
+        assert_eq!(ctrl.process_char(b'a'), b"");
+        assert_eq!(ctrl.line(), b"a");
+    }
+
+    #[test]
+    fn test_menu_cycle_case() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bmenu\r");
+        assert_eq!(ctrl.process_char(b'2'), b"case: upper\r\n");
+        assert_eq!(ctrl.case_mode(), CaseMode::Upper);
+        feed(&mut ctrl, b"q");
+        assert_eq!(ctrl.process_char(b'a'), b"A");
+    }
+
+    #[test]
+    fn test_menu_cycle_newline() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bmenu\r");
+        assert_eq!(ctrl.process_char(b'3'), b"newline: cr\r\n");
+        assert_eq!(ctrl.process_char(b'3'), b"newline: lf\r\n");
+        assert_eq!(ctrl.process_char(b'3'), b"newline: crlf\r\n");
+        assert_eq!(ctrl.newline_mode(), NewlineMode::CrLf);
+    }
+
+    #[test]
+    fn test_menu_invalid_key_rings_bell() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bmenu\r");
+        assert_eq!(
+            ctrl.try_process_char(b'9'),
+            Err((UartError::InvalidArgument, &[BELL][..]))
+        );
+        assert_eq!(ctrl.process_char(b'9'), &[BELL]);
+        assert_eq!(ctrl.mode(), Mode::Menu);
+    }
+
+    #[test]
+    fn test_menu_escape_closes() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bmenu\r\x1b");
+        assert_eq!(ctrl.idle(), b"menu closed\r\n");
+        assert_eq!(ctrl.mode(), Mode::Echo);
+    }
+
+    #[test]
+    fn test_newline_mode_crlf_echo() {
+        let mut ctrl = UartController::new();
+        ctrl.set_newline_mode(NewlineMode::CrLf);
+        assert_eq!(ctrl.process_char(b'\r'), b"\r\n");
+        assert_eq!(ctrl.process_char(b'\n'), b"");
+        assert_eq!(ctrl.process_char(b'\n'), b"\r\n");
+    }
+
+    #[test]
+    fn test_newline_mode_as_received_default() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.newline_mode(), NewlineMode::AsReceived);
+        assert_eq!(ctrl.process_char(b'\r'), b"\r");
+        assert_eq!(ctrl.process_char(b'\n'), b"\n");
+    }
+
+    // ==================== Direction Statistics Tests ====================
+
+    #[test]
+    fn test_direction_counters_start_at_zero() {
+        let ctrl = UartController::new();
+        assert_eq!(ctrl.rx_bytes(), 0);
+        assert_eq!(ctrl.tx_bytes(), 0);
+    }
+
+    #[test]
+    fn test_plain_character_counts_both_directions() {
+        let mut ctrl = UartController::new();
+        ctrl.process_char(b'A');
+        assert_eq!(ctrl.rx_bytes(), 1);
+        assert_eq!(ctrl.tx_bytes(), 1);
+    }
+
+    #[test]
+    fn test_backspace_diverges_rx_and_tx() {
+        let mut ctrl = UartController::new();
+        ctrl.process_char(b'A');
+        ctrl.process_char(BACKSPACE);
+        assert_eq!(ctrl.rx_bytes(), 2);
+        assert_eq!(ctrl.tx_bytes(), 1 + BACKSPACE_SEQ.len() as u64);
+        assert_eq!(BACKSPACE_SEQ.len(), 3);
+    }
+
+    #[test]
+    fn test_line_counts_directions() {
+        let mut ctrl = UartController::new();
+        let mut sent = 0;
+        for &b in b"ab\r" {
+            sent += ctrl.process_char(b).len();
+        }
+        assert_eq!(ctrl.rx_bytes(), 3);
+        assert_eq!(ctrl.tx_bytes(), sent as u64);
+    }
+
+    #[test]
+    fn test_failed_character_counts_rx_only() {
+        let mut ctrl = UartController::new();
+        assert!(ctrl.try_process_char(0x01).is_err());
+        assert_eq!(ctrl.rx_bytes(), 1);
+        assert_eq!(ctrl.tx_bytes(), 0);
+    }
+
+    // ==================== Flush Accounting Tests ====================
+
+    #[test]
+    fn test_flushed_count_starts_at_zero() {
+        let ctrl = UartController::new();
+        assert_eq!(ctrl.flushed_count(), 0);
+    }
+
+    #[test]
+    fn test_flushed_count_independent_of_echo_count() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"abc");
+        assert_eq!(ctrl.echo_count(), 3);
+        assert_eq!(ctrl.flushed_count(), 0);
+        ctrl.record_flushed(2);
+        assert_eq!(ctrl.flushed_count(), 2);
+        assert_eq!(ctrl.echo_count(), 3);
+    }
+
+    #[test]
+    fn test_flushed_count_accumulates() {
+        let mut ctrl = UartController::new();
+        ctrl.record_flushed(3);
+        ctrl.record_flushed(0);
+        ctrl.record_flushed(5);
+        assert_eq!(ctrl.flushed_count(), 8);
+    }
+
+    // ==================== Quality Command Tests ====================
+
+    #[test]
+    fn test_record_overrun_counts() {
+        let mut ctrl = UartController::new();
+        ctrl.record_overrun();
+        ctrl.record_overrun();
+        assert_eq!(ctrl.overruns(), 2);
+    }
+
+    // ==================== Command Case Folding Tests ====================
+
+    #[test]
+    fn test_fold_command_case_default_off() {
+        let mut ctrl = UartController::new();
+        assert!(!ctrl.fold_command_case());
+        assert_eq!(feed(&mut ctrl, b"\x1bSTATS\r"), b"\r\nunknown command\r\n");
+    }
+
+    #[test]
+    fn test_fold_command_case_dispatches_both_cases() {
+        let mut ctrl = UartController::new();
+        ctrl.set_fold_command_case(true);
+        let upper = feed(&mut ctrl, b"\x1bSTATS\r");
+        assert!(upper.starts_with(b"\r\nrx: "));
+        let lower = feed(&mut ctrl, b"\x1bstats\r");
+        assert!(lower.starts_with(b"\r\nrx: "));
+    }
+
+    #[test]
+    fn test_fold_command_case_preserves_echo_and_buffer() {
+        let mut ctrl = UartController::new();
+        ctrl.set_fold_command_case(true);
+        ctrl.set_case_mode(CaseMode::Lower);
+        feed(&mut ctrl, b"\x1bC");
+        for &ch in b"aLc" {
+            assert_eq!(ctrl.process_char(ch), &[ch]);
+        }
+        assert_eq!(ctrl.line(), b"CaLc");
+        feed(&mut ctrl, b" 1+1\r");
+        assert_eq!(feed(&mut ctrl, b"\x1b!!\r"), b"\r\nCaLc 1+1\r\n2\r\n");
+    }
+
+    #[test]
+    fn test_fold_command_case_keeps_argument_case() {
+        let mut ctrl = UartController::new();
+        ctrl.set_fold_command_case(true);
+        feed(&mut ctrl, b"\x1bNAME Bob\r");
+        assert_eq!(ctrl.name(), b"Bob");
+    }
+
+    #[test]
+    fn test_fold_command_case_governs_reset_and_bootsel() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1breset\r\x1bBOOTSEL\r");
+        assert!(!ctrl.take_reset_request());
+        assert!(!ctrl.take_bootsel_request());
+        ctrl.set_fold_command_case(true);
+        feed(&mut ctrl, b"\x1breset\r");
+        assert!(ctrl.take_reset_request());
+        feed(&mut ctrl, b"\x1bBOOTSEL\r");
+        assert!(ctrl.take_bootsel_request());
+    }
+
+    // ==================== Clear Command Tests ====================
+
+    #[test]
+    fn test_clear_errors_keeps_echo_count() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"ab");
+        ctrl.record_overrun();
+        ctrl.record_overrun();
+        let echoed = ctrl.echo_count();
+        assert_eq!(
+            feed(&mut ctrl, b"\x1bclear errors\r"),
+            b"\r\ncleared errors\r\n"
+        );
+        assert_eq!(ctrl.overruns(), 0);
+        assert_eq!(ctrl.parity_errors(), 0);
+        assert!(ctrl.errors().is_empty());
+        assert!(ctrl.echo_count() >= echoed);
+    }
+
+    #[test]
+    fn test_clear_errors_method_preserves_echo_count() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"ab");
+        ctrl.record_overrun();
+        ctrl.clear_errors();
+        assert_eq!(ctrl.overruns(), 0);
+        assert!(ctrl.errors().is_empty());
+        assert_eq!(ctrl.echo_count(), 2);
+        assert_eq!(ctrl.rx_bytes(), 2);
+    }
+
+    #[test]
+    fn test_clear_echo_keeps_errors() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"ab");
+        ctrl.record_overrun();
+        assert_eq!(
+            feed(&mut ctrl, b"\x1bclear echo\r"),
+            b"\r\ncleared echo\r\n"
+        );
+        assert_eq!(ctrl.echo_count(), 0);
+        assert_eq!(ctrl.overruns(), 1);
+        assert_eq!(ctrl.errors().len(), 1);
+    }
+
+    #[test]
+    fn test_clear_bytes() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"ab");
+        ctrl.clear_bytes();
+        assert_eq!(ctrl.rx_bytes(), 0);
+        assert_eq!(ctrl.tx_bytes(), 0);
+        assert_eq!(ctrl.echo_count(), 2);
+    }
+
+    #[test]
+    fn test_clear_unknown_counter() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"ab");
+        ctrl.record_overrun();
+        assert_eq!(
+            feed(&mut ctrl, b"\x1bclear widgets\r"),
+            b"\r\nunknown counter\r\n"
+        );
+        assert_eq!(ctrl.overruns(), 1);
+        assert!(ctrl.echo_count() > 0);
+        assert_eq!(
+            ctrl.errors().get(1).map(|(err, _)| err),
+            Some(UartError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn test_clear_without_counter_is_error() {
+        let mut ctrl = UartController::new();
+        assert_eq!(feed(&mut ctrl, b"\x1bclear\r"), b"\r\nunknown counter\r\n");
+    }
+
+    // ==================== Logo Command Tests ====================
+
+    fn logo_with(newline_mode: NewlineMode) -> Vec<u8> {
+        let mut ctrl = UartController::new();
+        ctrl.set_newline_mode(newline_mode);
+        feed(&mut ctrl, b"\x1blogo\r")[2..].to_vec()
+    }
+
+    fn logo_translated(newline: &[u8]) -> Vec<u8> {
+        let mut expected = Vec::new();
+        for &b in LOGO {
+            if b == b'\n' {
+                expected.extend_from_slice(newline);
+            } else {
+                expected.push(b);
+            }
+        }
+        expected
+    }
+
+    #[test]
+    fn test_logo_as_received_keeps_lf() {
+        assert_eq!(logo_with(NewlineMode::AsReceived), LOGO);
+    }
+
+    #[test]
+    fn test_logo_crlf_mode() {
+        let out = logo_with(NewlineMode::CrLf);
+        assert_eq!(out, logo_translated(b"\r\n"));
+        assert_eq!(out.windows(2).filter(|w| w == b"\r\n").count(), 4);
+    }
+
+    #[test]
+    fn test_logo_cr_mode() {
+        let out = logo_with(NewlineMode::Cr);
+        assert_eq!(out, logo_translated(b"\r"));
+        assert!(!out.contains(&b'\n'));
+    }
+
+    #[test]
+    fn test_logo_lf_mode() {
+        let out = logo_with(NewlineMode::Lf);
+        assert_eq!(out, LOGO);
+        assert!(!out.contains(&b'\r'));
+    }
+
+    // ==================== Stats Command Tests ====================
+
+    #[test]
+    fn test_stats_command_reports_counters() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"ab");
+        ctrl.record_overrun();
+        assert_eq!(
+            feed(&mut ctrl, b"\x1bstats\r"),
+            b"\r\nrx: 9 echoed: 9 overruns: 1\r\n"
+        );
+    }
+
+    #[test]
+    fn test_button_matches_stats_command() {
+        let mut ctrl = UartController::new();
+        let typed = feed(&mut ctrl, b"x\x1bstats\r");
+        assert_eq!(ctrl.press_button(), &typed[2..]);
+    }
+
+    #[test]
+    fn test_button_keeps_partial_line() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"ab");
+        assert_eq!(ctrl.press_button(), b"rx: 2 echoed: 2 overruns: 0\r\n");
+        assert_eq!(ctrl.line(), b"ab");
+        assert_eq!(ctrl.mode(), Mode::Echo);
+    }
+
+    #[test]
+    fn test_button_silent_at_login_gate() {
+        let mut ctrl = UartController::new();
+        ctrl.set_password(Some(b"pw"));
+        assert_eq!(ctrl.press_button(), b"");
+        feed(&mut ctrl, b"pw\r");
+        assert_eq!(ctrl.press_button(), b"rx: 3 echoed: 3 overruns: 0\r\n");
+    }
+
+    #[test]
+    fn test_button_charged_to_command_budget() {
+        let mut ctrl = UartController::new();
+        ctrl.set_command_budget(Some(1));
+        assert_eq!(ctrl.press_button(), b"rx: 0 echoed: 0 overruns: 0\r\n");
+        assert_eq!(ctrl.commands_run(), 1);
+        assert_eq!(ctrl.press_button(), b"command limit reached\r\n");
+        assert_eq!(ctrl.commands_run(), 1);
+    }
+
+    // ==================== Error Log Tests ====================
+
+    #[test]
+    fn test_errors_recorded_with_time() {
+        let mut ctrl = UartController::<2>::with_capacity();
+        ctrl.set_time(1234);
+        feed(&mut ctrl, b"abc");
+        ctrl.set_time(2000);
+        ctrl.record_overrun();
+        assert_eq!(ctrl.errors().len(), 2);
+        assert_eq!(ctrl.errors().get(0), Some((UartError::LineOverflow, 1234)));
+        assert_eq!(ctrl.errors().get(1), Some((UartError::RxOverrun, 2000)));
+    }
+
+    #[test]
+    fn test_tx_drop_recorded_with_time() {
+        let mut ctrl = UartController::new();
+        ctrl.set_time(500);
+        ctrl.record_tx_drop();
+        assert_eq!(ctrl.errors().get(0), Some((UartError::TxQueueFull, 500)));
+        assert_eq!(ctrl.overruns(), 0);
+    }
+
+    #[test]
+    fn test_try_process_char_logs_error() {
+        let mut ctrl = UartController::new();
+        ctrl.set_time(7);
+        feed(&mut ctrl, b"\x1bbogus");
+        assert_eq!(
+            ctrl.try_process_char(b'\r'),
+            Err((UartError::UnknownCommand, &b"\r\nunknown command\r\n"[..]))
+        );
+        assert_eq!(ctrl.errors().get(0), Some((UartError::UnknownCommand, 7)));
+    }
+
+    #[test]
+    fn test_errors_command_empty() {
+        let mut ctrl = UartController::new();
+        assert_eq!(feed(&mut ctrl, b"\x1berrors\r"), b"\r\nno errors\r\n");
+    }
+
+    #[test]
+    fn test_errors_command_lists_names() {
+        let mut ctrl = UartController::new();
+        ctrl.set_time(42);
+        ctrl.record_overrun();
+        ctrl.set_time(99);
+        feed(&mut ctrl, b"\x1bbogus\r");
+        assert_eq!(
+            feed(&mut ctrl, b"\x1berrors\r"),
+            b"\r\n[00000042] rx overrun\r\n[00000099] unknown command\r\n"
+        );
+    }
+
+    #[test]
+    fn test_errors_wraparound_keeps_latest() {
+        let mut ctrl = UartController::new();
+        for t in 0..ERROR_LOG_SIZE as u64 + 2 {
+            ctrl.set_time(t);
+            ctrl.record_overrun();
+        }
+        assert_eq!(ctrl.errors().len(), ERROR_LOG_SIZE);
+        assert_eq!(ctrl.errors().get(0), Some((UartError::RxOverrun, 2)));
+    }
+
+    #[test]
+    fn test_quality_reports_rate() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, &[b'x'; 91]);
+        ctrl.record_overrun();
+        assert_eq!(
+            feed(&mut ctrl, b"\x1bquality\r"),
+            b"\r\noverrun rate: 10 per mille\r\n"
+        );
+    }
+
+    // ==================== Status Marker Tests ====================
+
+    #[test]
+    fn test_status_markers_default_off() {
+        let mut ctrl = UartController::new();
+        assert!(!ctrl.status_markers());
+        assert_eq!(feed(&mut ctrl, b"\x1bcalc 1+1\r"), b"\r\n2\r\n");
+    }
+
+    #[test]
+    fn test_status_marker_ok() {
+        let mut ctrl = UartController::new();
+        ctrl.set_status_markers(true);
+        assert_eq!(feed(&mut ctrl, b"\x1bcalc 1+1\r"), b"\r\n2\r\n[OK]\r\n");
+    }
+
+    #[test]
+    fn test_status_marker_unknown_command() {
+        let mut ctrl = UartController::new();
+        ctrl.set_status_markers(true);
+        feed(&mut ctrl, b"\x1bbogus");
+        assert_eq!(
+            ctrl.try_process_char(b'\r'),
+            Err((
+                UartError::UnknownCommand,
+                &b"\r\nunknown command\r\n[ERR 127]\r\n"[..]
+            ))
+        );
+        assert_eq!(
+            feed(&mut ctrl, b"\x1bbogus\r"),
+            b"\r\nunknown command\r\n[ERR 127]\r\n"
+        );
+    }
+
+    #[test]
+    fn test_status_marker_failed_command() {
+        let mut ctrl = UartController::new();
+        ctrl.set_status_markers(true);
+        assert_eq!(
+            feed(&mut ctrl, b"\x1bprofile z\r"),
+            b"\r\ninvalid profile\r\n[ERR 1]\r\n"
+        );
+    }
+
+    // ==================== Again Command Tests ====================
+
+    #[test]
+    fn test_again_reruns_previous_command() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bcalc 2*3\r");
+        assert_eq!(feed(&mut ctrl, b"\x1bagain\r"), b"\r\ncalc 2*3\r\n6\r\n");
+    }
+
+    #[test]
+    fn test_bang_bang_reruns_quality() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bquality\r");
+        assert_eq!(
+            feed(&mut ctrl, b"\x1b!!\r"),
+            b"\r\nquality\r\noverrun rate: 0 per mille\r\n"
+        );
+    }
+
+    #[test]
+    fn test_again_without_previous_is_noop() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bagain");
+        assert_eq!(ctrl.try_process_char(b'\r'), Ok(&b"\r\n"[..]));
+        assert_eq!(ctrl.mode(), Mode::Echo);
+    }
+
+    #[test]
+    fn test_again_twice_does_not_recurse() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bcalc 1+1\r\x1bagain\r");
+        assert_eq!(feed(&mut ctrl, b"\x1bagain\r"), b"\r\ncalc 1+1\r\n2\r\n");
+    }
+
+    #[test]
+    fn test_again_replays_unknown_command_error() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bbogus\r\x1bagain");
+        assert_eq!(
+            ctrl.try_process_char(b'\r'),
+            Err((
+                UartError::UnknownCommand,
+                &b"\r\nbogus\r\nunknown command\r\n"[..]
+            ))
+        );
+    }
+
+    #[test]
+    fn test_quality_without_overruns() {
+        let mut ctrl = UartController::new();
+        assert_eq!(
+            feed(&mut ctrl, b"\x1bquality\r"),
+            b"\r\noverrun rate: 0 per mille\r\n"
+        );
+    }
+
+    // ==================== Case Command Tests ====================
+
+    #[test]
+    fn test_case_command_upper() {
+        let mut ctrl = UartController::new();
+        assert_eq!(feed(&mut ctrl, b"\x1bcase upper\r"), b"\r\ncase: upper\r\n");
+        assert_eq!(ctrl.case_mode(), CaseMode::Upper);
+    }
+
+    #[test]
+    fn test_case_command_title_any_case() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bcase TITLE\r");
+        assert_eq!(ctrl.case_mode(), CaseMode::Title);
+        feed(&mut ctrl, b"hELLO wORLD");
+        assert_eq!(ctrl.line(), b"Hello World");
+    }
+
+    #[test]
+    fn test_case_command_bogus() {
+        let mut ctrl = UartController::new();
+        assert_eq!(
+            feed(&mut ctrl, b"\x1bcase bogus\r"),
+            b"\r\nunknown case\r\n"
+        );
+        feed(&mut ctrl, b"\x1bcase bogus");
+        assert_eq!(
+            ctrl.try_process_char(b'\r'),
+            Err((UartError::InvalidArgument, &b"\r\nunknown case\r\n"[..]))
+        );
+        assert_eq!(ctrl.case_mode(), CaseMode::AsIs);
+    }
+
+    // ==================== Sweep Command Tests ====================
+
+    #[test]
+    fn test_sweep_command_once() {
+        let mut ctrl = UartController::new();
+        let out = feed(&mut ctrl, b"\x1bsweep\r");
+        let expected: Vec<u8> = (0x20..=0x7E).collect();
+        assert_eq!(&out[..2], b"\r\n");
+        assert_eq!(&out[2..97], &expected[..]);
+        assert_eq!(&out[97..], b"\r\n");
+    }
+
+    #[test]
+    fn test_sweep_command_repeats_with_newline_mode() {
+        let mut ctrl = UartController::new();
+        ctrl.set_newline_mode(NewlineMode::Lf);
+        let out = feed(&mut ctrl, b"\x1bsweep 3\r");
+        assert_eq!(out.len(), 2 + 3 * 96);
+        assert_eq!(out.iter().filter(|&&b| b == b'~').count(), 3);
+        assert_eq!(out.last(), Some(&b'\n'));
+    }
+
+    #[test]
+    fn test_sweep_max_repeat_reaches_tx_queue() {
+        use crate::config::TX_QUEUE_SIZE;
+        use crate::txqueue::TxQueue;
+        let mut ctrl = UartController::new();
+        let out = feed(&mut ctrl, b"\x1bsweep 4\r");
+        assert_eq!(out.len(), 2 + 4 * 97);
+        let mut queue: TxQueue<TX_QUEUE_SIZE> = TxQueue::new();
+        queue.push(&[b'x'; ECHO_BUF_SIZE]).unwrap();
+        queue.push(&out).unwrap();
+        assert_eq!(queue.len(), ECHO_BUF_SIZE + out.len());
+    }
+
+    #[test]
+    fn test_sweep_command_bad_count() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bsweep 9");
+        assert_eq!(
+            ctrl.try_process_char(b'\r'),
+            Err((UartError::InvalidArgument, &b"\r\ninvalid count\r\n"[..]))
+        );
+    }
+
+    // ==================== Name Command Tests ====================
+
+    #[test]
+    fn test_name_command_sets_name() {
+        let mut ctrl = UartController::new();
+        assert_eq!(feed(&mut ctrl, b"\x1bname lab1\r"), b"\r\nname: lab1\r\n");
+        assert_eq!(ctrl.name(), b"lab1");
+    }
+
+    #[test]
+    fn test_name_shown_in_prompt() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.process_char(0x1B), b"");
+        assert_eq!(ctrl.idle(), b"\r\n> ");
+        feed(&mut ctrl, b"name lab1\r");
+        ctrl.process_char(0x1B);
+        assert_eq!(ctrl.idle(), b"\r\nlab1> ");
+    }
+
+    // ==================== Prompt Tests ====================
+
+    /// Builds the prompt into a vector.
+    fn prompt_of(ctrl: &UartController) -> Vec<u8> {
+        let mut out = [0u8; PROMPT_BUF_SIZE];
+        let n = ctrl.prompt(&mut out);
+        out[..n].to_vec()
+    }
+
+    #[test]
+    fn test_prompt_default_parts() {
+        let ctrl = UartController::new();
+        assert_eq!(ctrl.prompt_parts(), PromptParts::NAME);
+        assert_eq!(prompt_of(&ctrl), b"> ");
+    }
+
+    #[test]
+    fn test_prompt_tracks_echo_count() {
+        let mut ctrl = UartController::new();
+        ctrl.set_prompt_parts(PromptParts::ECHO_COUNT | PromptParts::MODE);
+        assert_eq!(prompt_of(&ctrl), b"[0](echo)> ");
+        feed(&mut ctrl, b"abc");
+        assert_eq!(prompt_of(&ctrl), b"[3](echo)> ");
+    }
+
+    #[test]
+    fn test_dynamic_prompt_on_escape() {
+        let mut ctrl = UartController::new();
+        ctrl.set_prompt_parts(PromptParts::NAME | PromptParts::ECHO_COUNT | PromptParts::MODE);
+        ctrl.set_name(b"lab1").unwrap();
+        feed(&mut ctrl, b"hello");
+        ctrl.process_char(0x1B);
+        assert_eq!(ctrl.idle(), b"\r\nlab1[6](command)> ");
+    }
+
+    #[test]
+    fn test_name_too_long_rejected() {
+        let mut ctrl = UartController::new();
+        ctrl.set_name(b"lab1").unwrap();
+        feed(&mut ctrl, b"\x1bname abcdefghijklmnopq");
+        assert_eq!(
+            ctrl.try_process_char(b'\r'),
+            Err((UartError::InvalidArgument, &b"\r\ninvalid name\r\n"[..]))
+        );
+        assert_eq!(ctrl.name(), b"lab1");
+    }
+
+    #[test]
+    fn test_set_name_rejects_unprintable() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.set_name(b"a\tb"), Err(UartError::InvalidArgument));
+        assert_eq!(ctrl.name(), b"");
+    }
+
+    #[test]
+    fn test_name_query() {
+        let mut ctrl = UartController::new();
+        ctrl.set_name(b"lab1").unwrap();
+        assert_eq!(feed(&mut ctrl, b"\x1bname\r"), b"\r\nname: lab1\r\n");
+    }
+
+    #[test]
+    fn test_banner_includes_name() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.banner(), b"RP2350 UART echo\r\n");
+        ctrl.set_name(b"lab1").unwrap();
+        assert_eq!(ctrl.banner(), b"RP2350 UART echo - lab1\r\n");
+    }
+
+    // ==================== Calc Command Tests ====================
+
+    #[test]
+    fn test_calc_command_result() {
+        let mut ctrl = UartController::new();
+        assert_eq!(feed(&mut ctrl, b"\x1bcalc 2+3*4\r"), b"\r\n20\r\n");
+    }
+
+    #[test]
+    fn test_calc_command_overflow() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bcalc 1-2");
+        assert_eq!(
+            ctrl.try_process_char(b'\r'),
+            Err((UartError::InvalidArgument, &b"\r\noverflow\r\n"[..]))
+        );
+    }
+
+    #[test]
+    fn test_calc_command_malformed() {
+        let mut ctrl = UartController::new();
+        assert_eq!(
+            feed(&mut ctrl, b"\x1bcalc 2+\r"),
+            b"\r\ninvalid expression\r\n"
+        );
+    }
+
+    // ==================== Profile Tests ====================
+
+    #[test]
+    fn test_snapshot_restore_round_trip() {
+        let mut ctrl = UartController::new();
+        ctrl.set_case_mode(CaseMode::Upper);
+        ctrl.set_caesar(3);
+        let state = ctrl.snapshot();
+        ctrl.set_case_mode(CaseMode::Lower);
+        ctrl.set_caesar(0);
+        ctrl.restore(&state);
+        assert_eq!(ctrl.snapshot(), state);
+        assert_eq!(ctrl.case_mode(), CaseMode::Upper);
+    }
+
+    #[test]
+    fn test_profile_switch_restores_settings() {
+        let mut ctrl = UartController::new();
+        ctrl.set_case_mode(CaseMode::Upper);
+        ctrl.set_show_line_endings(true);
+        ctrl.set_newline_mode(NewlineMode::CrLf);
+        let profile_a = ctrl.snapshot();
+        assert_eq!(feed(&mut ctrl, b"\x1bprofile b\r"), b"\r\nprofile: b\r\n");
+        assert_eq!(ctrl.active_profile(), 1);
+        assert_eq!(ctrl.snapshot(), UartController::new().snapshot());
+        ctrl.set_caesar(13);
+        ctrl.set_collapse_spaces(true);
+        let profile_b = ctrl.snapshot();
+        feed(&mut ctrl, b"\x1bprofile a\r");
+        assert_eq!(ctrl.active_profile(), 0);
+        assert_eq!(ctrl.snapshot(), profile_a);
+        feed(&mut ctrl, b"\x1bprofile b\r");
+        assert_eq!(ctrl.snapshot(), profile_b);
+    }
+
+    #[test]
+    fn test_profile_same_profile_keeps_settings() {
+        let mut ctrl = UartController::new();
+        ctrl.set_case_mode(CaseMode::Title);
+        feed(&mut ctrl, b"\x1bprofile a\r");
+        assert_eq!(ctrl.case_mode(), CaseMode::Title);
+    }
+
+    #[test]
+    fn test_profile_switch_keeps_interlock() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bprofile b\r");
+        ctrl.set_interlock(true);
+        feed(&mut ctrl, b"\x1bprofile a\r");
+        assert!(ctrl.interlock());
+        ctrl.set_interlock(false);
+        ctrl.restore(&{
+            let mut other = UartController::new();
+            other.set_interlock(true);
+            other.snapshot()
+        });
+        assert!(!ctrl.interlock());
+    }
+
+    #[test]
+    fn test_profile_invalid_name() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bprofile c");
+        assert_eq!(
+            ctrl.try_process_char(b'\r'),
+            Err((UartError::InvalidArgument, &b"\r\ninvalid profile\r\n"[..]))
+        );
+        assert_eq!(ctrl.active_profile(), 0);
+    }
+
+    // ==================== Pattern Command Tests ====================
+
+    /// Collects every pattern chunk into one stream.
+    fn pattern_all(ctrl: &mut UartController) -> Vec<u8> {
+        let mut out = Vec::new();
+        while ctrl.pattern_pending() {
+            out.extend_from_slice(ctrl.pattern_chunk());
+        }
+        out
+    }
+
+    #[test]
+    fn test_pattern_streams_prbs7() {
+        let mut ctrl = UartController::new();
+        assert!(!ctrl.pattern_pending());
+        assert_eq!(feed(&mut ctrl, b"\x1bpattern 4\r"), b"\r\n");
+        assert!(ctrl.pattern_pending());
+        assert_eq!(pattern_all(&mut ctrl), [0x02, 0x0C, 0x28, 0xF2]);
+        assert_eq!(ctrl.pattern_chunk(), b"");
+    }
+
+    #[test]
+    fn test_pattern_chunks_continue_sequence() {
+        let mut ctrl = UartController::new();
+        let count = PATTERN_CHUNK_SIZE * 2 + 5;
+        feed(&mut ctrl, format!("\x1bpattern {}\r", count).as_bytes());
+        let mut out = ctrl.pattern_chunk().to_vec();
+        assert_eq!(out.len(), PATTERN_CHUNK_SIZE);
+        out.extend_from_slice(&pattern_all(&mut ctrl));
+        let mut prbs = Prbs7::new();
+        let expected: Vec<u8> = (0..count).map(|_| prbs.next_byte()).collect();
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn test_pattern_restarts_from_seed() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bpattern 3\r");
+        let first = pattern_all(&mut ctrl);
+        feed(&mut ctrl, b"\x1bpattern 3\r");
+        assert_eq!(pattern_all(&mut ctrl), first);
+    }
+
+    #[test]
+    fn test_pattern_rejects_bad_count() {
+        let mut ctrl = UartController::new();
+        assert_eq!(
+            feed(&mut ctrl, b"\x1bpattern 0\r"),
+            b"\r\ninvalid count\r\n"
+        );
+        feed(&mut ctrl, b"\x1bpattern");
+        assert_eq!(
+            ctrl.try_process_char(b'\r'),
+            Err((UartError::InvalidArgument, &b"\r\ninvalid count\r\n"[..]))
+        );
+        feed(&mut ctrl, b"\x1bpattern 1000001\r");
+        assert!(!ctrl.pattern_pending());
+    }
+
+    // ==================== Export Command Tests ====================
+
+    /// Collects every export chunk into one stream.
+    fn export_all(ctrl: &mut UartController) -> Vec<u8> {
+        let mut out = Vec::new();
+        while ctrl.export_pending() {
+            out.extend_from_slice(ctrl.export_chunk());
+        }
+        out
+    }
+
+    #[test]
+    fn test_export_streams_transcript_hex() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"AB\r");
+        assert!(!ctrl.export_pending());
+        assert_eq!(feed(&mut ctrl, b"\x1bexport\r"), b"\r\n");
+        assert!(ctrl.export_pending());
+        assert_eq!(export_all(&mut ctrl), b"41420D\r\n");
+        assert_eq!(ctrl.export_chunk(), b"");
+    }
+
+    #[test]
+    fn test_export_lowercase_hex() {
+        let mut ctrl = UartController::new();
+        ctrl.set_hex_uppercase(false);
+        feed(&mut ctrl, b"J\r\x1bexport\r");
+        assert_eq!(export_all(&mut ctrl), b"4a0d\r\n");
+    }
+
+    #[test]
+    fn test_export_empty_transcript() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bexport\r");
+        assert_eq!(export_all(&mut ctrl), b"\r\n");
+    }
+
+    #[test]
+    fn test_export_chunk_boundaries_keep_bytes() {
+        let mut ctrl = UartController::new();
+        let typed: Vec<u8> = (0..EXPORT_CHUNK_SIZE as u8 + 8)
+            .map(|i| b'a' + i % 26)
+            .collect();
+        feed(&mut ctrl, &typed);
+        feed(&mut ctrl, b"\x1bexport\r");
+        let first = ctrl.export_chunk().to_vec();
+        assert_eq!(first.len(), EXPORT_CHUNK_SIZE * 2);
+        let mut out = first;
+        out.extend_from_slice(&export_all(&mut ctrl));
+        let expected: Vec<u8> = typed.iter().flat_map(|&b| byte_to_hex(b, true)).collect();
+        assert_eq!(&out[..out.len() - 2], &expected[..]);
+        assert!(out.ends_with(b"\r\n"));
+    }
+
+    #[test]
+    fn test_export_unaffected_by_later_typing() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"hi");
+        feed(&mut ctrl, b"\x1bexport\r");
+        feed(&mut ctrl, b"xyz");
+        assert_eq!(export_all(&mut ctrl), b"6869\r\n");
+    }
+
+    // ==================== Find Command Tests ====================
+
+    #[test]
+    fn test_find_command_reports_offset() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"hello\r");
+        assert_eq!(feed(&mut ctrl, b"\x1bfind 6c 6f\r"), b"\r\nfound at 3\r\n");
+    }
+
+    #[test]
+    fn test_find_command_not_found() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"hello");
+        assert_eq!(feed(&mut ctrl, b"\x1bfind 7a\r"), b"\r\nnot found\r\n");
+    }
+
+    #[test]
+    fn test_find_command_ignores_command_text() {
+        let mut ctrl = UartController::new();
+        assert_eq!(feed(&mut ctrl, b"\x1bfind 66 69\r"), b"\r\nnot found\r\n");
+    }
+
+    #[test]
+    fn test_find_command_bad_pattern() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bfind 4");
+        assert_eq!(
+            ctrl.try_process_char(b'\r'),
+            Err((UartError::InvalidArgument, &b"\r\ninvalid pattern\r\n"[..]))
+        );
+    }
+
+    // ==================== Fault Command Tests ====================
+
+    #[test]
+    fn test_fault_refused_when_disabled() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bfault");
+        assert_eq!(
+            ctrl.try_process_char(b'\r'),
+            Err((
+                UartError::InvalidArgument,
+                &b"\r\nfault injection disabled\r\n"[..]
+            ))
+        );
+        assert!(!ctrl.fault_pending());
+        assert_eq!(ctrl.process_char(b'a'), b"a");
+    }
+
+    #[test]
+    fn test_fault_alters_exactly_one_response() {
+        let mut ctrl = UartController::new();
+        ctrl.set_fault_injection(true);
+        assert_eq!(feed(&mut ctrl, b"\x1bfault\r"), b"\r\nfault armed\r\n");
+        assert!(ctrl.fault_pending());
+        assert_eq!(ctrl.process_char(b'a'), b"`");
+        assert!(!ctrl.fault_pending());
+        assert_eq!(ctrl.process_char(b'a'), b"a");
+    }
+
+    #[test]
+    fn test_fault_waits_for_non_empty_response() {
+        let mut ctrl = UartController::new();
+        ctrl.set_fault_injection(true);
+        feed(&mut ctrl, b"\x1bfault\r");
+        assert_eq!(ctrl.process_char(0x01), b"");
+        assert!(ctrl.fault_pending());
+        assert_eq!(ctrl.process_char(b'b'), b"c");
+    }
+
+    #[test]
+    fn test_fault_disarmed_by_disabling() {
+        let mut ctrl = UartController::new();
+        ctrl.set_fault_injection(true);
+        feed(&mut ctrl, b"\x1bfault\r");
+        ctrl.set_fault_injection(false);
+        assert_eq!(ctrl.process_char(b'a'), b"a");
+    }
+
+    // ==================== Soft Reset Sequence Tests ====================
+
+    #[test]
+    fn test_escape_reset_default_off() {
+        let mut ctrl = UartController::new();
+        assert!(!ctrl.escape_reset());
+        feed(&mut ctrl, b"\x1bR\x1bR");
+        assert!(!ctrl.take_reset_request());
+    }
+
+    #[test]
+    fn test_double_escape_r_requests_reset() {
+        let mut ctrl = UartController::new();
+        ctrl.set_escape_reset(true);
+        feed(&mut ctrl, b"\x1bR\x1b");
+        assert_eq!(feed(&mut ctrl, b"R"), b"\r\nresetting...\r\n");
+        assert!(ctrl.take_reset_request());
+        assert_eq!(ctrl.mode(), Mode::Echo);
+    }
+
+    #[test]
+    fn test_single_escape_r_does_not_reset() {
+        let mut ctrl = UartController::new();
+        ctrl.set_escape_reset(true);
+        feed(&mut ctrl, b"\x1bR");
+        assert!(!ctrl.take_reset_request());
+        assert_eq!(ctrl.mode(), Mode::Command);
+    }
+
+    #[test]
+    fn test_escape_r_pair_split_by_idle_does_not_reset() {
+        let mut ctrl = UartController::new();
+        ctrl.set_escape_reset(true);
+        feed(&mut ctrl, b"\x1bR");
+        ctrl.idle();
+        feed(&mut ctrl, b"\x1bR");
+        assert!(!ctrl.take_reset_request());
     }
 
     #[test]
-    fn test_backspace_increments_count() {
+    fn test_monitor_session_ignores_escape_reset() {
         let mut ctrl = UartController::new();
-        ctrl.process_char(0x08);
-        assert_eq!(ctrl.echo_count(), 1);
+        ctrl.set_escape_reset(true);
+        ctrl.set_password(Some(b"admin"));
+        ctrl.set_monitor_password(Some(b"view"));
+        feed(&mut ctrl, b"view\r\x1bR\x1bR");
+        assert!(!ctrl.take_reset_request());
     }
 
+    // ==================== Reset Command Tests ====================
+
     #[test]
-    fn test_multiple_backspaces() {
+    fn test_reset_command_confirms_and_requests() {
         let mut ctrl = UartController::new();
-        ctrl.process_char(0x08);
-        ctrl.process_char(0x7F);
-        ctrl.process_char(0x08);
-        assert_eq!(ctrl.echo_count(), 3);
+        assert_eq!(feed(&mut ctrl, b"\x1bRESET\r"), b"\r\nresetting...\r\n");
+        assert!(ctrl.take_reset_request());
+        assert!(!ctrl.take_reset_request());
     }
 
     #[test]
-    fn test_mixed_chars_and_backspace() {
+    fn test_reset_not_requested_by_near_miss() {
         let mut ctrl = UartController::new();
-        ctrl.process_char(b'A');
-        ctrl.process_char(b'B');
-        ctrl.process_char(0x08);
-        ctrl.process_char(b'C');
-        assert_eq!(ctrl.echo_count(), 4);
+        feed(&mut ctrl, b"\x1bRESE\r");
+        assert!(!ctrl.take_reset_request());
+    }
+
+    // ==================== Bootsel Command Tests ====================
+
+    #[test]
+    fn test_bootsel_command_confirms_and_requests() {
+        let mut ctrl = UartController::new();
+        assert_eq!(
+            feed(&mut ctrl, b"\x1bbootsel\r"),
+            b"\r\nrebooting to bootloader...\r\n"
+        );
+        assert!(ctrl.take_bootsel_request());
+        assert!(!ctrl.take_bootsel_request());
+        assert!(!ctrl.take_reset_request());
+    }
+
+    #[test]
+    fn test_bootsel_denied_for_monitor() {
+        let mut ctrl = UartController::new();
+        ctrl.set_password(Some(b"admin"));
+        ctrl.set_monitor_password(Some(b"view"));
+        feed(&mut ctrl, b"view\r\x1bbootsel\r");
+        assert!(!ctrl.take_bootsel_request());
+    }
+
+    // ==================== Delay Command Tests ====================
+
+    #[test]
+    fn test_echo_delay_default_zero() {
+        let ctrl = UartController::new();
+        assert_eq!(ctrl.echo_delay(), 0);
+    }
+
+    #[test]
+    fn test_delay_command_sets_delay() {
+        let mut ctrl = UartController::new();
+        assert_eq!(
+            feed(&mut ctrl, b"\x1bdelay 250\r"),
+            b"\r\ndelay: 250 ms\r\n"
+        );
+        assert_eq!(ctrl.echo_delay(), 250);
+    }
+
+    #[test]
+    fn test_delay_command_accepts_range_ends() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bdelay 1000\r");
+        assert_eq!(ctrl.echo_delay(), 1000);
+        feed(&mut ctrl, b"\x1bdelay 0\r");
+        assert_eq!(ctrl.echo_delay(), 0);
+    }
+
+    #[test]
+    fn test_delay_command_rejects_out_of_range() {
+        let mut ctrl = UartController::new();
+        ctrl.set_echo_delay(40);
+        feed(&mut ctrl, b"\x1bdelay 1001");
+        assert_eq!(
+            ctrl.try_process_char(b'\r'),
+            Err((UartError::InvalidArgument, &b"\r\ninvalid delay\r\n"[..]))
+        );
+        assert_eq!(feed(&mut ctrl, b"\x1bdelay -5\r"), b"\r\ninvalid delay\r\n");
+        feed(&mut ctrl, b"\x1bdelay 5ms\r");
+        assert_eq!(ctrl.echo_delay(), 40);
+    }
+
+    #[test]
+    fn test_delay_command_shows_current() {
+        let mut ctrl = UartController::new();
+        ctrl.set_echo_delay(15);
+        assert_eq!(feed(&mut ctrl, b"\x1bdelay\r"), b"\r\ndelay: 15 ms\r\n");
+    }
+
+    #[test]
+    fn test_set_echo_delay_clamps() {
+        let mut ctrl = UartController::new();
+        ctrl.set_echo_delay(5000);
+        assert_eq!(ctrl.echo_delay(), MAX_ECHO_DELAY_MS);
+    }
+
+    // ==================== Interlock Tests ====================
+
+    #[test]
+    fn test_interlock_default_off() {
+        let ctrl = UartController::new();
+        assert!(!ctrl.interlock());
+        assert_eq!(ctrl.pending_confirmation(), None);
+    }
+
+    #[test]
+    fn test_interlock_reset_prompts() {
+        let mut ctrl = UartController::new();
+        ctrl.set_interlock(true);
+        assert_eq!(feed(&mut ctrl, b"\x1bRESET\r"), b"\r\nconfirm (y/n)? ");
+        assert_eq!(ctrl.pending_confirmation(), Some(Command::Reset));
+        assert!(!ctrl.take_reset_request());
+    }
+
+    #[test]
+    fn test_interlock_yes_executes() {
+        let mut ctrl = UartController::new();
+        ctrl.set_interlock(true);
+        feed(&mut ctrl, b"\x1bRESET\r");
+        assert_eq!(ctrl.process_char(b'y'), b"y\r\nresetting...\r\n");
+        assert!(ctrl.take_reset_request());
+        assert_eq!(ctrl.pending_confirmation(), None);
+    }
+
+    #[test]
+    fn test_interlock_no_cancels() {
+        let mut ctrl = UartController::new();
+        ctrl.set_interlock(true);
+        feed(&mut ctrl, b"\x1bRESET\r");
+        assert_eq!(ctrl.process_char(b'n'), b"n\r\ncancelled\r\n");
+        assert!(!ctrl.take_reset_request());
+        assert_eq!(ctrl.process_char(b'y'), b"y");
+        assert!(!ctrl.take_reset_request());
+    }
+
+    #[test]
+    fn test_interlock_unrelated_key_cancels() {
+        let mut ctrl = UartController::new();
+        ctrl.set_interlock(true);
+        feed(&mut ctrl, b"\x1bbootsel\r");
+        assert_eq!(ctrl.process_char(b'q'), b"q\r\ncancelled\r\n");
+        assert!(!ctrl.take_bootsel_request());
+    }
+
+    #[test]
+    fn test_interlock_skips_lf_after_command() {
+        let mut ctrl = UartController::new();
+        ctrl.set_interlock(true);
+        feed(&mut ctrl, b"\x1bRESET\r\n");
+        assert_eq!(ctrl.pending_confirmation(), Some(Command::Reset));
+        feed(&mut ctrl, b"Y");
+        assert!(ctrl.take_reset_request());
+    }
+
+    #[test]
+    fn test_interlock_baud_prompts_then_switches() {
+        let mut ctrl = UartController::new();
+        ctrl.set_interlock(true);
+        assert_eq!(feed(&mut ctrl, b"\x1bbaud 9600\r"), b"\r\nconfirm (y/n)? ");
+        assert_eq!(ctrl.pending_confirmation(), Some(Command::Baud));
+        assert_eq!(ctrl.take_baud_request(), None);
+        assert_eq!(ctrl.process_char(b'y'), b"y\r\nbaud: 9600\r\n");
+        assert_eq!(ctrl.take_baud_request(), Some(9600));
+        assert_eq!(ctrl.take_baud_request(), None);
+    }
+
+    #[test]
+    fn test_interlock_baud_cancel_keeps_rate() {
+        let mut ctrl = UartController::new();
+        ctrl.set_interlock(true);
+        feed(&mut ctrl, b"\x1bbaud 9600\r");
+        assert_eq!(ctrl.process_char(b'n'), b"n\r\ncancelled\r\n");
+        assert_eq!(ctrl.take_baud_request(), None);
+    }
+
+    #[test]
+    fn test_interlock_invalid_baud_does_not_prompt() {
+        let mut ctrl = UartController::new();
+        ctrl.set_interlock(true);
+        assert_eq!(feed(&mut ctrl, b"\x1bbaud 1234\r"), b"\r\ninvalid baud\r\n");
+        assert_eq!(ctrl.pending_confirmation(), None);
+        assert_eq!(feed(&mut ctrl, b"\x1bbaud\r"), b"\r\ninvalid baud\r\n");
+    }
+
+    #[test]
+    fn test_baud_without_interlock_switches() {
+        let mut ctrl = UartController::new();
+        assert_eq!(
+            feed(&mut ctrl, b"\x1bbaud 921600\r"),
+            b"\r\nbaud: 921600\r\n"
+        );
+        assert_eq!(ctrl.take_baud_request(), Some(921600));
+    }
+
+    #[test]
+    fn test_baud_denied_for_monitor() {
+        let mut ctrl = UartController::new();
+        ctrl.set_password(Some(b"admin"));
+        ctrl.set_monitor_password(Some(b"view"));
+        feed(&mut ctrl, b"view\r\x1bbaud 9600\r");
+        assert_eq!(ctrl.take_baud_request(), None);
+    }
+
+    #[test]
+    fn test_interlock_ignores_safe_commands() {
+        let mut ctrl = UartController::new();
+        ctrl.set_interlock(true);
+        assert_eq!(feed(&mut ctrl, b"\x1bcalc 2+3\r"), b"\r\n5\r\n");
+        assert_eq!(ctrl.pending_confirmation(), None);
+    }
+
+    // ==================== Status Command Tests ====================
+
+    #[test]
+    fn test_status_command_sets_request() {
+        let mut ctrl = UartController::new();
+        assert_eq!(feed(&mut ctrl, b"\x1bstatus\r"), b"\r\n");
+        assert!(ctrl.take_status_request());
+        assert!(!ctrl.take_status_request());
+    }
+
+    #[test]
+    fn test_uart_status_formats_flags() {
+        let mut ctrl = UartController::new();
+        assert_eq!(
+            ctrl.uart_status(0x90),
+            b"TXFE=1 TXFF=0 RXFE=1 RXFF=0 BUSY=0 CTS=0\r\n"
+        );
+    }
+
+    // ==================== Rate Command Tests ====================
+
+    #[test]
+    fn test_rate_command_sets_request() {
+        let mut ctrl = UartController::new();
+        assert_eq!(feed(&mut ctrl, b"\x1brate\r"), b"\r\n");
+        assert!(ctrl.take_rate_request());
+        assert!(!ctrl.take_rate_request());
+    }
+
+    #[test]
+    fn test_rate_report_format() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.rate_report(1152), b"rate: 1152 B/s\r\n");
+    }
+
+    // ==================== Loop Report Tests ====================
+
+    #[test]
+    fn test_loop_report_format() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.loop_report(48_000), b"loops: 48000/s\r\n");
+    }
+
+    // ==================== Mem Command Tests ====================
+
+    #[test]
+    fn test_mem_command_sets_request() {
+        let mut ctrl = UartController::new();
+        assert_eq!(feed(&mut ctrl, b"\x1bmem\r"), b"\r\n");
+        assert!(ctrl.take_mem_request());
+        assert!(!ctrl.take_mem_request());
+    }
+
+    #[test]
+    fn test_mem_report_format() {
+        let mut ctrl = UartController::new();
+        assert_eq!(ctrl.mem_report(4096), b"free: 4096 bytes\r\n");
+    }
+
+    // ==================== Flash Info Command Tests ====================
+
+    #[test]
+    fn test_flashinfo_command_sets_request() {
+        let mut ctrl = UartController::new();
+        assert_eq!(feed(&mut ctrl, b"\x1bflashinfo\r"), b"\r\n");
+        assert!(ctrl.take_flash_info_request());
+        assert!(!ctrl.take_flash_info_request());
+    }
+
+    #[test]
+    fn test_flash_info_report_format() {
+        let mut ctrl = UartController::new();
+        assert_eq!(
+            ctrl.flash_info_report(65_536, 0xCBF4_3926),
+            b"flash: 65536 bytes crc32: 0xCBF43926\r\n"
+        );
+    }
+
+    #[test]
+    fn test_flash_info_report_pads_and_lowercase() {
+        let mut ctrl = UartController::new();
+        ctrl.set_hex_uppercase(false);
+        assert_eq!(
+            ctrl.flash_info_report(0, 0xAB),
+            b"flash: 0 bytes crc32: 0x000000ab\r\n"
+        );
+    }
+
+    #[test]
+    fn test_flash_info_report_of_image() {
+        let image = b"123456789";
+        let mut ctrl = UartController::new();
+        let report = ctrl.flash_info_report(image.len() as u32, crate::crc::crc32(image));
+        assert_eq!(report, b"flash: 9 bytes crc32: 0xCBF43926\r\n");
+    }
+
+    // ==================== Base64 Command Tests ====================
+
+    #[test]
+    fn test_b64_reports_byte_count() {
+        let mut ctrl = UartController::new();
+        assert_eq!(
+            feed(&mut ctrl, b"\x1bb64 SGVsbG8=\r"),
+            b"\r\ndecoded 5 bytes\r\n"
+        );
+    }
+
+    #[test]
+    fn test_b64_invalid_surfaces_error() {
+        let mut ctrl = UartController::new();
+        feed(&mut ctrl, b"\x1bb64 Zm9*");
+        assert_eq!(
+            ctrl.try_process_char(b'\r'),
+            Err((UartError::InvalidArgument, &b"\r\ninvalid base64\r\n"[..]))
+        );
+    }
+
+    #[test]
+    fn test_b64_invalid_message() {
+        let mut ctrl = UartController::new();
+        assert_eq!(feed(&mut ctrl, b"\x1bb64 Zg=\r"), b"\r\ninvalid base64\r\n");
+    }
+
+    // ==================== Byte Budget Tests ====================
+
+    #[test]
+    fn test_byte_budget_default_unlimited() {
+        let ctrl = UartController::new();
+        assert_eq!(ctrl.byte_budget(), None);
+    }
+
+    #[test]
+    fn test_byte_budget_echoes_up_to_budget() {
+        let mut ctrl = UartController::new();
+        ctrl.set_byte_budget(Some(3));
+        assert_eq!(ctrl.process_char(b'a'), b"a");
+        assert_eq!(ctrl.process_char(b'b'), b"b");
+        assert_eq!(ctrl.process_char(b'c'), b"c");
+    }
+
+    #[test]
+    fn test_byte_budget_exceeded_message_once() {
+        let mut ctrl = UartController::new();
+        ctrl.set_byte_budget(Some(2));
+        feed(&mut ctrl, b"ab");
+        assert_eq!(
+            ctrl.try_process_char(b'c'),
+            Err((UartError::BudgetExhausted, &b"limit reached\r\n"[..]))
+        );
+        assert_eq!(ctrl.process_char(b'c'), b"");
+        ctrl.set_byte_budget(Some(2));
+        assert_eq!(ctrl.process_char(b'd'), b"");
+    }
+
+    #[test]
+    fn test_byte_budget_exhaustion_logged_once() {
+        let mut ctrl = UartController::new();
+        ctrl.set_byte_budget(Some(1));
+        ctrl.set_time(9);
+        feed(&mut ctrl, b"abcd");
+        assert_eq!(ctrl.errors().len(), 1);
+        assert_eq!(ctrl.errors().get(0), Some((UartError::BudgetExhausted, 9)));
+        ctrl.reset();
+        feed(&mut ctrl, b"ef");
+        assert_eq!(ctrl.errors().len(), 2);
+    }
+
+    #[test]
+    fn test_byte_budget_exceeded_message_text() {
+        let mut ctrl = UartController::new();
+        ctrl.set_byte_budget(Some(1));
+        feed(&mut ctrl, b"a");
+        assert_eq!(ctrl.process_char(b'b'), b"limit reached\r\n");
+    }
+
+    #[test]
+    fn test_byte_budget_reset_restores_echo() {
+        let mut ctrl = UartController::new();
+        ctrl.set_byte_budget(Some(1));
+        feed(&mut ctrl, b"ab");
+        ctrl.reset();
+        assert_eq!(ctrl.process_char(b'c'), b"c");
+    }
+
+    // ==================== Command Budget Tests ====================
+
+    #[test]
+    fn test_command_budget_default_unlimited() {
+        let ctrl = UartController::new();
+        assert_eq!(ctrl.command_budget(), None);
+        assert_eq!(ctrl.commands_run(), 0);
+    }
+
+    #[test]
+    fn test_command_budget_runs_up_to_budget() {
+        let mut ctrl = UartController::new();
+        ctrl.set_command_budget(Some(2));
+        assert_eq!(feed(&mut ctrl, b"\x1bcalc 1+1\r"), b"\r\n2\r\n");
+        assert_eq!(feed(&mut ctrl, b"\x1bcalc 2+2\r"), b"\r\n4\r\n");
+        assert_eq!(ctrl.commands_run(), 2);
+    }
+
+    #[test]
+    fn test_command_budget_refuses_next_command() {
+        let mut ctrl = UartController::new();
+        ctrl.set_command_budget(Some(1));
+        feed(&mut ctrl, b"\x1bcalc 1+1\r");
+        assert_eq!(
+            feed(&mut ctrl, b"\x1bcalc 2+2\r"),
+            b"\r\ncommand limit reached\r\n"
+        );
+        feed(&mut ctrl, b"\x1bRESET");
+        assert_eq!(
+            ctrl.try_process_char(b'\r'),
+            Err((
+                UartError::BudgetExhausted,
+                &b"\r\ncommand limit reached\r\n"[..]
+            ))
+        );
+        assert!(!ctrl.take_reset_request());
+        assert_eq!(ctrl.commands_run(), 1);
+    }
+
+    #[test]
+    fn test_command_budget_again_counts_once() {
+        let mut ctrl = UartController::new();
+        ctrl.set_command_budget(Some(2));
+        feed(&mut ctrl, b"\x1bcalc 1+1\r");
+        feed(&mut ctrl, b"\x1b!!\r");
+        assert_eq!(ctrl.commands_run(), 2);
+        assert_eq!(
+            feed(&mut ctrl, b"\x1b!!\r"),
+            b"\r\ncalc 1+1\r\ncommand limit reached\r\n"
+        );
+    }
+
+    #[test]
+    fn test_command_budget_reset_restores() {
+        let mut ctrl = UartController::new();
+        ctrl.set_command_budget(Some(1));
+        feed(&mut ctrl, b"\x1bcalc 1+1\r\x1bcalc 1+1\r");
+        ctrl.reset();
+        assert_eq!(ctrl.commands_run(), 0);
+        assert_eq!(feed(&mut ctrl, b"\x1bcalc 3+3\r"), b"\r\n6\r\n");
+    }
+
+    // ==================== Loopback Guard Tests ====================
+
+    #[test]
+    fn test_loopback_guard_default_off() {
+        let mut ctrl = UartController::new();
+        assert!(!ctrl.loopback_guard());
+        feed(&mut ctrl, b"a");
+        assert_eq!(ctrl.process_char(b'a'), b"a");
+    }
+
+    #[test]
+    fn test_loopback_guard_suppresses_recent_byte() {
+        let mut ctrl = UartController::new();
+        ctrl.set_loopback_guard(true);
+        assert_eq!(ctrl.process_char(b'a'), b"a");
+        assert_eq!(ctrl.process_char(b'a'), b"");
+        assert_eq!(ctrl.line(), b"a");
+    }
+
+    #[test]
+    fn test_loopback_guard_echoes_unrelated_byte() {
+        let mut ctrl = UartController::new();
+        ctrl.set_loopback_guard(true);
+        feed(&mut ctrl, b"a");
+        assert_eq!(ctrl.process_char(b'b'), b"b");
+    }
+
+    #[test]
+    fn test_loopback_guard_breaks_echo_storm() {
+        let mut ctrl = UartController::new();
+        ctrl.set_loopback_guard(true);
+        let echoed = ctrl.process_char(b'x').to_vec();
+        for &b in &echoed {
+            assert_eq!(ctrl.process_char(b), b"");
+        }
+        assert_eq!(ctrl.process_char(b'x'), b"x");
+    }
+
+    // ==================== Input Hold Tests ====================
+
+    #[test]
+    fn test_hold_input_default_off() {
+        let mut ctrl = UartController::new();
+        assert!(!ctrl.hold_input());
+        feed(&mut ctrl, b"\x1bdump\r");
+        assert!(!ctrl.output_pending());
+    }
+
+    #[test]
+    fn test_command_sets_output_pending() {
+        let mut ctrl = UartController::new();
+        ctrl.set_hold_input(true);
+        feed(&mut ctrl, b"\x1bdump\r");
+        assert!(ctrl.output_pending());
+    }
+
+    #[test]
+    fn test_input_queued_while_pending() {
+        let mut ctrl = UartController::new();
+        ctrl.set_output_pending(true);
+        assert_eq!(ctrl.process_char(b'a'), b"");
+        assert_eq!(ctrl.process_char(b'b'), b"");
+        assert_eq!(ctrl.queued_input(), b"ab");
+        assert_eq!(ctrl.echo_count(), 0);
+    }
+
+    #[test]
+    fn test_queued_input_replayed_in_order() {
+        let mut ctrl = UartController::new();
+        ctrl.set_output_pending(true);
+        feed(&mut ctrl, b"ab\x7fc");
+        assert_eq!(ctrl.finish_output(), b"ab\x08 \x08c");
+        assert_eq!(ctrl.line(), b"ac");
+        assert_eq!(ctrl.queued_input(), b"");
+        assert!(!ctrl.output_pending());
+    }
+
+    #[test]
+    fn test_replay_stops_at_next_held_command() {
+        let mut ctrl = UartController::new();
+        ctrl.set_hold_input(true);
+        ctrl.set_output_pending(true);
+        feed(&mut ctrl, b"\x1bdump\rxy");
+        ctrl.finish_output();
+        assert!(ctrl.output_pending());
+        assert_eq!(ctrl.queued_input(), b"xy");
+        assert_eq!(ctrl.finish_output(), b"xy");
+    }
+
+    #[test]
+    fn test_input_queue_overflow() {
+        let mut ctrl = UartController::new();
+        ctrl.set_output_pending(true);
+        for _ in 0..INPUT_BUF_SIZE {
+            ctrl.process_char(b'x');
+        }
+        assert_eq!(
+            ctrl.try_process_char(b'y'),
+            Err((UartError::InputOverflow, &b""[..]))
+        );
     }
 
     // ==================== Trait Tests ====================
@@ -0,0 +1,674 @@
+/*
+ * @file echo.rs
+ * @brief Echo-mode character pipeline
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: echo.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Echo Pipeline.
+//!
+//! BRIEF:
+//! Turns an echo-mode character into its echo and line buffer updates.
+//! Covers framing, hex entry, line commit, uniq, and committed-line
+//! decorations such as numbers, indent, and reversal.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+use super::{CrLfMode, NewlineMode, UartController};
+use crate::config::{
+    BACKSPACE, BACKSPACE_SEQ, CLEAR_SCREEN_SEQ, DELETE, FORM_FEED, MAX_INDENT, VERTICAL_TAB,
+    VISIBLE_CR_SEQ, VISIBLE_LF_SEQ, VT_NEWLINE_SEQ,
+};
+use crate::error::UartError;
+use crate::events::Event;
+use crate::fmtbuf::FmtBuf;
+use crate::hex::byte_to_hex;
+use crate::keyname::control_name;
+use core::fmt::Write;
+
+impl<const LINE_CAP: usize> UartController<LINE_CAP> {
+    /// Handles a character in echo mode.
+    ///
+    /// # Details
+    /// Echoes the character and maintains the line buffer. CR, LF, or
+    /// CRLF commits the line; backspace removes the last buffered byte.
+    ///
+    /// # Arguments
+    /// * `ch` - The character received
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, UnsupportedByte, or LineOverflow
+    pub(super) fn echo_char(&mut self, ch: u8) -> Result<(), UartError> {
+        self.transcript.push(ch);
+        if self.settings.hex_input {
+            return self.hex_entry_char(ch);
+        }
+        let prev = match self.line_len {
+            0 => b' ',
+            n => self.line[n - 1],
+        };
+        let ch = self.transform_chain().apply(ch, prev);
+        if self.settings.ascii_value_mode {
+            self.emit_ascii_value(ch);
+            return Ok(());
+        }
+        if self.settings.show_hex_escapes && !ch.is_ascii() {
+            if self.settings.echo_enabled && !self.muted {
+                let hex = byte_to_hex(ch, self.settings.hex_uppercase);
+                self.emit_framed(&[b'\\', b'x', hex[0], hex[1]]);
+                self.emit_seq();
+            }
+            return Ok(());
+        }
+        if self.settings.collapse_spaces && ch == b' ' && self.prev_space {
+            return Ok(());
+        }
+        let bytes = self.echo_for(ch);
+        if bytes.is_empty() {
+            return Err(UartError::UnsupportedByte(ch));
+        }
+        let translated = self.settings.newline_mode != NewlineMode::AsReceived;
+        let bytes = if !self.settings.echo_enabled || (ch == b'\n' && self.prev_cr && translated) {
+            &[][..]
+        } else {
+            bytes
+        };
+        if self.settings.line_buffering {
+            self.buffer_char(ch)?;
+        }
+        self.prev_space = match ch {
+            BACKSPACE | DELETE => self.line[..self.line_len].last() == Some(&b' '),
+            _ => ch == b' ',
+        };
+        if self.settings.echo_enabled
+            && !self.muted
+            && !self.settings.uniq
+            && !self.settings.suppress.matches(ch)
+        {
+            if self.settings.auto_wrap
+                && (0x20..=0x7E).contains(&ch)
+                && self.col > self.settings.wrap_width
+            {
+                self.emit(b"\r\n");
+            }
+            self.emit_framed(bytes);
+            if ch == FORM_FEED {
+                self.row = 1;
+                self.col = 1;
+            }
+            self.emit_seq();
+        }
+        if core::mem::take(&mut self.echo_committed) {
+            self.emit_committed_line();
+        }
+        Ok(())
+    }
+
+    /// Emits an echoed character's bytes between the framing bytes.
+    ///
+    /// # Details
+    /// Nothing is emitted for an empty echo, so a swallowed character
+    /// does not produce a bare prefix and suffix.
+    ///
+    /// # Arguments
+    /// * `bytes` - Echo bytes for one character
+    fn emit_framed(&mut self, bytes: &[u8]) {
+        if bytes.is_empty() {
+            return;
+        }
+        if let Some(prefix) = self.settings.char_prefix {
+            self.emit(&[prefix]);
+        }
+        self.emit(bytes);
+        if let Some(suffix) = self.settings.char_suffix {
+            self.emit(&[suffix]);
+        }
+    }
+
+    /// Computes the echo for a character without changing the controller.
+    ///
+    /// # Details
+    /// A dry run for validating settings such as transform pipelines.
+    /// Follows echo_char() for a character typed after the current
+    /// line, reading the settings in place so counters, buffers, and
+    /// callbacks are left untouched. Decorations added when a line
+    /// commits are not previewed. Echo bytes that do not fit in `out`
+    /// are dropped.
+    ///
+    /// # Arguments
+    /// * `ch` - The character to preview
+    /// * `out` - Destination buffer for the echo bytes
+    ///
+    /// # Returns
+    /// * `usize` - Number of bytes written to `out`
+    #[allow(dead_code)]
+    pub fn preview(&self, ch: u8, out: &mut [u8]) -> usize {
+        let mut len = 0;
+        let mut put = |bytes: &[u8]| {
+            let n = bytes.len().min(out.len() - len);
+            out[len..len + n].copy_from_slice(&bytes[..n]);
+            len += n;
+        };
+        let prev = match self.line_len {
+            0 => b' ',
+            n => self.line[n - 1],
+        };
+        let ch = self.transform_chain().apply(ch, prev);
+        let hex = byte_to_hex(ch, self.settings.hex_uppercase);
+        let mut msg = [0u8; 8];
+        let bytes = if self.settings.ascii_value_mode {
+            let mut text = FmtBuf::new(&mut msg);
+            let _ = write!(text, "{}\r\n", ch);
+            let n = text.len();
+            put(&msg[..n]);
+            return len;
+        } else if self.settings.show_hex_escapes && !ch.is_ascii() {
+            &[b'\\', b'x', hex[0], hex[1]][..]
+        } else if self.settings.collapse_spaces && ch == b' ' && self.prev_space {
+            &[][..]
+        } else {
+            let translated = self.settings.newline_mode != NewlineMode::AsReceived;
+            match self.echo_for(ch) {
+                _ if ch == b'\n' && self.prev_cr && translated => &[][..],
+                _ if self.settings.uniq || self.settings.suppress.matches(ch) => &[][..],
+                bytes => bytes,
+            }
+        };
+        if bytes.is_empty() || !self.settings.echo_enabled || self.muted {
+            return len;
+        }
+        if self.settings.auto_wrap
+            && (0x20..=0x7E).contains(&ch)
+            && self.col > self.settings.wrap_width
+        {
+            put(b"\r\n");
+        }
+        put(self.settings.char_prefix.as_slice());
+        put(bytes);
+        put(self.settings.char_suffix.as_slice());
+        if self.settings.seq_echo {
+            put(&[self.seq]);
+        }
+        len
+    }
+
+    /// Emits the next sequence number byte if sequence echo is enabled.
+    fn emit_seq(&mut self) {
+        if self.settings.seq_echo {
+            self.emit(&[self.seq]);
+            self.seq = self.seq.wrapping_add(1);
+        }
+    }
+
+    /// Handles a character while hex entry is enabled.
+    ///
+    /// # Details
+    /// Enter with a half-entered byte rings the bell and keeps the
+    /// digits so the byte can be finished.
+    ///
+    /// # Arguments
+    /// * `ch` - The character received
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or UnsupportedByte if rejected
+    fn hex_entry_char(&mut self, ch: u8) -> Result<(), UartError> {
+        match ch {
+            b'\n' if self.prev_cr => Ok(()),
+            b'\r' | b'\n' if !self.hex_entry.is_complete() => {
+                self.ring_bell();
+                Ok(())
+            }
+            b'\r' | b'\n' => {
+                let entry = self.hex_entry;
+                self.hex_entry.clear();
+                self.emit(b"\r\n");
+                self.emit(entry.bytes());
+                self.emit(b"\r\n");
+                Ok(())
+            }
+            BACKSPACE | DELETE => {
+                if self.hex_entry.backspace() {
+                    self.emit(&BACKSPACE_SEQ);
+                }
+                Ok(())
+            }
+            _ if self.hex_entry.push(ch) => {
+                self.emit(&[ch]);
+                Ok(())
+            }
+            _ => {
+                self.ring_bell();
+                Err(UartError::UnsupportedByte(ch))
+            }
+        }
+    }
+
+    /// Applies an echo-mode character to the line buffer.
+    ///
+    /// # Arguments
+    /// * `ch` - The character received
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or LineOverflow
+    fn buffer_char(&mut self, ch: u8) -> Result<(), UartError> {
+        match ch {
+            BACKSPACE | DELETE if self.settings.backspace_handling => {
+                self.line_len = self.line_len.saturating_sub(1);
+                self.pending_newline = false;
+            }
+            BACKSPACE | DELETE => {}
+            b'\n' if self.prev_cr && self.settings.crlf_mode == CrLfMode::Collapse => {}
+            b'\r' | b'\n' | VERTICAL_TAB => self.end_line()?,
+            FORM_FEED => {}
+            _ => self.push_line(ch)?,
+        }
+        Ok(())
+    }
+
+    /// Handles Enter in echo mode.
+    ///
+    /// # Details
+    /// Normally commits the line. In paragraph mode the first Enter
+    /// buffers a newline and only a second consecutive Enter commits.
+    ///
+    /// # Returns
+    /// * `Result<(), UartError>` - Ok, or LineOverflow if the newline is rejected
+    fn end_line(&mut self) -> Result<(), UartError> {
+        if self.paragraph_mode && !self.pending_newline {
+            self.push_line(b'\n')?;
+            self.pending_newline = true;
+            return Ok(());
+        }
+        if self.pending_newline && self.line_len > 0 {
+            self.line_len -= 1;
+        }
+        self.commit_line();
+        Ok(())
+    }
+
+    /// Commits the echo-mode line.
+    ///
+    /// # Details
+    /// Saves the line as the last committed line and clears the
+    /// line buffer for the next line. With uniq enabled the line is
+    /// numbered and echoed by uniq_line() instead.
+    fn commit_line(&mut self) {
+        if self.settings.uniq {
+            self.uniq_line();
+        } else if self.settings.line_numbers {
+            self.line_no = self.line_no.wrapping_add(1);
+        }
+        self.last_line = self.line;
+        self.last_len = self.line_len;
+        self.line_len = 0;
+        self.pending_newline = false;
+        self.line_committed = self.line_timing || self.settings.timestamp_lines;
+        self.echo_committed = !self.settings.uniq
+            && (self.settings.line_numbers
+                || self.settings.indent > 0
+                || self.settings.reverse_lines);
+        let line = self.last_line;
+        self.callbacks
+            .fire(Event::LineCommitted, &line[..self.last_len]);
+    }
+
+    /// Emits the line being committed unless it repeats the last one.
+    ///
+    /// # Details
+    /// A uniq line is left open so that, once a different line
+    /// arrives, it can be closed with ` (repeated N times)` if it
+    /// occurred more than once. Only the first line of a run takes a
+    /// line number.
+    fn uniq_line(&mut self) {
+        let line = self.line;
+        let last = self.last_line;
+        if self.repeat_count > 0 && line[..self.line_len] == last[..self.last_len] {
+            self.repeat_count = self.repeat_count.saturating_add(1);
+            return;
+        }
+        if self.repeat_count > 1 {
+            let mut msg = [0u8; 32];
+            let mut text = FmtBuf::new(&mut msg);
+            let _ = write!(text, " (repeated {} times)", self.repeat_count);
+            let len = text.len();
+            self.emit(&msg[..len]);
+        }
+        if self.repeat_count > 0 {
+            self.emit(b"\r\n");
+        }
+        self.repeat_count = 1;
+        if self.settings.line_numbers {
+            self.line_no = self.line_no.wrapping_add(1);
+        }
+        self.emit_decorated(line, self.line_len);
+    }
+
+    /// Echoes the last committed line followed by CRLF.
+    fn emit_committed_line(&mut self) {
+        let line = self.last_line;
+        self.emit_decorated(line, self.last_len);
+        self.emit(b"\r\n");
+    }
+
+    /// Echoes a line with its indent and line number.
+    ///
+    /// # Details
+    /// The indent spaces come first. With line numbers enabled, the
+    /// number is right-aligned to the configured width, e.g.
+    /// `  1: hello`. With reversed lines enabled each newline-separated
+    /// part of a paragraph is emitted last to first.
+    ///
+    /// # Arguments
+    /// * `line` - Copy of the line buffer
+    /// * `len` - Number of valid bytes in `line`
+    fn emit_decorated(&mut self, mut line: [u8; LINE_CAP], len: usize) {
+        let spaces = [b' '; MAX_INDENT as usize];
+        self.emit(&spaces[..usize::from(self.settings.indent)]);
+        if self.settings.line_numbers {
+            let mut msg = [0u8; 16];
+            let mut text = FmtBuf::new(&mut msg);
+            let _ = write!(
+                text,
+                "{:>width$}: ",
+                self.line_no,
+                width = self.settings.line_number_width
+            );
+            let len = text.len();
+            self.emit(&msg[..len]);
+        }
+        let line = &mut line[..len];
+        if self.settings.reverse_lines {
+            line.split_mut(|&b| b == b'\n').for_each(<[u8]>::reverse);
+        }
+        self.emit(line);
+    }
+
+    /// Echoes a character as its decimal code followed by CRLF.
+    ///
+    /// # Arguments
+    /// * `ch` - The character received
+    fn emit_ascii_value(&mut self, ch: u8) {
+        let mut msg = [0u8; 8];
+        let mut text = FmtBuf::new(&mut msg);
+        let _ = write!(text, "{}\r\n", ch);
+        let len = text.len();
+        self.emit(&msg[..len]);
+    }
+
+    /// Looks up the echo response under the current settings.
+    ///
+    /// # Details
+    /// Applies the line-ending debug option, newline mode, form
+    /// control, and raw backspace handling before the default table.
+    /// Does not modify controller state.
+    ///
+    /// # Arguments
+    /// * `ch` - The character received
+    ///
+    /// # Returns
+    /// * `&'static [u8]` - Bytes to echo back, empty if unsupported
+    pub(super) fn echo_for(&self, ch: u8) -> &'static [u8] {
+        if let Some(name) = control_name(ch).filter(|_| self.settings.named_controls) {
+            return name;
+        }
+        match ch {
+            b'\r' if self.settings.show_line_endings => &VISIBLE_CR_SEQ,
+            b'\n' if self.settings.show_line_endings => &VISIBLE_LF_SEQ,
+            b'\r' | b'\n' => self.settings.newline_mode.echo(ch),
+            VERTICAL_TAB if self.settings.form_control => &VT_NEWLINE_SEQ,
+            FORM_FEED if self.settings.form_control => &CLEAR_SCREEN_SEQ,
+            BACKSPACE if !self.settings.backspace_handling => &[BACKSPACE],
+            DELETE if !self.settings.backspace_handling => &[DELETE],
+            _ => echo_bytes(ch),
+        }
+    }
+}
+
+/// Looks up the echo response for a received character.
+///
+/// # Details
+/// Backspace and delete map to the erase sequence.
+/// Printable ASCII and common whitespace echo as-is.
+///
+/// # Arguments
+/// * `ch` - The character received
+///
+/// # Returns
+/// * `&'static [u8]` - Bytes to echo back, empty if unsupported
+fn echo_bytes(ch: u8) -> &'static [u8] {
+    if ch == BACKSPACE || ch == DELETE {
+        &BACKSPACE_SEQ
+    } else {
+        match ch {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' => {
+                static CHARS: [u8; 62] = [
+                    b'A', b'B', b'C', b'D', b'E', b'F', b'G', b'H', b'I', b'J', b'K', b'L', b'M',
+                    b'N', b'O', b'P', b'Q', b'R', b'S', b'T', b'U', b'V', b'W', b'X', b'Y', b'Z',
+                    b'a', b'b', b'c', b'd', b'e', b'f', b'g', b'h', b'i', b'j', b'k', b'l', b'm',
+                    b'n', b'o', b'p', b'q', b'r', b's', b't', b'u', b'v', b'w', b'x', b'y', b'z',
+                    b'0', b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8', b'9',
+                ];
+                let idx = CHARS.iter().position(|&c| c == ch).unwrap();
+                &CHARS[idx..idx + 1]
+            }
+            b' ' => b" ",
+            b'!' => b"!",
+            b'"' => b"\"",
+            b'#' => b"#",
+            b'$' => b"$",
+            b'%' => b"%",
+            b'&' => b"&",
+            b'\'' => b"\'",
+            b'(' => b"(",
+            b')' => b")",
+            b'*' => b"*",
+            b'+' => b"+",
+            b',' => b",",
+            b'-' => b"-",
+            b'.' => b".",
+            b'/' => b"/",
+            b':' => b":",
+            b';' => b";",
+            b'<' => b"<",
+            b'=' => b"=",
+            b'>' => b">",
+            b'?' => b"?",
+            b'@' => b"@",
+            b'[' => b"[",
+            b'\\' => b"\\",
+            b']' => b"]",
+            b'^' => b"^",
+            b'_' => b"_",
+            b'`' => b"`",
+            b'{' => b"{",
+            b'|' => b"|",
+            b'}' => b"}",
+            b'~' => b"~",
+            b'\n' => b"\n",
+            b'\r' => b"\r",
+            b'\t' => b"\t",
+            _ => b"",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transform::CaseMode;
+    use crate::uart::DefaultController;
+
+    fn collect<const N: usize>(ctrl: &mut UartController<N>, bytes: &[u8]) -> Vec<u8> {
+        let mut out = Vec::new();
+        for &b in bytes {
+            out.extend_from_slice(ctrl.process_char(b));
+        }
+        out
+    }
+
+    // ==================== Echo Combination Tests ====================
+
+    #[test]
+    fn test_uniq_with_line_numbers_numbers_each_run() {
+        let mut ctrl = DefaultController::new();
+        ctrl.set_uniq(true);
+        ctrl.set_line_numbers(true);
+        assert_eq!(
+            collect(&mut ctrl, b"a\ra\rb\r"),
+            b"  1: a (repeated 2 times)\r\n  2: b"
+        );
+    }
+
+    #[test]
+    fn test_uniq_with_reverse_lines_echoes_once() {
+        let mut ctrl = DefaultController::new();
+        ctrl.set_uniq(true);
+        ctrl.set_reverse_lines(true);
+        assert_eq!(
+            collect(&mut ctrl, b"ab\rab\rcd\r"),
+            b"ba (repeated 2 times)\r\ndc"
+        );
+    }
+
+    #[test]
+    fn test_uniq_compares_transformed_lines() {
+        let mut ctrl = DefaultController::new();
+        ctrl.set_case_mode(CaseMode::Upper);
+        ctrl.set_uniq(true);
+        assert_eq!(
+            collect(&mut ctrl, b"ab\rAB\rcd\r"),
+            b"AB (repeated 2 times)\r\nCD"
+        );
+    }
+
+    #[test]
+    fn test_uniq_suppresses_seq_echo() {
+        let mut ctrl = DefaultController::new();
+        ctrl.set_uniq(true);
+        ctrl.set_seq_echo(true);
+        assert_eq!(collect(&mut ctrl, b"ab\r"), b"ab");
+    }
+
+    #[test]
+    fn test_paragraph_reverse_keeps_line_order() {
+        let mut ctrl = DefaultController::new();
+        ctrl.set_paragraph_mode(true);
+        ctrl.set_reverse_lines(true);
+        assert_eq!(collect(&mut ctrl, b"ab\rcd\r\r"), b"ab\rcd\r\rba\ndc\r\n");
+    }
+
+    #[test]
+    fn test_paragraph_takes_one_line_number() {
+        let mut ctrl = DefaultController::new();
+        ctrl.set_paragraph_mode(true);
+        ctrl.set_line_numbers(true);
+        assert_eq!(collect(&mut ctrl, b"ab\r\r"), b"ab\r\r  1: ab\r\n");
+        assert_eq!(collect(&mut ctrl, b"cd\r\r"), b"cd\r\r  2: cd\r\n");
+    }
+
+    #[test]
+    fn test_line_numbers_with_timestamp() {
+        let mut ctrl = DefaultController::new();
+        ctrl.set_line_numbers(true);
+        ctrl.set_timestamp_lines(true);
+        assert_eq!(collect(&mut ctrl, b"hi\r"), b"hi\r  1: hi\r\n");
+        assert!(ctrl.take_line_committed());
+        assert_eq!(ctrl.timestamped_line(7), b"[00000007] hi\r\n");
+    }
+
+    #[test]
+    fn test_indent_line_numbers_and_reverse() {
+        let mut ctrl = DefaultController::new();
+        ctrl.set_indent(2);
+        ctrl.set_line_numbers(true);
+        ctrl.set_reverse_lines(true);
+        assert_eq!(collect(&mut ctrl, b"xy\r"), b"xy\r    1: yx\r\n");
+    }
+
+    #[test]
+    fn test_transform_chain_with_reverse_and_numbers() {
+        let mut ctrl = DefaultController::new();
+        ctrl.set_caesar(1);
+        ctrl.set_reverse_lines(true);
+        ctrl.set_line_numbers(true);
+        assert_eq!(collect(&mut ctrl, b"abc\r"), b"bcd\r  1: dcb\r\n");
+    }
+
+    #[test]
+    fn test_framing_with_seq_echo() {
+        let mut ctrl = DefaultController::new();
+        ctrl.set_char_prefix(Some(b'['));
+        ctrl.set_char_suffix(Some(b']'));
+        ctrl.set_seq_echo(true);
+        assert_eq!(collect(&mut ctrl, b"ab"), b"[a]\x00[b]\x01");
+    }
+
+    #[test]
+    fn test_framing_with_named_controls() {
+        let mut ctrl = DefaultController::new();
+        ctrl.set_char_prefix(Some(b'<'));
+        ctrl.set_char_suffix(Some(b'>'));
+        ctrl.set_named_controls(true);
+        assert_eq!(collect(&mut ctrl, &[0x01, b'a']), b"<<SOH>><a>");
+    }
+
+    #[test]
+    fn test_framing_with_hex_escape() {
+        let mut ctrl = DefaultController::new();
+        ctrl.set_char_prefix(Some(b'<'));
+        ctrl.set_char_suffix(Some(b'>'));
+        ctrl.set_show_hex_escapes(true);
+        assert_eq!(collect(&mut ctrl, &[0xE9, b'a']), b"<\\xE9><a>");
+    }
+
+    #[test]
+    fn test_hex_escape_with_seq_echo() {
+        let mut ctrl = DefaultController::new();
+        ctrl.set_show_hex_escapes(true);
+        ctrl.set_seq_echo(true);
+        assert_eq!(collect(&mut ctrl, &[0xFF, b'a']), b"\\xFF\x00a\x01");
+    }
+
+    #[test]
+    fn test_named_controls_with_seq_echo() {
+        let mut ctrl = DefaultController::new();
+        ctrl.set_named_controls(true);
+        ctrl.set_seq_echo(true);
+        assert_eq!(collect(&mut ctrl, &[0x02]), b"<STX>\x00");
+    }
+
+    #[test]
+    fn test_bit_reverse_feeds_hex_escape() {
+        let mut ctrl = DefaultController::new();
+        ctrl.set_bit_reverse(true);
+        ctrl.set_show_hex_escapes(true);
+        assert_eq!(collect(&mut ctrl, b"A"), b"\\x82");
+    }
+}
@@ -0,0 +1,162 @@
+/*
+ * @file settings.rs
+ * @brief Saved echo settings
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: settings.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Echo Settings.
+//!
+//! BRIEF:
+//! Groups the echo settings that profiles save and restore.
+//! The controller keeps one Settings value and reads it while echoing.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+use super::{BellMode, CrLfMode, NewlineMode};
+use crate::config::{LINE_NUMBER_WIDTH, RIGHT_MARGIN};
+use crate::suppress::SuppressMask;
+use crate::transform::CaseMode;
+
+/// Echo settings of a controller.
+///
+/// # Details
+/// Configuration only; line buffers, counters, and session state live
+/// in the controller. Taken with snapshot() and applied with restore().
+///
+/// # Fields
+/// * `echo_enabled` - Echo characters back
+/// * `case_mode` - Letter case applied to echoed characters
+/// * `newline_mode` - Line ending echoed for Enter
+/// * `bell_mode` - How the bell is signalled
+/// * `crlf_mode` - Whether CRLF commits one line or two
+/// * `caesar_shift` - Caesar cipher shift applied to echoed letters
+/// * `substitution` - Byte replaced before other transforms, and its replacement
+/// * `bit_reverse` - Reverse the bit order of echoed bytes
+/// * `show_line_endings` - Echo CR/LF as visible escapes
+/// * `hex_uppercase` - Emit hex with uppercase letter digits
+/// * `status_markers` - Follow command output with `[OK]` or `[ERR N]`
+/// * `char_prefix` - Byte echoed before each echoed character, if any
+/// * `char_suffix` - Byte echoed after each echoed character, if any
+/// * `seq_echo` - Follow each echoed character with a sequence number
+/// * `named_controls` - Echo control keys as bracketed names
+/// * `collapse_spaces` - Drop a space typed directly after another space
+/// * `ascii_value_mode` - Echo each key as its decimal ASCII code
+/// * `show_hex_escapes` - Echo bytes from 0x80 up as `\xNN` escapes
+/// * `hex_input` - Assemble typed hex digits into bytes
+/// * `auto_wrap` - Wrap echoed text at `wrap_width`
+/// * `wrap_width` - Column width used for auto-wrap
+/// * `form_control` - Handle vertical tab and form feed
+/// * `suppress` - Character classes whose echo is suppressed
+/// * `line_buffering` - Buffer echo-mode characters into lines
+/// * `backspace_handling` - Treat backspace and delete as line editing
+/// * `uniq` - Collapse consecutive identical lines
+/// * `line_numbers` - Echo each committed line with a line number
+/// * `line_number_width` - Digit width line numbers are padded to
+/// * `indent` - Spaces echoed before each committed line
+/// * `reverse_lines` - Echo each committed line with its bytes reversed
+/// * `timestamp_lines` - Echo each committed line with a tick timestamp
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct Settings {
+    pub(super) echo_enabled: bool,
+    pub(super) case_mode: CaseMode,
+    pub(super) newline_mode: NewlineMode,
+    pub(super) bell_mode: BellMode,
+    pub(super) crlf_mode: CrLfMode,
+    pub(super) caesar_shift: u8,
+    pub(super) substitution: Option<(u8, u8)>,
+    pub(super) bit_reverse: bool,
+    pub(super) show_line_endings: bool,
+    pub(super) hex_uppercase: bool,
+    pub(super) status_markers: bool,
+    pub(super) char_prefix: Option<u8>,
+    pub(super) char_suffix: Option<u8>,
+    pub(super) seq_echo: bool,
+    pub(super) named_controls: bool,
+    pub(super) collapse_spaces: bool,
+    pub(super) ascii_value_mode: bool,
+    pub(super) show_hex_escapes: bool,
+    pub(super) hex_input: bool,
+    pub(super) auto_wrap: bool,
+    pub(super) wrap_width: u16,
+    pub(super) form_control: bool,
+    pub(super) suppress: SuppressMask,
+    pub(super) line_buffering: bool,
+    pub(super) backspace_handling: bool,
+    pub(super) uniq: bool,
+    pub(super) line_numbers: bool,
+    pub(super) line_number_width: usize,
+    pub(super) indent: u8,
+    pub(super) reverse_lines: bool,
+    pub(super) timestamp_lines: bool,
+}
+
+impl Default for Settings {
+    /// Returns the settings of a newly created controller.
+    ///
+    /// # Returns
+    /// * `Self` - Default echo settings
+    fn default() -> Self {
+        Self {
+            echo_enabled: true,
+            case_mode: CaseMode::AsIs,
+            newline_mode: NewlineMode::AsReceived,
+            bell_mode: BellMode::Audible,
+            crlf_mode: CrLfMode::Collapse,
+            caesar_shift: 0,
+            substitution: None,
+            bit_reverse: false,
+            show_line_endings: false,
+            hex_uppercase: true,
+            status_markers: false,
+            char_prefix: None,
+            char_suffix: None,
+            seq_echo: false,
+            named_controls: false,
+            collapse_spaces: false,
+            ascii_value_mode: false,
+            show_hex_escapes: false,
+            hex_input: false,
+            auto_wrap: false,
+            wrap_width: RIGHT_MARGIN,
+            form_control: false,
+            suppress: SuppressMask::NONE,
+            line_buffering: true,
+            backspace_handling: true,
+            uniq: false,
+            line_numbers: false,
+            line_number_width: LINE_NUMBER_WIDTH,
+            indent: 0,
+            reverse_lines: false,
+            timestamp_lines: false,
+        }
+    }
+}
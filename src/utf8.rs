@@ -0,0 +1,215 @@
+/*
+ * @file utf8.rs
+ * @brief UTF-8 decoding
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: utf8.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART UTF-8 Decoding.
+//!
+//! BRIEF:
+//! Decodes multibyte UTF-8 input one byte at a time.
+//! Maps pasted smart punctuation to plain ASCII equivalents.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Result of feeding one byte to the UTF-8 decoder.
+///
+/// # Variants
+/// * `Ascii` - Single-byte character
+/// * `Pending` - Byte belongs to an incomplete sequence
+/// * `Char` - Completed multibyte sequence with its code point
+/// * `Invalid` - Byte cannot start or continue a sequence
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum Utf8Event {
+    Ascii(u8),
+    Pending,
+    Char(u32),
+    Invalid,
+}
+
+/// Byte-at-a-time UTF-8 decoder.
+///
+/// # Details
+/// An ASCII byte always decodes as itself and abandons any sequence
+/// in progress. Overlong and surrogate forms are not rejected.
+///
+/// # Fields
+/// * `code` - Code point bits collected so far
+/// * `remaining` - Continuation bytes still expected
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct Utf8Decoder {
+    code: u32,
+    remaining: u8,
+}
+
+impl Utf8Decoder {
+    /// Creates a decoder outside any sequence.
+    ///
+    /// # Returns
+    /// * `Self` - New Utf8Decoder
+    #[allow(dead_code)]
+    pub const fn new() -> Self {
+        Self {
+            code: 0,
+            remaining: 0,
+        }
+    }
+
+    /// Feeds one received byte to the decoder.
+    ///
+    /// # Arguments
+    /// * `ch` - The byte received
+    ///
+    /// # Returns
+    /// * `Utf8Event` - Decoded character, if complete
+    #[allow(dead_code)]
+    pub fn feed(&mut self, ch: u8) -> Utf8Event {
+        let (code, remaining) = match ch {
+            0x00..=0x7F => {
+                self.remaining = 0;
+                return Utf8Event::Ascii(ch);
+            }
+            0x80..=0xBF if self.remaining > 0 => {
+                self.code = (self.code << 6) | u32::from(ch & 0x3F);
+                self.remaining -= 1;
+                return match self.remaining {
+                    0 => Utf8Event::Char(self.code),
+                    _ => Utf8Event::Pending,
+                };
+            }
+            0xC2..=0xDF => (ch & 0x1F, 1),
+            0xE0..=0xEF => (ch & 0x0F, 2),
+            0xF0..=0xF4 => (ch & 0x07, 3),
+            _ => {
+                self.remaining = 0;
+                return Utf8Event::Invalid;
+            }
+        };
+        self.code = u32::from(code);
+        self.remaining = remaining;
+        Utf8Event::Pending
+    }
+}
+
+/// Maps typographic punctuation to its plain ASCII equivalent.
+///
+/// # Details
+/// Covers curly single and double quotes, primes, en and em dashes,
+/// the minus sign, and the no-break space.
+///
+/// # Arguments
+/// * `code` - Unicode code point
+///
+/// # Returns
+/// * `Option<u8>` - ASCII replacement, or None if not smart punctuation
+#[allow(dead_code)]
+pub fn normalize_punctuation(code: u32) -> Option<u8> {
+    match code {
+        0x2018 | 0x2019 | 0x201A | 0x201B | 0x2032 => Some(b'\''),
+        0x201C | 0x201D | 0x201E | 0x201F | 0x2033 => Some(b'"'),
+        0x2010..=0x2015 | 0x2212 => Some(b'-'),
+        0x00A0 => Some(b' '),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(bytes: &[u8]) -> Utf8Event {
+        let mut decoder = Utf8Decoder::new();
+        let mut event = Utf8Event::Pending;
+        for &b in bytes {
+            event = decoder.feed(b);
+        }
+        event
+    }
+
+    // ==================== Decoder Tests ====================
+
+    #[test]
+    fn test_ascii_passes_through() {
+        assert_eq!(decode(b"a"), Utf8Event::Ascii(b'a'));
+    }
+
+    #[test]
+    fn test_two_byte_sequence() {
+        assert_eq!(decode("é".as_bytes()), Utf8Event::Char(0xE9));
+    }
+
+    #[test]
+    fn test_three_byte_sequence() {
+        assert_eq!(decode("\u{201C}".as_bytes()), Utf8Event::Char(0x201C));
+    }
+
+    #[test]
+    fn test_four_byte_sequence() {
+        assert_eq!(decode("\u{1F600}".as_bytes()), Utf8Event::Char(0x1F600));
+    }
+
+    #[test]
+    fn test_stray_continuation_invalid() {
+        assert_eq!(decode(&[0x80]), Utf8Event::Invalid);
+    }
+
+    #[test]
+    fn test_ascii_abandons_sequence() {
+        let mut decoder = Utf8Decoder::new();
+        decoder.feed(0xE2);
+        assert_eq!(decoder.feed(b'x'), Utf8Event::Ascii(b'x'));
+        assert_eq!(decoder.feed(0x80), Utf8Event::Invalid);
+    }
+
+    // ==================== Normalization Tests ====================
+
+    #[test]
+    fn test_curly_quotes_straightened() {
+        assert_eq!(normalize_punctuation(0x2018), Some(b'\''));
+        assert_eq!(normalize_punctuation(0x2019), Some(b'\''));
+        assert_eq!(normalize_punctuation(0x201C), Some(b'"'));
+        assert_eq!(normalize_punctuation(0x201D), Some(b'"'));
+    }
+
+    #[test]
+    fn test_dashes_to_hyphen() {
+        assert_eq!(normalize_punctuation(0x2013), Some(b'-'));
+        assert_eq!(normalize_punctuation(0x2014), Some(b'-'));
+    }
+
+    #[test]
+    fn test_other_code_points_unmapped() {
+        assert_eq!(normalize_punctuation(0xE9), None);
+        assert_eq!(normalize_punctuation(0x1F600), None);
+    }
+}
@@ -0,0 +1,92 @@
+/*
+ * @file watchdog.rs
+ * @brief Watchdog feed scheduling
+ * @author Kevin Thomas
+ * @date 2025
+ *
+ * MIT License
+ *
+ * Copyright (c) 2025 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: watchdog.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART Watchdog Feed Scheduling.
+//!
+//! BRIEF:
+//! Decides when the main loop must feed the hardware watchdog.
+//! Spaces feeds WATCHDOG_FEED_MS apart so a hung loop, not a busy one,
+//! lets the watchdog reset the chip.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 16, 2026
+//! UPDATE DATE: October 16, 2026
+
+/// Decides whether the watchdog should be fed now.
+///
+/// # Details
+/// Feeding every `interval_ms` rather than on every loop pass keeps
+/// register writes rare while staying well inside the timeout.
+///
+/// # Arguments
+/// * `last_feed_ms` - Time of the previous feed
+/// * `now_ms` - Current time
+/// * `interval_ms` - Desired time between feeds
+///
+/// # Returns
+/// * `bool` - True if the watchdog is due to be fed
+#[allow(dead_code)]
+pub fn should_feed(last_feed_ms: u64, now_ms: u64, interval_ms: u64) -> bool {
+    now_ms.saturating_sub(last_feed_ms) >= interval_ms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Feed Decision Tests ====================
+
+    #[test]
+    fn test_not_due_within_interval() {
+        assert!(!should_feed(1000, 1499, 500));
+    }
+
+    #[test]
+    fn test_due_at_interval() {
+        assert!(should_feed(1000, 1500, 500));
+    }
+
+    #[test]
+    fn test_due_after_long_gap() {
+        assert!(should_feed(1000, 9000, 500));
+    }
+
+    #[test]
+    fn test_clock_behind_last_feed_not_due() {
+        assert!(!should_feed(1000, 900, 500));
+    }
+
+    #[test]
+    fn test_zero_interval_always_due() {
+        assert!(should_feed(1000, 1000, 0));
+    }
+}